@@ -1,5 +1,10 @@
 use std::ops::Deref;
 
+use crate::DecodeError;
+
+#[cfg(test)]
+mod test;
+
 #[derive(Debug, Default)]
 pub struct Bytes {
     bytes: Vec<u8>,
@@ -75,6 +80,14 @@ impl Bytes {
     pub const fn shift(&self) -> u32 {
         self.shift
     }
+    /// Returns the number of bits written so far
+    #[must_use]
+    pub const fn bit_len(&self) -> usize {
+        match self.shift {
+            0 => self.bytes.len() * 8,
+            shift => (self.bytes.len() - 1) * 8 + shift as usize,
+        }
+    }
 }
 
 impl Deref for Bytes {
@@ -84,3 +97,66 @@ impl Deref for Bytes {
         &self.bytes
     }
 }
+
+/// Reads numbers most significant bit first, as [`Bytes::push`] writes them
+#[derive(Debug, Clone)]
+pub struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    #[must_use]
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+    /// Returns the number of bits read so far
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+    /// Returns the number of bits left to read
+    #[must_use]
+    pub const fn remaining_bits(&self) -> usize {
+        self.bytes.len() * 8 - self.position
+    }
+    /// Returns the next `width` bits as a number without moving past them
+    ///
+    /// # Errors
+    ///
+    /// [`DecodeError::UnexpectedEnd`] if there are fewer bits left
+    ///
+    /// # Panics
+    ///
+    /// If `width` is larger than 32
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn peek(&self, width: u16) -> Result<u32, DecodeError> {
+        assert!(width <= 32, "cannot read {width} bits at once");
+        let width = usize::from(width);
+        if width > self.remaining_bits() {
+            return Err(DecodeError::UnexpectedEnd);
+        }
+        // at most 5 bytes, the 32 bits being offset by up to 7
+        let end = self.position + width;
+        let value = self.bytes[self.position / 8..end.div_ceil(8)]
+            .iter()
+            .fold(0_u64, |value, &byte| value << 8 | u64::from(byte));
+        let value = value >> (end.div_ceil(8) * 8 - end);
+        Ok((value & ((1 << width) - 1)) as u32)
+    }
+    /// Reads the next `width` bits as a number
+    ///
+    /// # Errors
+    ///
+    /// [`DecodeError::UnexpectedEnd`] if there are fewer bits left, in
+    /// which case nothing is read
+    ///
+    /// # Panics
+    ///
+    /// If `width` is larger than 32
+    pub fn read(&mut self, width: u16) -> Result<u32, DecodeError> {
+        let value = self.peek(width)?;
+        self.position += usize::from(width);
+        Ok(value)
+    }
+}
@@ -0,0 +1,44 @@
+use crate::{
+    byte_stream::{BitReader, Bytes},
+    DecodeError,
+};
+
+#[test]
+fn round_trip() {
+    let values = [
+        (0b101, 3),
+        (0x1FF, 9),
+        (0, 1),
+        (0xBEEF, 16),
+        (0x7F, 7),
+        (1, 1),
+    ];
+    let mut bytes = Bytes::default();
+    for (value, width) in values {
+        bytes.push(value, width);
+    }
+    let mut reader = BitReader::new(&bytes);
+    assert_eq!(reader.remaining_bits(), 40);
+    for (value, width) in values {
+        assert_eq!(reader.peek(width), Ok(u32::from(value)));
+        assert_eq!(reader.read(width), Ok(u32::from(value)));
+    }
+    assert_eq!((reader.position(), reader.remaining_bits()), (37, 3));
+}
+
+#[test]
+fn wide_reads() {
+    let bytes = [0x12, 0x34, 0x56, 0x78, 0x9A];
+    let mut reader = BitReader::new(&bytes);
+    assert_eq!(reader.read(4), Ok(0x1));
+    // 32 bits spanning five bytes
+    assert_eq!(reader.peek(32), Ok(0x2345_6789));
+    assert_eq!(reader.read(0), Ok(0));
+    assert_eq!(reader.read(20), Ok(0x23456));
+    assert_eq!(reader.read(17), Err(DecodeError::UnexpectedEnd));
+    // nothing is read on underflow
+    assert_eq!(reader.remaining_bits(), 16);
+    assert_eq!(reader.read(16), Ok(0x789A));
+    assert_eq!(reader.peek(1), Err(DecodeError::UnexpectedEnd));
+    assert_eq!(BitReader::new(&[]).read(1), Err(DecodeError::UnexpectedEnd));
+}
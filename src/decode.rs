@@ -0,0 +1,443 @@
+//! Reading the payload back out of a grid of modules
+use crate::{
+    byte_stream::BitReader,
+    encoding::count_bits_count,
+    format::{format_bits, version_bits},
+    gs1::{Fnc1, GS},
+    kanji,
+    mask::Mask,
+    matrix::QrMatrix,
+    reed_solomon,
+    segment::{eci, Segment},
+    structured_append::StructuredAppend,
+    DecodeError, EncodingMode, ErrorCorrection, QRCodeVersion,
+};
+
+#[cfg(test)]
+mod test;
+
+/// The characters of alphanumeric mode, by their value
+const ALPHANUMERIC: &[u8; 45] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+/// The most bits a copy of the format or version information may be off by
+const MAX_DISTANCE: u32 = 3;
+
+/// A decoded symbol
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decoded {
+    pub version: QRCodeVersion,
+    pub ec: ErrorCorrection,
+    pub mask: Mask,
+    /// The payload of every segment [joined together](Bitstream::text)
+    pub text: String,
+    /// The first ECI designator, which is not applied to the text
+    pub eci: Option<u32>,
+    pub fnc1: Option<Fnc1>,
+    pub structured_append: Option<StructuredAppend>,
+    /// The number of codewords corrected in every block, in the order of
+    /// the data
+    pub corrections: Vec<usize>,
+}
+
+/// Decodes a symbol from its modules, given row by row with `true`
+/// being dark and without a quiet zone
+///
+/// # Errors
+///
+/// - [`DecodeError::InvalidSize`] if the modules do not form a square of a
+///   [version's size](QRCodeVersion::size)
+/// - [`DecodeError::FormatInformation`] or
+///   [`DecodeError::VersionInformation`] if those cannot be read
+/// - [`DecodeError::TooManyErrors`] if a block is damaged beyond
+///   [correction](reed_solomon::correct)
+/// - any error from [parsing](parse_segments) the data codewords, apart
+///   from their padding
+pub fn decode(modules: &[bool]) -> Result<Decoded, DecodeError> {
+    let size = modules.len().isqrt();
+    let version = (size * size == modules.len() && size >= 21 && size % 4 == 1)
+        .then(|| QRCodeVersion::from_number((size - 17) / 4))
+        .flatten()
+        .ok_or(DecodeError::InvalidSize {
+            modules: modules.len(),
+        })?;
+    let get = |x: usize, y: usize| modules[y * size + x];
+    let version = read_version(version, get)?;
+    let (ec, mask) = read_format(size, get)?;
+
+    let codewords = read_codewords(version, mask, get);
+    let split = version.split(ec);
+    let ec_bytes = split.ec_bytes();
+    let mut data = Vec::with_capacity(version.data_size(ec));
+    let mut corrections = Vec::with_capacity(split.blocks());
+    for (block, mut codewords) in deinterleave(&codewords, version, ec)
+        .into_iter()
+        .enumerate()
+    {
+        let corrected = reed_solomon::correct(&mut codewords, ec_bytes, &[])
+            .ok_or(DecodeError::TooManyErrors { block })?;
+        corrections.push(corrected);
+        data.extend_from_slice(&codewords[..codewords.len() - ec_bytes]);
+    }
+    let bitstream = read_segments(&mut BitReader::new(&data), version)?;
+    Ok(Decoded {
+        version,
+        ec,
+        mask,
+        text: bitstream.text(),
+        eci: bitstream.segments.iter().find_map(|segment| segment.eci),
+        fnc1: bitstream.fnc1,
+        structured_append: bitstream.structured_append,
+        corrections,
+    })
+}
+
+/// Returns the number of bits two codes differ by
+const fn distance(a: u32, b: u32) -> u32 {
+    (a ^ b).count_ones()
+}
+
+/// Reads both copies of the format information, returning the closest
+/// valid level & mask
+///
+/// # Errors
+///
+/// [`DecodeError::FormatInformation`] if both copies are more than three
+/// bits off
+pub fn read_format(
+    size: usize,
+    get: impl Fn(usize, usize) -> bool,
+) -> Result<(ErrorCorrection, Mask), DecodeError> {
+    let read = |positions: &mut dyn Iterator<Item = (usize, usize)>| {
+        positions
+            .enumerate()
+            .fold(0, |bits, (i, (x, y))| bits | u32::from(get(x, y)) << i)
+    };
+    // the positions of bits 0 to 14, as written by `QrMatrix::place_format`
+    let first = read(
+        &mut (0..6)
+            .map(|i| (8, i))
+            .chain([(8, 7), (8, 8), (7, 8)])
+            .chain((9..15).map(|i| (14 - i, 8))),
+    );
+    let second = read(
+        &mut (0..8)
+            .map(|i| (size - 1 - i, 8))
+            .chain((8..15).map(|i| (8, size - 15 + i))),
+    );
+    ErrorCorrection::ALL
+        .into_iter()
+        .flat_map(|ec| Mask::ALL.map(|mask| (ec, mask)))
+        .map(|(ec, mask)| {
+            let bits = u32::from(format_bits(ec, mask));
+            (distance(bits, first).min(distance(bits, second)), ec, mask)
+        })
+        .min_by_key(|&(distance, ..)| distance)
+        .filter(|&(distance, ..)| distance <= MAX_DISTANCE)
+        .map(|(_, ec, mask)| (ec, mask))
+        .ok_or(DecodeError::FormatInformation)
+}
+
+/// Reads both copies of the version information from
+/// [`QRCodeVersion::V7`] onwards, checking it against the version given
+/// by the size
+///
+/// # Errors
+///
+/// [`DecodeError::VersionInformation`] if both copies are more than three
+/// bits off, or the closest version is not of this size
+pub fn read_version(
+    version: QRCodeVersion,
+    get: impl Fn(usize, usize) -> bool,
+) -> Result<QRCodeVersion, DecodeError> {
+    let Some(_) = version_bits(version) else {
+        return Ok(version);
+    };
+    let size = version.size();
+    let (mut top_right, mut bottom_left) = (0, 0);
+    for i in 0..18 {
+        let (a, b) = (size - 11 + i % 3, i / 3);
+        top_right |= u32::from(get(a, b)) << i;
+        bottom_left |= u32::from(get(b, a)) << i;
+    }
+    QRCodeVersion::ALL
+        .iter()
+        .filter_map(|&v| version_bits(v).map(|bits| (v, bits)))
+        .map(|(v, bits)| {
+            let distance = distance(bits, top_right).min(distance(bits, bottom_left));
+            (distance, v)
+        })
+        .min_by_key(|&(distance, _)| distance)
+        .filter(|&(distance, v)| distance <= MAX_DISTANCE && v == version)
+        .map(|(_, v)| v)
+        .ok_or(DecodeError::VersionInformation)
+}
+
+/// Reads the unmasked data modules in placement order, returning every
+/// codeword and dropping the remainder bits
+#[must_use]
+pub fn read_codewords(
+    version: QRCodeVersion,
+    mask: Mask,
+    get: impl Fn(usize, usize) -> bool,
+) -> Vec<u8> {
+    let mut codewords = vec![0; version.total_codewords()];
+    let positions = QrMatrix::new(version).data_positions();
+    for (i, (x, y)) in positions.into_iter().take(codewords.len() * 8).enumerate() {
+        if get(x, y) != mask.applies(x, y) {
+            codewords[i / 8] |= 0x80 >> (i % 8);
+        }
+    }
+    codewords
+}
+
+/// Splits interleaved codewords back into their blocks, reversing
+/// [`interleave`](crate::encoding::interleave)
+///
+/// Every block holds its data codewords followed by its error correction
+/// codewords.
+///
+/// # Panics
+///
+/// If `codewords` is shorter than [`QRCodeVersion::total_codewords`]
+#[must_use]
+pub fn deinterleave(codewords: &[u8], version: QRCodeVersion, ec: ErrorCorrection) -> Vec<Vec<u8>> {
+    let split = version.split(ec);
+    let sizes = split.block_sizes().collect::<Vec<_>>();
+    let mut blocks = sizes
+        .iter()
+        .map(|&size| Vec::with_capacity(size + split.ec_bytes()))
+        .collect::<Vec<_>>();
+    let mut codewords = codewords.iter().copied();
+    let longest = sizes.iter().copied().max().unwrap_or(0);
+    for i in 0..longest {
+        for (block, &size) in blocks.iter_mut().zip(&sizes) {
+            if i < size {
+                block.extend(codewords.next());
+            }
+        }
+    }
+    for _ in 0..split.ec_bytes() {
+        for block in &mut blocks {
+            block.extend(codewords.next());
+        }
+    }
+    blocks
+}
+
+/// The contents of a symbol's data codewords, as written by
+/// [`create_bits`](crate::segment::create_bits) and the headers before it
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Bitstream {
+    /// Every segment in order, alphanumeric segments under FNC1 holding
+    /// `%` & `%%` as written
+    pub segments: Vec<Segment>,
+    pub fnc1: Option<Fnc1>,
+    pub structured_append: Option<StructuredAppend>,
+}
+
+impl Bitstream {
+    /// Joins the data of every segment into text
+    ///
+    /// Each byte mode segment is read on its own, as UTF-8 when valid or
+    /// declared by [ECI 26](eci::UTF_8), and as ISO-8859-1 otherwise.
+    /// Under FNC1 an alphanumeric `%` is read as [`GS`].
+    #[must_use]
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+        let mut utf8 = false;
+        for segment in &self.segments {
+            if let Some(designator) = segment.eci {
+                utf8 = designator == eci::UTF_8;
+            }
+            match segment.mode {
+                EncodingMode::Byte => match std::str::from_utf8(&segment.data) {
+                    Ok(valid) => text.push_str(valid),
+                    Err(_) if utf8 => text.push_str(&String::from_utf8_lossy(&segment.data)),
+                    Err(_) => text.extend(segment.data.iter().map(|&b| char::from(b))),
+                },
+                EncodingMode::Alphanumeric if self.fnc1.is_some() => {
+                    let mut chars = segment.data.iter().map(|&b| char::from(b)).peekable();
+                    while let Some(c) = chars.next() {
+                        if c != '%' {
+                            text.push(c);
+                        } else if chars.next_if_eq(&'%').is_some() {
+                            text.push('%');
+                        } else {
+                            text.push(GS);
+                        }
+                    }
+                }
+                // read back as UTF-8 in the first place
+                _ => text.push_str(&String::from_utf8_lossy(&segment.data)),
+            }
+        }
+        text
+    }
+}
+
+/// Parses the data codewords of a symbol of the given version, reversing
+/// [`create_bits`](crate::segment::create_bits)
+///
+/// Unlike [`decode`], which reads whatever follows the last segment as
+/// padding, the terminator & padding written by the encoder are checked.
+///
+/// # Errors
+///
+/// - [`DecodeError::InvalidMode`] for an unknown mode indicator
+/// - [`DecodeError::UnexpectedEnd`] if the data ends within a segment
+/// - [`DecodeError::InvalidSegmentData`] or
+///   [`DecodeError::InvalidEciDesignator`] for values no encoder writes
+/// - [`DecodeError::InvalidPadding`] if the terminator isn't followed by
+///   zeros up to a codeword boundary then `0xEC` & `0x11` in turn
+pub fn parse_segments(data: &[u8], version: QRCodeVersion) -> Result<Bitstream, DecodeError> {
+    let mut bits = BitReader::new(data);
+    let bitstream = read_segments(&mut bits, version)?;
+    // what is left of the terminator, then zeros up to the codeword
+    while !bits.position().is_multiple_of(8) {
+        let position = bits.position();
+        if bits.read(1)? != 0 {
+            return Err(DecodeError::InvalidPadding { position });
+        }
+    }
+    for (i, &codeword) in data[bits.position() / 8..].iter().enumerate() {
+        if codeword != [0xEC, 0x11][i % 2] {
+            return Err(DecodeError::InvalidPadding {
+                position: bits.position() + i * 8,
+            });
+        }
+    }
+    Ok(bitstream)
+}
+
+/// Reads segments up to & including the terminator, which may be cut short
+/// at the end of the data
+#[allow(clippy::cast_possible_truncation)]
+fn read_segments(bits: &mut BitReader, version: QRCodeVersion) -> Result<Bitstream, DecodeError> {
+    let mut bitstream = Bitstream::default();
+    let mut eci = None;
+    while bits.remaining_bits() >= 4 {
+        let indicator = bits.read(4)?;
+        let mode = match indicator {
+            0b0000 => break,
+            0b0001 => EncodingMode::Numeric,
+            0b0010 => EncodingMode::Alphanumeric,
+            0b0100 => EncodingMode::Byte,
+            0b1000 => EncodingMode::Kanji,
+            0b0111 => {
+                eci = Some(read_eci_designator(bits)?);
+                continue;
+            }
+            0b0011 => {
+                bitstream.structured_append = Some(StructuredAppend {
+                    position: bits.read(4)? as u8,
+                    total: bits.read(4)? as u8 + 1,
+                    parity: bits.read(8)? as u8,
+                });
+                continue;
+            }
+            0b0101 => {
+                bitstream.fnc1 = Some(Fnc1::First);
+                continue;
+            }
+            0b1001 => {
+                bitstream.fnc1 = Some(Fnc1::Second {
+                    application_indicator: bits.read(8)? as u8,
+                });
+                continue;
+            }
+            _ => {
+                return Err(DecodeError::InvalidMode {
+                    indicator: indicator as u8,
+                })
+            }
+        };
+        let count = bits.read(count_bits_count(version, mode))? as usize;
+        let mut data = Vec::with_capacity(count);
+        match mode {
+            EncodingMode::Numeric => read_numeric(bits, count, &mut data)?,
+            EncodingMode::Alphanumeric => read_alphanumeric(bits, count, &mut data)?,
+            EncodingMode::Byte => {
+                for _ in 0..count {
+                    data.push(bits.read(8)? as u8);
+                }
+            }
+            EncodingMode::Kanji => read_kanji(bits, count, &mut data)?,
+        }
+        bitstream.segments.push(Segment {
+            mode,
+            data,
+            eci: eci.take(),
+        });
+    }
+    Ok(bitstream)
+}
+
+/// Reads an ECI assignment number of one, two or three bytes
+fn read_eci_designator(bits: &mut BitReader) -> Result<u32, DecodeError> {
+    let first = bits.read(8)?;
+    match (first << 24).leading_ones() {
+        0 => Ok(first),
+        1 => Ok((first & 0x3F) << 8 | bits.read(8)?),
+        2 => Ok((first & 0x1F) << 16 | bits.read(16)?),
+        _ => Err(DecodeError::InvalidEciDesignator),
+    }
+}
+
+/// Reverses [`encode_numeric`](crate::encoding::encode_numeric)
+fn read_numeric(bits: &mut BitReader, count: usize, text: &mut Vec<u8>) -> Result<(), DecodeError> {
+    let invalid = DecodeError::InvalidSegmentData {
+        mode: EncodingMode::Numeric,
+    };
+    let mut left = count;
+    while left > 0 {
+        let digits = left.min(3);
+        let value = bits.read([4, 7, 10][digits - 1])?;
+        if value >= [10, 100, 1000][digits - 1] {
+            return Err(invalid);
+        }
+        let value = format!("{value:0digits$}");
+        text.extend(value.bytes());
+        left -= digits;
+    }
+    Ok(())
+}
+
+/// Reverses [`encode_alphanumeric`](crate::encoding::encode_alphanumeric)
+fn read_alphanumeric(
+    bits: &mut BitReader,
+    count: usize,
+    text: &mut Vec<u8>,
+) -> Result<(), DecodeError> {
+    let invalid = DecodeError::InvalidSegmentData {
+        mode: EncodingMode::Alphanumeric,
+    };
+    let mut left = count;
+    while left > 0 {
+        if left >= 2 {
+            let value = bits.read(11)? as usize;
+            if value >= 45 * 45 {
+                return Err(invalid);
+            }
+            text.extend([ALPHANUMERIC[value / 45], ALPHANUMERIC[value % 45]]);
+            left -= 2;
+        } else {
+            let value = bits.read(6)? as usize;
+            text.push(*ALPHANUMERIC.get(value).ok_or(invalid)?);
+            left -= 1;
+        }
+    }
+    Ok(())
+}
+
+/// Reverses [`encode_kanji`](crate::encoding::encode_kanji)
+#[allow(clippy::cast_possible_truncation)]
+fn read_kanji(bits: &mut BitReader, count: usize, text: &mut Vec<u8>) -> Result<(), DecodeError> {
+    for _ in 0..count {
+        let c = kanji::from_shift_jis(kanji::expand(bits.read(13)? as u16)).ok_or(
+            DecodeError::InvalidSegmentData {
+                mode: EncodingMode::Kanji,
+            },
+        )?;
+        text.extend(c.encode_utf8(&mut [0; 4]).bytes());
+    }
+    Ok(())
+}
@@ -0,0 +1,232 @@
+use crate::{
+    decode::{decode, deinterleave, parse_segments},
+    encoding::interleave,
+    gs1::{Fnc1, Gs1Builder, GS},
+    mask::Mask,
+    segment::{create_bits, eci, Segment},
+    structured_append::{split, StructuredAppend},
+    DecodeError, ErrorCorrection, QRCodeVersion, QrCode,
+};
+
+#[test]
+fn round_trip() {
+    for (data, ec) in [
+        ("HELLO WORLD", ErrorCorrection::Q),
+        ("01234567890123456789", ErrorCorrection::H),
+        ("Mixed 123456789012 text ABCDEFGHIJKLMN", ErrorCorrection::M),
+        ("漢字とカタカナ", ErrorCorrection::L),
+        ("ünïcödé", ErrorCorrection::L),
+        ("", ErrorCorrection::L),
+    ] {
+        let code = QrCode::new(data, ec).unwrap();
+        let decoded = decode(code.matrix().modules()).unwrap();
+        assert_eq!(decoded.text, data);
+        assert_eq!(
+            (decoded.version, decoded.ec, decoded.mask),
+            (code.version(), ec, code.mask())
+        );
+    }
+    // with version information, and two groups of blocks
+    let text = "https://example.com/".repeat(20);
+    let code = QrCode::new(&text, ErrorCorrection::Q).unwrap();
+    assert!(code.version() >= QRCodeVersion::V7);
+    assert_eq!(decode(code.matrix().modules()).unwrap().text, text);
+
+    for mask in Mask::ALL {
+        let mut code = QrCode::new("MASKS", ErrorCorrection::L).unwrap();
+        code.set_mask(mask);
+        assert_eq!(decode(code.matrix().modules()).unwrap().mask, mask);
+    }
+}
+
+#[test]
+fn headers() {
+    let code = QrCode::new_utf8("Grüße", ErrorCorrection::M).unwrap();
+    let decoded = decode(code.matrix().modules()).unwrap();
+    assert_eq!(
+        (decoded.text.as_str(), decoded.eci),
+        ("Grüße", Some(eci::UTF_8))
+    );
+
+    let segments = [Segment::byte(&[b'G', 0xE9])
+        .with_eci(eci::ISO_8859_1)
+        .unwrap()];
+    let code = QrCode::from_segments(&segments, ErrorCorrection::M).unwrap();
+    let decoded = decode(code.matrix().modules()).unwrap();
+    assert_eq!((decoded.text.as_str(), decoded.eci), ("Gé", Some(3)));
+
+    let gs1 = Gs1Builder::new()
+        .push("01", "09506000134352")
+        .unwrap()
+        .push("10", "AB-12%")
+        .unwrap()
+        .push("17", "201225")
+        .unwrap();
+    let decoded = decode(gs1.build(ErrorCorrection::M).unwrap().matrix().modules()).unwrap();
+    assert_eq!(decoded.fnc1, Some(Fnc1::First));
+    assert_eq!(
+        decoded.text,
+        format!("010950600013435210AB-12%{GS}17201225")
+    );
+    let second = Fnc1::with_letter(b'A').unwrap();
+    let code = QrCode::with_fnc1("AA1234BBB112", second, ErrorCorrection::L).unwrap();
+    assert_eq!(decode(code.matrix().modules()).unwrap().fnc1, Some(second));
+
+    let codes = split(&"0123456789".repeat(300), ErrorCorrection::H).unwrap();
+    let mut text = String::new();
+    for (i, code) in codes.iter().enumerate() {
+        let decoded = decode(code.matrix().modules()).unwrap();
+        let header = decoded.structured_append.unwrap();
+        assert_eq!(
+            (usize::from(header.position), usize::from(header.total)),
+            (i, codes.len())
+        );
+        text += &decoded.text;
+    }
+    assert_eq!(text, "0123456789".repeat(300));
+    assert!(matches!(
+        decode(codes[0].matrix().modules()).unwrap().structured_append,
+        Some(StructuredAppend { parity, .. }) if parity == crate::structured_append::parity(text.as_bytes())
+    ));
+}
+
+#[test]
+fn blocks() {
+    let version = QRCodeVersion::V5;
+    let ec = ErrorCorrection::Q;
+    let data = (0..=u8::MAX)
+        .take(version.data_size(ec))
+        .collect::<Vec<_>>();
+    let blocks = deinterleave(&interleave(&data, version, ec), version, ec);
+    // two blocks of 15 data codewords and two of 16
+    assert_eq!(
+        blocks.iter().map(Vec::len).collect::<Vec<_>>(),
+        [33, 33, 34, 34]
+    );
+    assert_eq!(blocks[0][..15], data[..15]);
+    assert_eq!(blocks[3][..16], data[46..]);
+}
+
+#[test]
+fn damage() {
+    let code = QrCode::new("DAMAGED", ErrorCorrection::L).unwrap();
+    let size = code.matrix().size();
+    let mut modules = code.matrix().modules().to_vec();
+    // a few wrong bits of format information are tolerated
+    for x in [0, 1, 2] {
+        modules[8 * size + x] ^= true;
+    }
+    let decoded = decode(&modules).unwrap();
+    assert_eq!(
+        (decoded.text.as_str(), decoded.corrections),
+        ("DAMAGED", vec![0])
+    );
+    // V1-L corrects up to 3 of its 26 codewords, the first three taking up
+    // 2x4 areas of the right two columns from the bottom up
+    for i in 0..3 {
+        modules[(size - 1 - 4 * i) * size + size - 1] ^= true;
+    }
+    assert_eq!(decode(&modules).unwrap().corrections, [3]);
+    // the fourth codeword runs down the next two columns
+    modules[9 * size + size - 3] ^= true;
+    assert_eq!(
+        decode(&modules),
+        Err(DecodeError::TooManyErrors { block: 0 })
+    );
+
+    // the first four codewords of 5-Q go to each of its blocks in turn
+    let segments = [Segment::alphanumeric("ERRORS IN EVERY BLOCK").unwrap()];
+    let version = QRCodeVersion::V5;
+    let code = QrCode::from_segments_with_version(&segments, version, ErrorCorrection::Q).unwrap();
+    let mut modules = code.matrix().modules().to_vec();
+    let size = version.size();
+    for y in size - 16..size {
+        modules[y * size + size - 1] ^= true;
+        modules[y * size + size - 2] ^= true;
+    }
+    let decoded = decode(&modules).unwrap();
+    assert_eq!(decoded.text, "ERRORS IN EVERY BLOCK");
+    assert_eq!(decoded.corrections, [1, 1, 1, 1]);
+
+    let mut modules = code.matrix().modules().to_vec();
+    for i in 0..9 {
+        modules[8 * size + i] ^= true;
+        modules[(size - 1 - i) + 8 * size] ^= true;
+    }
+    assert_eq!(decode(&modules), Err(DecodeError::FormatInformation));
+    assert_eq!(
+        decode(&[false; 22 * 22]),
+        Err(DecodeError::InvalidSize { modules: 484 })
+    );
+    assert_eq!(
+        decode(&[false; 20]),
+        Err(DecodeError::InvalidSize { modules: 20 })
+    );
+}
+
+#[test]
+fn segments() {
+    let segments = vec![
+        Segment::numeric("0123456789").unwrap(),
+        Segment::alphanumeric("AC-42").unwrap(),
+        Segment::byte("Grüße".as_bytes())
+            .with_eci(eci::UTF_8)
+            .unwrap(),
+        Segment::kanji("漢字").unwrap(),
+        Segment::byte(b"!").with_eci(70_000).unwrap(),
+    ];
+    // the character counts differ in width in each group of versions
+    for version in [QRCodeVersion::V3, QRCodeVersion::V10, QRCodeVersion::V27] {
+        let data = create_bits(&segments, version, ErrorCorrection::M).unwrap();
+        let bitstream = parse_segments(&data, version).unwrap();
+        assert_eq!(bitstream.segments, segments);
+        assert_eq!(bitstream.text(), "0123456789AC-42Grüße漢字!");
+    }
+
+    // a byte segment that is not UTF-8 leaves the others alone, while
+    // under ECI 26 it is read as UTF-8 regardless
+    let mixed = [Segment::kanji("漢字").unwrap(), Segment::byte(&[0xE9])];
+    let data = create_bits(&mixed, QRCodeVersion::V1, ErrorCorrection::L).unwrap();
+    let bitstream = parse_segments(&data, QRCodeVersion::V1).unwrap();
+    assert_eq!(bitstream.text(), "漢字é");
+    let declared = [
+        mixed[1].clone().with_eci(eci::UTF_8).unwrap(),
+        mixed[0].clone(),
+    ];
+    let data = create_bits(&declared, QRCodeVersion::V1, ErrorCorrection::L).unwrap();
+    let bitstream = parse_segments(&data, QRCodeVersion::V1).unwrap();
+    assert_eq!(bitstream.text(), "\u{FFFD}漢字");
+
+    let version = QRCodeVersion::V1;
+    let data = create_bits(&segments[..2], version, ErrorCorrection::L).unwrap();
+    // 4 + 10 + 34 bits of numeric then 4 + 9 + 28 of alphanumeric, the
+    // terminator ending at bit 93
+    assert_eq!(data[12..], [0xEC, 0x11, 0xEC, 0x11, 0xEC, 0x11, 0xEC]);
+    let mut wrong = data.clone();
+    wrong[11] |= 1;
+    assert_eq!(
+        parse_segments(&wrong, version),
+        Err(DecodeError::InvalidPadding { position: 95 })
+    );
+    let mut wrong = data;
+    wrong[13] = 0xEC;
+    assert_eq!(
+        parse_segments(&wrong, version),
+        Err(DecodeError::InvalidPadding { position: 13 * 8 })
+    );
+    // 38 bits, leaving only two bits of the terminator in five codewords
+    let digits = [Segment::numeric("0123456").unwrap()];
+    let data = create_bits(&digits, version, ErrorCorrection::H).unwrap();
+    assert_eq!(
+        parse_segments(&data[..5], version).unwrap().segments,
+        digits
+    );
+    assert_eq!(
+        parse_segments(&[0b0110_0000], version),
+        Err(DecodeError::InvalidMode { indicator: 0b0110 })
+    );
+    assert_eq!(
+        parse_segments(&data[..3], version),
+        Err(DecodeError::UnexpectedEnd)
+    );
+}
@@ -0,0 +1,329 @@
+//! Finding & sampling symbols in grayscale images
+//!
+//! The image is binarized with a threshold adapted to the neighbourhood
+//! of every pixel, then scanned for three finder patterns. Their centers,
+//! and that of the bottom right alignment pattern if there is one, give
+//! the perspective transform used to sample every module.
+use crate::{
+    decode::{decode, Decoded},
+    DecodeError, QRCodeVersion,
+};
+
+use self::{
+    finder::{
+        find_alignment_pattern, find_finder_patterns, module_size_towards, select_finder_patterns,
+        Pattern,
+    },
+    homography::Homography,
+};
+
+pub mod finder;
+pub mod homography;
+#[cfg(test)]
+mod test;
+
+/// Neighbourhoods with a lower standard deviation are thresholded with
+/// the global threshold instead of their mean
+const MIN_CONTRAST: f64 = 12.0;
+/// The search radii for the alignment pattern, in modules
+const ALIGNMENT_ALLOWANCES: [f64; 3] = [4.0, 8.0, 16.0];
+
+/// A binarized image
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bitmap {
+    width: usize,
+    height: usize,
+    dark: Vec<bool>,
+}
+
+impl Bitmap {
+    #[inline]
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+    #[inline]
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+    /// Returns whether the pixel at `(x, y)` is dark
+    ///
+    /// # Panics
+    ///
+    /// If the coordinates are out of bounds
+    #[inline]
+    #[must_use]
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        assert!(x < self.width && y < self.height, "pixel out of bounds");
+        self.dark[y * self.width + x]
+    }
+}
+
+/// Returns the threshold splitting the histogram of the pixels into the
+/// two classes of least variance, by Otsu's method
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn otsu_threshold(pixels: &[u8]) -> u8 {
+    let mut histogram = [0_u64; 256];
+    for &p in pixels {
+        histogram[usize::from(p)] += 1;
+    }
+    let total = pixels.len() as f64;
+    let sum = (0..=255_u8)
+        .map(|i| f64::from(i) * histogram[usize::from(i)] as f64)
+        .sum::<f64>();
+    let (mut background, mut background_sum) = (0.0, 0.0);
+    let (mut best, mut best_variance) = (0, 0.0);
+    for i in 0..=255_u8 {
+        background += histogram[usize::from(i)] as f64;
+        background_sum += f64::from(i) * histogram[usize::from(i)] as f64;
+        let foreground = total - background;
+        if background == 0.0 || foreground == 0.0 {
+            continue;
+        }
+        let difference = background_sum / background - (sum - background_sum) / foreground;
+        let variance = background * foreground * difference * difference;
+        if variance > best_variance {
+            (best, best_variance) = (i, variance);
+        }
+    }
+    // the classes are the pixels up to & including the threshold, and those
+    // above it
+    best.saturating_add(1)
+}
+
+/// Binarizes a grayscale image given row by row, `0` being black
+///
+/// Every pixel is compared to the mean of the square around it, of a
+/// sixteenth of the image's longer side. Where that square has little
+/// contrast, such as inside large dark or light areas, the
+/// [global threshold](otsu_threshold) is used instead.
+///
+/// # Errors
+///
+/// [`DecodeError::ImageSize`] if the number of pixels does not match
+#[allow(clippy::cast_precision_loss)]
+pub fn binarize(pixels: &[u8], width: usize, height: usize) -> Result<Bitmap, DecodeError> {
+    if width.checked_mul(height) != Some(pixels.len()) {
+        return Err(DecodeError::ImageSize {
+            expected: width.saturating_mul(height),
+            found: pixels.len(),
+        });
+    }
+    // summed area tables of the pixels and their squares, with a leading
+    // row & column of zeros
+    let stride = width + 1;
+    let mut sums = vec![0_u64; stride * (height + 1)];
+    let mut squares = vec![0_u64; stride * (height + 1)];
+    for y in 0..height {
+        let (mut row_sum, mut row_squares) = (0, 0);
+        for x in 0..width {
+            let p = u64::from(pixels[y * width + x]);
+            row_sum += p;
+            row_squares += p * p;
+            sums[(y + 1) * stride + x + 1] = sums[y * stride + x + 1] + row_sum;
+            squares[(y + 1) * stride + x + 1] = squares[y * stride + x + 1] + row_squares;
+        }
+    }
+    let area = |table: &[u64], (x0, y0): (usize, usize), (x1, y1): (usize, usize)| {
+        table[y1 * stride + x1] + table[y0 * stride + x0]
+            - table[y0 * stride + x1]
+            - table[y1 * stride + x0]
+    };
+
+    let global = otsu_threshold(pixels);
+    let radius = (width.max(height) / 32).max(4);
+    let mut dark = Vec::with_capacity(pixels.len());
+    for y in 0..height {
+        for x in 0..width {
+            let from = (x.saturating_sub(radius), y.saturating_sub(radius));
+            let to = ((x + radius + 1).min(width), (y + radius + 1).min(height));
+            let count = ((to.0 - from.0) * (to.1 - from.1)) as f64;
+            let mean = area(&sums, from, to) as f64 / count;
+            let variance = area(&squares, from, to) as f64 / count - mean * mean;
+            let p = pixels[y * width + x];
+            dark.push(if variance.max(0.0).sqrt() < MIN_CONTRAST {
+                p < global
+            } else {
+                f64::from(p) < mean
+            });
+        }
+    }
+    Ok(Bitmap {
+        width,
+        height,
+        dark,
+    })
+}
+
+/// A symbol found in an image
+#[derive(Debug, Clone, PartialEq)]
+pub struct Detection {
+    /// The top left, top right & bottom left finder patterns
+    pub finders: [Pattern; 3],
+    /// The bottom right alignment pattern, if there is one and it was found
+    pub alignment: Option<Pattern>,
+    /// The width & height of the symbol in modules
+    pub size: usize,
+    /// The sampled modules, row by row, ready to be [decoded](decode)
+    pub modules: Vec<bool>,
+}
+
+/// Returns the mean distance from the top left finder pattern to the
+/// other two, in pixels
+fn spacing([top_left, top_right, bottom_left]: [Pattern; 3]) -> f64 {
+    f64::midpoint(
+        top_left.distance(&top_right),
+        top_left.distance(&bottom_left),
+    )
+}
+
+/// Finds the finder patterns of a symbol, estimating its size from the
+/// distances between them
+fn locate(bitmap: &Bitmap) -> Result<([Pattern; 3], usize), DecodeError> {
+    let finders =
+        select_finder_patterns(&find_finder_patterns(bitmap)).ok_or(DecodeError::SymbolNotFound)?;
+    // the runs the patterns were found on are stretched when the symbol is
+    // turned, unlike those between their centers
+    let [top_left, top_right, bottom_left] = finders;
+    let measured = [
+        (top_left, top_right),
+        (top_right, top_left),
+        (top_left, bottom_left),
+        (bottom_left, top_left),
+    ]
+    .iter()
+    .filter_map(|(from, to)| module_size_towards(bitmap, from, to))
+    .collect::<Vec<_>>();
+    #[allow(clippy::cast_precision_loss)]
+    let module_size = if measured.is_empty() {
+        finders.iter().map(|p| p.module_size).sum::<f64>() / 3.0
+    } else {
+        measured.iter().sum::<f64>() / measured.len() as f64
+    };
+    let between = spacing(finders) / module_size;
+    // the nearest size of the form 4n + 17
+    let version = ((between + 7.0 - 17.0) / 4.0).round().max(1.0);
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let version =
+        QRCodeVersion::from_number(version as usize).ok_or(DecodeError::SymbolNotFound)?;
+    Ok((finders, version.size()))
+}
+
+/// Samples a symbol of the given size located by its finder patterns
+///
+/// From [`QRCodeVersion::V2`] onwards the bottom right alignment pattern
+/// is searched for around where the finder patterns place it. Without it
+/// the symbol is assumed to be a parallelogram.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn sample(bitmap: &Bitmap, finders: [Pattern; 3], size: usize) -> Detection {
+    let [top_left, top_right, bottom_left] = finders;
+    let dimension = size as f64;
+    // the distances between finder pattern centers span size - 7 modules
+    let module_size = spacing(finders) / (dimension - 7.0);
+    let bottom_right = (
+        top_right.x + bottom_left.x - top_left.x,
+        top_right.y + bottom_left.y - top_left.y,
+    );
+    let alignment = (size > 21)
+        .then(|| {
+            // the alignment pattern is 3 modules in from the finder centers
+            let ratio = 1.0 - 3.0 / (dimension - 7.0);
+            let estimate = (
+                (bottom_right.0 - top_left.x).mul_add(ratio, top_left.x),
+                (bottom_right.1 - top_left.y).mul_add(ratio, top_left.y),
+            );
+            ALIGNMENT_ALLOWANCES.iter().find_map(|allowance| {
+                find_alignment_pattern(bitmap, estimate, module_size, allowance * module_size)
+            })
+        })
+        .flatten();
+    let corner = alignment.map_or(((dimension - 3.5, dimension - 3.5), bottom_right), |p| {
+        ((dimension - 6.5, dimension - 6.5), p.position())
+    });
+    let homography = Homography::from_points(
+        [
+            (3.5, 3.5),
+            (dimension - 3.5, 3.5),
+            (3.5, dimension - 3.5),
+            corner.0,
+        ],
+        [
+            top_left.position(),
+            top_right.position(),
+            bottom_left.position(),
+            corner.1,
+        ],
+    )
+    .unwrap_or(Homography::IDENTITY);
+
+    let mut modules = Vec::with_capacity(size * size);
+    for y in 0..size {
+        for x in 0..size {
+            let (px, py) = homography.map((x as f64 + 0.5, y as f64 + 0.5));
+            modules.push(pixel(bitmap, px, py));
+        }
+    }
+    Detection {
+        finders,
+        alignment,
+        size,
+        modules,
+    }
+}
+
+/// Returns whether the pixel at the point is dark, anything outside of
+/// the image being light
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+fn pixel(bitmap: &Bitmap, x: f64, y: f64) -> bool {
+    let inside =
+        (0.0..bitmap.width() as f64).contains(&x) && (0.0..bitmap.height() as f64).contains(&y);
+    inside && bitmap.get(x as usize, y as usize)
+}
+
+/// Finds and samples a symbol in a grayscale image given row by row, `0`
+/// being black
+///
+/// # Errors
+///
+/// - [`DecodeError::ImageSize`] if the number of pixels does not match
+/// - [`DecodeError::SymbolNotFound`] if there are no three finder
+///   patterns in the shape of a symbol
+pub fn detect(pixels: &[u8], width: usize, height: usize) -> Result<Detection, DecodeError> {
+    let bitmap = binarize(pixels, width, height)?;
+    let (finders, size) = locate(&bitmap)?;
+    Ok(sample(&bitmap, finders, size))
+}
+
+/// Finds, samples & decodes a symbol in a grayscale image given row by
+/// row, `0` being black
+///
+/// The size estimated from the finder patterns may be off for large
+/// symbols, so the neighbouring sizes are tried as well if decoding fails.
+///
+/// # Errors
+///
+/// Any error from [`detect`], or the error decoding the estimated size
+pub fn decode_image(pixels: &[u8], width: usize, height: usize) -> Result<Decoded, DecodeError> {
+    let bitmap = binarize(pixels, width, height)?;
+    let (finders, size) = locate(&bitmap)?;
+    let mut error = None;
+    for size in [Some(size), size.checked_sub(4), Some(size + 4)] {
+        let Some(size) = size.filter(|size| (21..=177).contains(size)) else {
+            continue;
+        };
+        match decode(&sample(&bitmap, finders, size).modules) {
+            Ok(decoded) => return Ok(decoded),
+            Err(e) => {
+                error.get_or_insert(e);
+            }
+        }
+    }
+    Err(error.unwrap_or(DecodeError::SymbolNotFound))
+}
@@ -0,0 +1,349 @@
+//! Locating finder & alignment patterns in a binarized image
+use super::Bitmap;
+
+/// The relative widths of the runs crossing a finder pattern through its
+/// center, dark first
+const FINDER_RATIOS: [f64; 5] = [1.0, 1.0, 3.0, 1.0, 1.0];
+/// The number of candidates tried when choosing three finder patterns
+const MAX_CANDIDATES: usize = 12;
+
+/// A finder or alignment pattern found in an image
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pattern {
+    /// The horizontal position of the center, in pixels
+    pub x: f64,
+    /// The vertical position of the center, in pixels
+    pub y: f64,
+    /// The estimated width of a module, in pixels
+    pub module_size: f64,
+    /// The number of scanned rows the pattern was found on
+    pub count: usize,
+}
+
+impl Pattern {
+    #[inline]
+    #[must_use]
+    pub const fn position(&self) -> (f64, f64) {
+        (self.x, self.y)
+    }
+    #[inline]
+    #[must_use]
+    pub fn distance(&self, other: &Self) -> f64 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+    /// Returns whether the other pattern is at about the same place
+    /// with about the same module size
+    fn matches(&self, x: f64, y: f64, module_size: f64) -> bool {
+        (self.x - x).abs() <= self.module_size
+            && (self.y - y).abs() <= self.module_size
+            && (self.module_size - module_size).abs() <= self.module_size.max(1.0)
+    }
+    /// Averages the other pattern into this one
+    #[allow(clippy::cast_precision_loss)]
+    fn merge(&mut self, x: f64, y: f64, module_size: f64) {
+        let count = self.count as f64;
+        let average = |a: f64, b: f64| a.mul_add(count, b) / (count + 1.0);
+        self.x = average(self.x, x);
+        self.y = average(self.y, y);
+        self.module_size = average(self.module_size, module_size);
+        self.count += 1;
+    }
+}
+
+/// Returns the length of the run of modules of the given color starting
+/// at `from` and moving by `step`, reading `get` until it returns [`None`]
+fn run(get: &impl Fn(isize) -> Option<bool>, from: isize, step: isize, dark: bool) -> usize {
+    let mut length = 0;
+    let mut i = from;
+    while get(i) == Some(dark) {
+        length += 1;
+        i += step;
+    }
+    length
+}
+
+/// Measures the five runs crossing the dark run at `center` along a line,
+/// returning them along with the middle of the center run
+///
+/// The runs are dark, light, dark, light, dark.
+#[allow(clippy::cast_possible_wrap, clippy::cast_precision_loss)]
+fn cross(get: impl Fn(isize) -> Option<bool>, center: usize) -> Option<([usize; 5], f64)> {
+    let center = center as isize;
+    let before = run(&get, center, -1, true);
+    let after = run(&get, center + 1, 1, true);
+    if before == 0 {
+        return None;
+    }
+    let start = center + 1 - before as isize;
+    let end = center + after as isize;
+    let light_before = run(&get, start - 1, -1, false);
+    let dark_before = run(&get, start - 1 - light_before as isize, -1, true);
+    let light_after = run(&get, end + 1, 1, false);
+    let dark_after = run(&get, end + 1 + light_after as isize, 1, true);
+    let counts = [
+        dark_before,
+        light_before,
+        before + after,
+        light_after,
+        dark_after,
+    ];
+    Some((counts, start as f64 + (before + after) as f64 / 2.0))
+}
+
+/// Returns whether the runs follow the ratios, each within half a module
+#[allow(clippy::cast_precision_loss)]
+fn has_ratios(counts: [usize; 5], ratios: [f64; 5]) -> bool {
+    let total = counts.iter().sum::<usize>() as f64;
+    let module_size = total / ratios.iter().sum::<f64>();
+    counts.iter().zip(ratios).all(|(&count, ratio)| {
+        count > 0 && ratio.mul_add(-module_size, count as f64).abs() < ratio * module_size / 2.0
+    })
+}
+
+/// Reads a column of the bitmap
+#[allow(clippy::cast_sign_loss)]
+fn column(bitmap: &Bitmap, x: usize) -> impl Fn(isize) -> Option<bool> + '_ {
+    move |y| {
+        (0..bitmap.height().cast_signed())
+            .contains(&y)
+            .then(|| bitmap.get(x, y as usize))
+    }
+}
+
+/// Reads a row of the bitmap
+#[allow(clippy::cast_sign_loss)]
+fn row(bitmap: &Bitmap, y: usize) -> impl Fn(isize) -> Option<bool> + '_ {
+    move |x| {
+        (0..bitmap.width().cast_signed())
+            .contains(&x)
+            .then(|| bitmap.get(x as usize, y))
+    }
+}
+
+/// Returns the runs of a row as `(start, length, dark)`
+fn runs(bitmap: &Bitmap, y: usize, from: usize, to: usize) -> Vec<(usize, usize, bool)> {
+    let mut runs: Vec<(usize, usize, bool)> = Vec::new();
+    for x in from..to {
+        let dark = bitmap.get(x, y);
+        match runs.last_mut() {
+            Some((_, length, color)) if *color == dark => *length += 1,
+            _ => runs.push((x, 1, dark)),
+        }
+    }
+    runs
+}
+
+/// Returns every finder pattern candidate in the image, found by scanning
+/// each row for dark & light runs in the ratio `1:1:3:1:1`
+///
+/// Each candidate is checked along its column, then again along the row
+/// through its vertical center.
+#[must_use]
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+pub fn find_finder_patterns(bitmap: &Bitmap) -> Vec<Pattern> {
+    let mut patterns: Vec<Pattern> = Vec::new();
+    for y in 0..bitmap.height() {
+        for window in runs(bitmap, y, 0, bitmap.width()).windows(5) {
+            let counts: [usize; 5] = std::array::from_fn(|i| window[i].1);
+            if !window[0].2 || !has_ratios(counts, FINDER_RATIOS) {
+                continue;
+            }
+            let total = counts.iter().sum::<usize>() as f64;
+            let x = window[2].0 + window[2].1 / 2;
+            let Some((vertical, cy)) = cross(column(bitmap, x), y) else {
+                continue;
+            };
+            let vertical_total = vertical.iter().sum::<usize>() as f64;
+            if !has_ratios(vertical, FINDER_RATIOS)
+                || 5.0 * (vertical_total - total).abs() >= 2.0 * total
+            {
+                continue;
+            }
+            let Some((horizontal, cx)) = cross(row(bitmap, cy as usize), x) else {
+                continue;
+            };
+            if !has_ratios(horizontal, FINDER_RATIOS) {
+                continue;
+            }
+            let horizontal_total = horizontal.iter().sum::<usize>() as f64;
+            let module_size = (horizontal_total + vertical_total) / 14.0;
+            match patterns.iter_mut().find(|p| p.matches(cx, cy, module_size)) {
+                Some(pattern) => pattern.merge(cx, cy, module_size),
+                None => patterns.push(Pattern {
+                    x: cx,
+                    y: cy,
+                    module_size,
+                    count: 1,
+                }),
+            }
+        }
+    }
+    patterns.sort_by_key(|p| std::cmp::Reverse(p.count));
+    patterns
+}
+
+/// Measures the module size along the line from the center of a finder
+/// pattern towards another, which crosses the pattern squarely unlike
+/// the rows & columns it was found on
+///
+/// From the center, the dark core, the light ring & the dark ring span
+/// 3.5 modules before the light separator.
+#[must_use]
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+pub fn module_size_towards(bitmap: &Bitmap, from: &Pattern, to: &Pattern) -> Option<f64> {
+    let length = from.distance(to);
+    let (dx, dy) = ((to.x - from.x) / length, (to.y - from.y) / length);
+    let mut transitions = 0;
+    let mut dark = true;
+    // in half pixels, over at most the 7 modules of the pattern
+    for half in 0..(from.module_size * 14.0) as usize {
+        let step = half as f64 / 2.0;
+        let (x, y) = (dx.mul_add(step, from.x), dy.mul_add(step, from.y));
+        if x < 0.0 || y < 0.0 || x >= bitmap.width() as f64 || y >= bitmap.height() as f64 {
+            return None;
+        }
+        if bitmap.get(x as usize, y as usize) != dark {
+            dark = !dark;
+            transitions += 1;
+            if transitions == 3 {
+                return Some(step / 3.5);
+            }
+        }
+    }
+    None
+}
+
+/// Chooses the three finder patterns most likely to belong to one symbol,
+/// returned as top left, top right & bottom left
+///
+/// The patterns should have similar module sizes and form a right
+/// isosceles triangle, the top left being at its right angle. The others
+/// are told apart by the orientation of the triangle, so that the symbol
+/// is not read mirrored.
+#[must_use]
+pub fn select_finder_patterns(patterns: &[Pattern]) -> Option<[Pattern; 3]> {
+    let candidates = &patterns[..patterns.len().min(MAX_CANDIDATES)];
+    let mut best: Option<(f64, [Pattern; 3])> = None;
+    for (i, &a) in candidates.iter().enumerate() {
+        for (j, &b) in candidates.iter().enumerate().skip(i + 1) {
+            for &c in candidates.iter().skip(j + 1) {
+                let module_sizes = [a, b, c].map(|p| p.module_size);
+                let smallest = module_sizes.iter().copied().fold(f64::INFINITY, f64::min);
+                let largest = module_sizes.iter().copied().fold(0.0, f64::max);
+                // perspective & shear easily stretch one pattern's runs by
+                // half of another's
+                if largest > 2.0 * smallest {
+                    continue;
+                }
+                // the corner is opposite the longest side
+                let mut sides = [
+                    (b.distance(&c), a, b, c),
+                    (a.distance(&c), b, a, c),
+                    (a.distance(&b), c, a, b),
+                ];
+                sides.sort_by(|x, y| x.0.total_cmp(&y.0));
+                let (hypotenuse, corner, p, q) = sides[2];
+                let (leg1, leg2) = (sides[0].0, sides[1].0);
+                // at least the 14 modules between the centers of a V1 symbol
+                if leg1 < 12.0 * largest {
+                    continue;
+                }
+                let isosceles = (leg2 - leg1) / leg2;
+                let right = hypotenuse
+                    .mul_add(hypotenuse, -leg1.mul_add(leg1, leg2 * leg2))
+                    .abs()
+                    / hypotenuse.powi(2);
+                let score = isosceles + right + (largest - smallest) / largest;
+                if isosceles > 0.5 || right > 0.5 || best.is_some_and(|(s, _)| s <= score) {
+                    continue;
+                }
+                // clockwise in image coordinates, y pointing down
+                let cross =
+                    (p.x - corner.x).mul_add(q.y - corner.y, -(p.y - corner.y) * (q.x - corner.x));
+                let ordered = if cross > 0.0 {
+                    [corner, p, q]
+                } else {
+                    [corner, q, p]
+                };
+                best = Some((score, ordered));
+            }
+        }
+    }
+    best.map(|(_, patterns)| patterns)
+}
+
+/// Searches for the alignment pattern within `radius` pixels of the
+/// estimated position, returning the one closest to it
+///
+/// Alignment patterns are found as runs of one module each, light, dark &
+/// light, between dark runs, which is checked along the column as well.
+#[must_use]
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+pub fn find_alignment_pattern(
+    bitmap: &Bitmap,
+    (ex, ey): (f64, f64),
+    module_size: f64,
+    radius: f64,
+) -> Option<Pattern> {
+    let clamp = |value: f64, limit: usize| (value.max(0.0) as usize).min(limit);
+    let (left, right) = (
+        clamp(ex - radius, bitmap.width()),
+        clamp(ex + radius, bitmap.width()),
+    );
+    let (top, bottom) = (
+        clamp(ey - radius, bitmap.height()),
+        clamp(ey + radius, bitmap.height()),
+    );
+    let about_one = |count: usize| (count as f64 - module_size).abs() < module_size * 0.7;
+    let is_alignment = |counts: [usize; 5]| {
+        counts[0] > 0 && counts[4] > 0 && counts[1..4].iter().all(|&count| about_one(count))
+    };
+
+    let mut best: Option<(f64, Pattern)> = None;
+    for y in top..bottom {
+        for window in runs(bitmap, y, left, right).windows(5) {
+            let counts: [usize; 5] = std::array::from_fn(|i| window[i].1);
+            if !window[0].2 || !is_alignment(counts) {
+                continue;
+            }
+            let x = window[2].0 + window[2].1 / 2;
+            let Some((vertical, cy)) = cross(column(bitmap, x), y) else {
+                continue;
+            };
+            let Some((horizontal, cx)) = cross(row(bitmap, cy as usize), x) else {
+                continue;
+            };
+            if !is_alignment(vertical) || !is_alignment(horizontal) {
+                continue;
+            }
+            let distance = (cx - ex).hypot(cy - ey);
+            if best.is_none_or(|(d, _)| distance < d) {
+                let size = (vertical[1..4].iter().sum::<usize>()
+                    + horizontal[1..4].iter().sum::<usize>()) as f64
+                    / 6.0;
+                best = Some((
+                    distance,
+                    Pattern {
+                        x: cx,
+                        y: cy,
+                        module_size: size,
+                        count: 1,
+                    },
+                ));
+            }
+        }
+    }
+    best.map(|(_, pattern)| pattern)
+}
@@ -0,0 +1,68 @@
+//! Projective transforms between the plane of a symbol and an image
+
+/// A projective transform of the plane, mapping `(u, v)` to
+/// `((a u + b v + c) / (g u + h v + 1), (d u + e v + f) / (g u + h v + 1))`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Homography {
+    /// `[a, b, c, d, e, f, g, h]`
+    coefficients: [f64; 8],
+}
+
+impl Homography {
+    /// The transform leaving every point in place
+    pub const IDENTITY: Self = Self {
+        coefficients: [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0],
+    };
+
+    /// Returns the transform mapping each of the four source points onto
+    /// its destination
+    ///
+    /// # Returns
+    ///
+    /// [`None`] if three of the points are collinear
+    #[must_use]
+    pub fn from_points(from: [(f64, f64); 4], to: [(f64, f64); 4]) -> Option<Self> {
+        // two rows of the linear system per point, the last column being
+        // the right hand side
+        let mut system = [[0.0; 9]; 8];
+        for (i, ((u, v), (x, y))) in from.into_iter().zip(to).enumerate() {
+            system[2 * i] = [u, v, 1.0, 0.0, 0.0, 0.0, -u * x, -v * x, x];
+            system[2 * i + 1] = [0.0, 0.0, 0.0, u, v, 1.0, -u * y, -v * y, y];
+        }
+        solve(&mut system).map(|coefficients| Self { coefficients })
+    }
+    /// Maps a point through the transform
+    #[must_use]
+    #[allow(clippy::many_single_char_names)]
+    pub fn map(&self, (u, v): (f64, f64)) -> (f64, f64) {
+        let [a, b, c, d, e, f, g, h] = self.coefficients;
+        let w = g.mul_add(u, h * v) + 1.0;
+        (
+            a.mul_add(u, b.mul_add(v, c)) / w,
+            d.mul_add(u, e.mul_add(v, f)) / w,
+        )
+    }
+}
+
+/// Solves the linear system by Gaussian elimination with partial pivoting
+fn solve(system: &mut [[f64; 9]; 8]) -> Option<[f64; 8]> {
+    for column in 0..8 {
+        let pivot = (column..8)
+            .max_by(|&a, &b| system[a][column].abs().total_cmp(&system[b][column].abs()))?;
+        if system[pivot][column].abs() < 1e-12 {
+            return None;
+        }
+        system.swap(column, pivot);
+        let row = system[column];
+        for (i, other) in system.iter_mut().enumerate() {
+            if i != column {
+                let factor = other[column] / row[column];
+                other
+                    .iter_mut()
+                    .zip(row)
+                    .for_each(|(value, r)| *value -= factor * r);
+            }
+        }
+    }
+    Some(std::array::from_fn(|i| system[i][8] / system[i][i]))
+}
@@ -0,0 +1,163 @@
+use crate::{
+    detect::{binarize, decode_image, detect, homography::Homography, otsu_threshold},
+    render::Symbol,
+    DecodeError, ErrorCorrection, QrCode,
+};
+
+/// Renders the symbol into a grayscale image, its corners, quiet zone
+/// excluded, placed at the given points
+///
+/// The lighting fades from left to right, dark modules going from 20 to
+/// 90 and light ones from 250 to 150.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn render(code: &QrCode, corners: [(f64, f64); 4], width: usize, height: usize) -> Vec<u8> {
+    let size = code.width() as f64;
+    let to_modules = Homography::from_points(
+        corners,
+        [(0.0, 0.0), (size, 0.0), (0.0, size), (size, size)],
+    )
+    .unwrap();
+    let mut pixels = Vec::with_capacity(width * height);
+    for y in 0..height {
+        for x in 0..width {
+            let (u, v) = to_modules.map((x as f64 + 0.5, y as f64 + 0.5));
+            let inside = (0.0..size).contains(&u) && (0.0..size).contains(&v);
+            let dark = inside && code.is_dark(u as usize, v as usize);
+            let fade = x as f64 / width as f64;
+            pixels.push(if dark {
+                70.0f64.mul_add(fade, 20.0)
+            } else {
+                100.0f64.mul_add(-fade, 250.0)
+            } as u8);
+        }
+    }
+    pixels
+}
+
+#[test]
+fn homography() {
+    let square = [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (1.0, 1.0)];
+    let quad = [(10.0, 20.0), (50.0, 25.0), (5.0, 70.0), (60.0, 80.0)];
+    let transform = Homography::from_points(square, quad).unwrap();
+    for (from, to) in square.into_iter().zip(quad) {
+        let (x, y) = transform.map(from);
+        assert!((x - to.0).abs() < 1e-9 && (y - to.1).abs() < 1e-9);
+    }
+    assert_eq!(Homography::IDENTITY.map((3.0, 4.0)), (3.0, 4.0));
+    let line = [(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (3.0, 3.0)];
+    assert_eq!(Homography::from_points(line, quad), None);
+}
+
+#[test]
+fn binarization() {
+    assert_eq!(otsu_threshold(&[10, 10, 200, 200]), 11);
+    assert_eq!(
+        binarize(&[0; 10], 3, 3),
+        Err(DecodeError::ImageSize {
+            expected: 9,
+            found: 10
+        })
+    );
+    // a dark square much larger than the neighbourhood, on a light
+    // background getting darker to the right
+    let (width, height) = (200, 100);
+    let pixels = (0..width * height)
+        .map(|i| {
+            let (x, y) = (i % width, i / width);
+            if (20..120).contains(&x) && (10..90).contains(&y) {
+                30
+            } else {
+                240 - u8::try_from(x / 2).unwrap()
+            }
+        })
+        .collect::<Vec<_>>();
+    let bitmap = binarize(&pixels, width, height).unwrap();
+    for (x, y, dark) in [
+        (70, 50, true),
+        (21, 11, true),
+        (119, 89, true),
+        (5, 5, false),
+        (190, 50, false),
+    ] {
+        assert_eq!(bitmap.get(x, y), dark, "({x}, {y})");
+    }
+}
+
+#[test]
+fn straight() {
+    let code = QrCode::new("HELLO WORLD", ErrorCorrection::M).unwrap();
+    // 4 pixels per module with a 4 module quiet zone
+    let pixels = render(
+        &code,
+        [(16.0, 16.0), (100.0, 16.0), (16.0, 100.0), (100.0, 100.0)],
+        116,
+        116,
+    );
+    let detection = detect(&pixels, 116, 116).unwrap();
+    assert_eq!(detection.size, 21);
+    assert_eq!(detection.modules, code.matrix().modules());
+    let [top_left, top_right, bottom_left] = detection.finders;
+    assert!((top_left.x - 30.0).abs() < 1.0 && (top_left.y - 30.0).abs() < 1.0);
+    assert!((top_right.x - 86.0).abs() < 1.0 && (bottom_left.y - 86.0).abs() < 1.0);
+    assert!((top_left.module_size - 4.0).abs() < 0.5);
+    assert_eq!(decode_image(&pixels, 116, 116).unwrap().text, "HELLO WORLD");
+}
+
+#[test]
+fn rotated() {
+    let text = "ROTATED AND UNEVENLY LIT, WITH AN ALIGNMENT PATTERN";
+    let code = QrCode::new(text, ErrorCorrection::Q).unwrap();
+    assert!(code.width() > 21);
+    let (center, radius) = (150.0, 90.0_f64);
+    let corner = |angle: f64| {
+        let angle = angle.to_radians() + 0.5;
+        (
+            radius.mul_add(angle.cos(), center),
+            radius.mul_add(angle.sin(), center),
+        )
+    };
+    // turned by about 29 degrees clockwise
+    let corners = [corner(225.0), corner(315.0), corner(135.0), corner(45.0)];
+    let pixels = render(&code, corners, 300, 300);
+    let detection = detect(&pixels, 300, 300).unwrap();
+    assert_eq!(detection.size, code.width());
+    assert!(detection.alignment.is_some());
+    assert_eq!(detection.modules, code.matrix().modules());
+    assert_eq!(decode_image(&pixels, 300, 300).unwrap().text, text);
+}
+
+#[test]
+fn perspective() {
+    let text = "https://example.com/labels/0123456789?line=factory-floor&batch=42";
+    let code = QrCode::new(&text.repeat(2), ErrorCorrection::M).unwrap();
+    assert!(code.width() >= 45, "with version information");
+    let corners = [(60.0, 40.0), (330.0, 70.0), (40.0, 340.0), (350.0, 320.0)];
+    let pixels = render(&code, corners, 400, 400);
+    let decoded = decode_image(&pixels, 400, 400).unwrap();
+    assert_eq!(decoded.text, text.repeat(2));
+    assert_eq!(decoded.version, code.version());
+}
+
+#[test]
+fn nothing_found() {
+    assert_eq!(
+        decode_image(&[200; 100 * 80], 100, 80),
+        Err(DecodeError::SymbolNotFound)
+    );
+    let code = QrCode::new("CROPPED", ErrorCorrection::L).unwrap();
+    // the bottom left finder pattern is outside of the image
+    let pixels = render(
+        &code,
+        [(8.0, 8.0), (92.0, 8.0), (8.0, 92.0), (92.0, 92.0)],
+        100,
+        60,
+    );
+    assert_eq!(
+        detect(&pixels, 100, 60).map(|d| d.size),
+        Err(DecodeError::SymbolNotFound)
+    );
+}
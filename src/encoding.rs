@@ -1,7 +1,12 @@
 #![allow(clippy::cast_lossless)]
 #![allow(clippy::missing_panics_doc)]
 #![allow(clippy::cast_possible_truncation)]
-use crate::byte_stream::Bytes;
+use crate::{
+    byte_stream::Bytes,
+    kanji,
+    reed_solomon::{create_blocks, Block},
+    EncodeError,
+};
 
 use super::{EncodingMode, ErrorCorrection, QRCodeVersion};
 
@@ -17,37 +22,98 @@ pub trait Encodable {
     ///
     /// - `Vec<u8>`: The actual data bits
     /// - `EncodingMode`: The encoding mode which was determined
+    ///
+    /// # Errors
+    ///
+    /// - [`EncodeError::CountFieldOverflow`] if there are more characters
+    ///   than the character count can represent
+    /// - [`EncodeError::DataTooLong`] if the data does not fit
     fn create_bits(
         &self,
         version: QRCodeVersion,
         ec: ErrorCorrection,
-    ) -> Option<(Vec<u8>, EncodingMode)>;
+    ) -> Result<(Vec<u8>, EncodingMode), EncodeError>;
 }
 impl Encodable for str {
     fn create_bits(
         &self,
         version: QRCodeVersion,
         ec: ErrorCorrection,
-    ) -> Option<(Vec<u8>, EncodingMode)> {
+    ) -> Result<(Vec<u8>, EncodingMode), EncodeError> {
         use EncodingMode::*;
         let mode = EncodingMode::analyze_string(self);
+        let count = mode.character_count(self);
+        check_fit(version, ec, mode, count)?;
         let required_code_words = version.data_size(ec);
         let mut bytes = Bytes::with_capacity(required_code_words);
 
-        encode_start(&mut bytes, version, mode, self.len());
-        (match mode {
-            Numeric => encode_numeric,
-            Alphanumeric => encode_alphanumeric,
-            Byte => encode_byte,
-        })(self, &mut bytes);
+        encode_start(&mut bytes, version, mode, count);
+        match mode {
+            Numeric => encode_numeric(self, &mut bytes),
+            Alphanumeric => encode_alphanumeric(self, &mut bytes),
+            Byte => encode_byte(self, &mut bytes),
+            Kanji => encode_kanji(self, &mut bytes)?,
+        }
         encode_end(&mut bytes, required_code_words);
 
-        if bytes.len() > required_code_words {
-            None
-        } else {
-            Some((bytes.into_parts().0, mode))
+        Ok((bytes.into_parts().0, mode))
+    }
+}
+/// Creates the final codeword sequence from the data codewords
+/// returned by [`Encodable::create_bits`]
+///
+/// The data codewords of every block are interleaved column by column,
+/// followed by the error correction codewords in the same manner. Blocks
+/// of the second group hold one extra data codeword, which is placed after
+/// the last column shared by all blocks. Finally the version's
+/// [remainder bits](QRCodeVersion::remainder_bits) are appended.
+///
+/// # Panics
+///
+/// If `data` is shorter than [`QRCodeVersion::data_size`]
+#[must_use]
+pub fn interleave(data: &[u8], version: QRCodeVersion, ec: ErrorCorrection) -> Bytes {
+    interleave_blocks(&create_blocks(data, version, ec), version.remainder_bits())
+}
+/// Interleaves the codewords of the blocks as described by [`interleave`],
+/// appending `remainder` zero bits
+#[must_use]
+pub fn interleave_blocks(blocks: &[Block], remainder: u16) -> Bytes {
+    let longest = blocks.iter().map(|b| b.data.len()).max().unwrap_or(0);
+    let ec_bytes = blocks.first().map_or(0, |b| b.ec.len());
+    let total = blocks.iter().map(|b| b.data.len()).sum::<usize>() + ec_bytes * blocks.len();
+    let mut bytes = Bytes::with_capacity(total + 1);
+
+    for i in 0..longest {
+        blocks
+            .iter()
+            .filter_map(|b| b.data.get(i))
+            .for_each(|&byte| bytes.push_full_byte(byte));
+    }
+    for i in 0..ec_bytes {
+        for block in blocks {
+            bytes.push_full_byte(block.ec[i]);
         }
     }
+    if remainder != 0 {
+        bytes.push(0, remainder);
+    }
+    bytes
+}
+/// Encodes each character as its 13 bit [compacted](kanji::compact)
+/// Shift JIS code
+///
+/// # Errors
+///
+/// [`EncodeError::InvalidCharacterForMode`] if a character has no double
+/// byte Shift JIS code, in which case nothing is written
+#[inline]
+pub fn encode_kanji(s: &str, bytes: &mut Bytes) -> Result<(), EncodeError> {
+    EncodingMode::Kanji.check(s)?;
+    s.chars()
+        .filter_map(kanji::to_shift_jis)
+        .for_each(|code| bytes.push(kanji::compact(code), 13));
+    Ok(())
 }
 #[inline]
 pub fn encode_byte(s: &str, bytes: &mut Bytes) {
@@ -77,9 +143,85 @@ pub fn encode_numeric(s: &str, bytes: &mut Bytes) {
         })
         .for_each(|(bits, w)| bytes.push(bits, w));
 }
+/// Returns the number of bits needed to encode `count` characters in the
+/// given mode, including the mode indicator & character count
+///
+/// The count is given by [`EncodingMode::character_count`].
+#[inline]
+#[must_use]
+pub const fn bit_length(version: QRCodeVersion, mode: EncodingMode, count: usize) -> usize {
+    4 + count_bits_count(version, mode) as usize + data_bit_length(mode, count)
+}
+/// Returns the number of bits needed to encode `count` characters in the
+/// given mode, without any mode indicator or character count
+#[inline]
+#[must_use]
+pub const fn data_bit_length(mode: EncodingMode, count: usize) -> usize {
+    use EncodingMode::*;
+    match mode {
+        Numeric => 10 * (count / 3) + [0, 4, 7][count % 3],
+        Alphanumeric => 11 * (count / 2) + 6 * (count % 2),
+        Byte => 8 * count,
+        Kanji => 13 * count,
+    }
+}
+/// Returns the smallest version able to hold the given string at the given
+/// [error correction](ErrorCorrection)
+///
+/// Unlike [`QRCodeVersion::fit_version`], this accounts for the width of
+/// the character count changing at [`QRCodeVersion::V10`] and
+/// [`QRCodeVersion::V27`].
+///
+/// # Errors
+///
+/// The reason the string does not fit in [`QRCodeVersion::V40`]
+pub fn fit_version(s: &str, ec: ErrorCorrection) -> Result<QRCodeVersion, EncodeError> {
+    let mode = EncodingMode::analyze_string(s);
+    let count = mode.character_count(s);
+    for &version in QRCodeVersion::ALL {
+        if check_fit(version, ec, mode, count).is_ok() {
+            return Ok(version);
+        }
+    }
+    // fails, and reports why the largest version is not enough
+    check_fit(QRCodeVersion::V40, ec, mode, count).map(|()| QRCodeVersion::V40)
+}
+/// Checks that `count` characters of the given mode fit in the version
+const fn check_fit(
+    version: QRCodeVersion,
+    ec: ErrorCorrection,
+    mode: EncodingMode,
+    count: usize,
+) -> Result<(), EncodeError> {
+    let count_bits = count_bits_count(version, mode);
+    if count >= 1 << count_bits {
+        return Err(EncodeError::CountFieldOverflow {
+            count,
+            count_bits,
+            version,
+            mode,
+        });
+    }
+    let needed_bits = bit_length(version, mode, count);
+    let capacity_bits = version.data_size(ec) * 8;
+    if needed_bits > capacity_bits {
+        return Err(EncodeError::DataTooLong {
+            needed_bits,
+            capacity_bits,
+            version,
+            ec,
+        });
+    }
+    Ok(())
+}
 /// Returns the number of bits unused in the last inputted [byte](u8)
 #[inline]
-fn encode_start(bytes: &mut Bytes, version: QRCodeVersion, mode: EncodingMode, count: usize) {
+pub(crate) fn encode_start(
+    bytes: &mut Bytes,
+    version: QRCodeVersion,
+    mode: EncodingMode,
+    count: usize,
+) {
     let count_bits = count_bits_count(version, mode);
     let mode = mode as u16;
     bytes.push(mode, 4);
@@ -87,7 +229,7 @@ fn encode_start(bytes: &mut Bytes, version: QRCodeVersion, mode: EncodingMode, c
 }
 /// Adds the final bits including the terminator and filler bits
 #[inline]
-fn encode_end(bytes: &mut Bytes, required_code_words: usize) {
+pub(crate) fn encode_end(bytes: &mut Bytes, required_code_words: usize) {
     if (bytes.shift() > 4 || bytes.shift() == 0) && bytes.len() < required_code_words {
         bytes.push_full_byte(0);
     }
@@ -107,19 +249,21 @@ pub const fn count_bits_count(version: QRCodeVersion, encoding: EncodingMode) ->
         V1 | V2 | V3 | V4 | V5 | V6 | V7 | V8 | V9 => match encoding {
             Numeric => 10,
             Alphanumeric => 9,
-            Byte => 8,
+            Byte | Kanji => 8,
         },
         V10 | V11 | V12 | V13 | V14 | V15 | V16 | V17 | V18 | V19 | V20 | V21 | V22 | V23 | V24
         | V25 | V26 => match encoding {
             Numeric => 12,
             Alphanumeric => 11,
             Byte => 16,
+            Kanji => 10,
         },
         V27 | V28 | V29 | V30 | V31 | V32 | V33 | V34 | V35 | V36 | V37 | V38 | V39 | V40 => {
             match encoding {
                 Numeric => 14,
                 Alphanumeric => 13,
                 Byte => 16,
+                Kanji => 12,
             }
         }
     }
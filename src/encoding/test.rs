@@ -15,7 +15,7 @@ macro_rules! test {
         //
         $(
             match $s.create_bits($version, $ec) {
-                Some((bytes, _)) => {
+                Ok((bytes, _)) => {
                     assert_eq! (
                         bytes,
                         $a,
@@ -23,9 +23,9 @@ macro_rules! test {
                         $s
                     )
                 }
-                None => {
+                Err(e) => {
                     panic!(
-                        "{}.create_bits(...) returned None",
+                        "{}.create_bits(...) returned {e}",
                         $s
                     );
                 }
@@ -194,3 +194,142 @@ fn limit_byte() {
     encoded.extend([0x48, 0xFB, 0xFB, 0xF0, 0xEC]);
     test![QRCodeVersion::V40, ErrorCorrection::L, &chars, encoded];
 }
+
+#[test]
+fn interleave() {
+    use crate::{encoding::interleave, reed_solomon::create_blocks};
+
+    let (data, _) = "HELLO WORLD"
+        .create_bits(QRCodeVersion::V5, ErrorCorrection::Q)
+        .unwrap();
+    let blocks = create_blocks(&data, QRCodeVersion::V5, ErrorCorrection::Q);
+    let (bytes, shift) = interleave(&data, QRCodeVersion::V5, ErrorCorrection::Q).into_parts();
+
+    assert_eq!(shift, 7);
+    assert_eq!(bytes.len(), QRCodeVersion::V5.total_codewords() + 1);
+    assert_eq!(bytes[..4], [data[0], data[15], data[30], data[46]]);
+    // only the second group has a 16th data codeword
+    assert_eq!(bytes[60..62], [data[45], data[61]]);
+    assert_eq!(
+        bytes[62..66],
+        [
+            blocks[0].ec[0],
+            blocks[1].ec[0],
+            blocks[2].ec[0],
+            blocks[3].ec[0]
+        ]
+    );
+    assert_eq!(bytes[133], blocks[3].ec[17]);
+    assert_eq!(bytes[134], 0);
+}
+
+#[test]
+fn total_codewords() {
+    for (version, total) in [
+        (QRCodeVersion::V1, 26),
+        (QRCodeVersion::V7, 196),
+        (QRCodeVersion::V14, 581),
+        (QRCodeVersion::V28, 1921),
+        (QRCodeVersion::V40, 3706),
+    ] {
+        assert_eq!(version.total_codewords(), total, "{version:?}");
+    }
+}
+
+#[test]
+fn errors() {
+    use crate::{EncodeError, EncodingMode};
+
+    assert_eq!(
+        "a".repeat(256)
+            .create_bits(QRCodeVersion::V9, ErrorCorrection::L),
+        Err(EncodeError::CountFieldOverflow {
+            count: 256,
+            count_bits: 8,
+            version: QRCodeVersion::V9,
+            mode: EncodingMode::Byte,
+        })
+    );
+    assert_eq!(
+        "HELLO WORLD".create_bits(QRCodeVersion::V1, ErrorCorrection::H),
+        Err(EncodeError::DataTooLong {
+            needed_bits: 74,
+            capacity_bits: 72,
+            version: QRCodeVersion::V1,
+            ec: ErrorCorrection::H,
+        })
+    );
+    assert_eq!(
+        EncodingMode::Alphanumeric.check("HELLO world"),
+        Err(EncodeError::InvalidCharacterForMode {
+            mode: EncodingMode::Alphanumeric,
+            character: 'w',
+            index: 6,
+        })
+    );
+    assert_eq!(EncodingMode::Numeric.check("0123"), Ok(()));
+}
+
+#[test]
+fn kanji() {
+    use crate::{kanji, EncodingMode};
+
+    // from ISO/IEC 18004 section 7.4.6
+    assert_eq!(kanji::to_shift_jis('点'), Some(0x935F));
+    assert_eq!(kanji::compact(0x935F), 0x0D9F);
+    assert_eq!(kanji::to_shift_jis('茗'), Some(0xE4AA));
+    assert_eq!(kanji::compact(0xE4AA), 0x1AAA);
+    assert_eq!(kanji::to_shift_jis('a'), None);
+    assert_eq!(kanji::from_shift_jis(0x935F), Some('点'));
+    assert_eq!(kanji::from_shift_jis(0xE4AA), Some('茗'));
+    assert_eq!(kanji::from_shift_jis(0x8140 - 1), None);
+    // every code maps back to the character it was found for
+    let mut found = 0;
+    for code in 0x8140..=0xEBBF {
+        if let Some(c) = kanji::from_shift_jis(code) {
+            assert_eq!(kanji::to_shift_jis(c), Some(code));
+            found += 1;
+        }
+    }
+    assert_eq!(found, 6879);
+
+    assert_eq!(EncodingMode::analyze_string("点茗"), EncodingMode::Kanji);
+    assert_eq!(EncodingMode::analyze_string("点茗A"), EncodingMode::Byte);
+    assert_eq!(EncodingMode::analyze_string("9点"), EncodingMode::Byte);
+    assert_eq!(
+        EncodingMode::analyze_string("9A"),
+        EncodingMode::Alphanumeric
+    );
+
+    test![
+        QRCodeVersion::V1,
+        ErrorCorrection::H,
+        "点茗",
+        [0x80, 0x26, 0xCF, 0xEA, 0xA8, 0x00, 0xEC, 0x11, 0xEC]
+    ];
+
+    // characters without a Shift JIS code are rejected rather than skipped
+    let mut bytes = crate::byte_stream::Bytes::default();
+    assert_eq!(
+        crate::encoding::encode_kanji("点a茗", &mut bytes),
+        Err(crate::EncodeError::InvalidCharacterForMode {
+            mode: EncodingMode::Kanji,
+            character: 'a',
+            index: 3,
+        })
+    );
+    assert!(bytes.is_empty());
+    assert_eq!(
+        crate::segment::Segment {
+            mode: EncodingMode::Kanji,
+            data: "点a".into(),
+            eci: None,
+        }
+        .encode_data(&mut bytes),
+        Err(crate::EncodeError::InvalidCharacterForMode {
+            mode: EncodingMode::Kanji,
+            character: 'a',
+            index: 3,
+        })
+    );
+}
@@ -0,0 +1,251 @@
+//! Errors returned while encoding & decoding
+use std::fmt;
+
+use crate::{micro::MicroVersion, rmqr::RmqrVersion, EncodingMode, ErrorCorrection, QRCodeVersion};
+
+/// The reasons data may fail to be encoded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EncodeError {
+    /// The encoded data does not fit in the data codewords
+    DataTooLong {
+        /// The number of bits the data needs, including
+        /// mode indicators and character counts
+        needed_bits: usize,
+        /// The number of data bits the symbol can hold
+        capacity_bits: usize,
+        version: QRCodeVersion,
+        ec: ErrorCorrection,
+    },
+    /// A character cannot be represented in the chosen mode
+    InvalidCharacterForMode {
+        mode: EncodingMode,
+        character: char,
+        /// The byte index of the character within the input
+        index: usize,
+    },
+    /// An ECI assignment number is larger than `999999`
+    InvalidEciDesignator { designator: u32 },
+    /// The number of characters is too large for the character count field
+    CountFieldOverflow {
+        count: usize,
+        /// The width of the character count field
+        count_bits: u16,
+        version: QRCodeVersion,
+        mode: EncodingMode,
+    },
+    /// A GS1 Application Identifier is not made of 2 to 4 digits, or has
+    /// the wrong number of digits for its predefined length
+    InvalidApplicationIdentifier { ai: String },
+    /// A GS1 element string is not of the length predefined for its
+    /// Application Identifier
+    Gs1LengthMismatch {
+        ai: String,
+        expected: usize,
+        found: usize,
+    },
+    /// A GS1 element string holds a character not allowed for its
+    /// Application Identifier
+    InvalidGs1Character {
+        ai: String,
+        character: char,
+        /// The byte index of the character within the value
+        index: usize,
+    },
+    /// The check digit of a GTIN, SSCC or GLN is wrong
+    Gs1CheckDigit { ai: String, expected: u8, found: u8 },
+    /// The encoded data does not fit in a Micro QR Code symbol
+    MicroDataTooLong {
+        needed_bits: usize,
+        capacity_bits: usize,
+        version: MicroVersion,
+        ec: ErrorCorrection,
+    },
+    /// The Micro QR Code version does not offer the error correction level
+    UnsupportedMicroErrorCorrection {
+        version: MicroVersion,
+        ec: ErrorCorrection,
+    },
+    /// The Micro QR Code version does not offer the mode
+    UnsupportedMicroMode {
+        version: MicroVersion,
+        mode: EncodingMode,
+    },
+    /// Micro QR Code symbols cannot hold an ECI
+    EciInMicro { designator: u32 },
+    /// The encoded data does not fit in an rMQR symbol
+    RmqrDataTooLong {
+        needed_bits: usize,
+        capacity_bits: usize,
+        version: RmqrVersion,
+        ec: ErrorCorrection,
+    },
+    /// rMQR only offers error correction levels M and H
+    UnsupportedRmqrErrorCorrection { ec: ErrorCorrection },
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DataTooLong {
+                needed_bits,
+                capacity_bits,
+                version,
+                ec,
+            } => write!(
+                f,
+                "data needs {needed_bits} bits but {version:?}-{ec:?} only holds {capacity_bits}"
+            ),
+            Self::InvalidCharacterForMode {
+                mode,
+                character,
+                index,
+            } => write!(
+                f,
+                "{character:?} at index {index} cannot be encoded in {mode:?} mode"
+            ),
+            Self::InvalidEciDesignator { designator } => {
+                write!(f, "{designator} is not a valid ECI assignment number")
+            }
+            Self::CountFieldOverflow {
+                count,
+                count_bits,
+                version,
+                mode,
+            } => write!(
+                f,
+                "a count of {count} does not fit the {count_bits} bit count field of {mode:?} mode in {version:?}"
+            ),
+            Self::InvalidApplicationIdentifier { ai } => {
+                write!(f, "{ai:?} is not a valid GS1 Application Identifier")
+            }
+            Self::Gs1LengthMismatch {
+                ai,
+                expected,
+                found,
+            } => write!(
+                f,
+                "AI ({ai}) takes {expected} characters but {found} were given"
+            ),
+            Self::InvalidGs1Character {
+                ai,
+                character,
+                index,
+            } => write!(
+                f,
+                "{character:?} at index {index} is not allowed in AI ({ai})"
+            ),
+            Self::Gs1CheckDigit {
+                ai,
+                expected,
+                found,
+            } => write!(
+                f,
+                "AI ({ai}) has check digit {found} but {expected} was expected"
+            ),
+            Self::MicroDataTooLong {
+                needed_bits,
+                capacity_bits,
+                version,
+                ec,
+            } => write!(
+                f,
+                "data needs {needed_bits} bits but {version:?}-{ec:?} only holds {capacity_bits}"
+            ),
+            Self::UnsupportedMicroErrorCorrection { version, ec } => {
+                write!(f, "{version:?} does not support error correction {ec:?}")
+            }
+            Self::UnsupportedMicroMode { version, mode } => {
+                write!(f, "{version:?} does not support {mode:?} mode")
+            }
+            Self::EciInMicro { designator } => {
+                write!(f, "ECI {designator} cannot be used in a Micro QR Code")
+            }
+            Self::RmqrDataTooLong {
+                needed_bits,
+                capacity_bits,
+                version,
+                ec,
+            } => write!(
+                f,
+                "data needs {needed_bits} bits but {version:?}-{ec:?} only holds {capacity_bits}"
+            ),
+            Self::UnsupportedRmqrErrorCorrection { ec } => {
+                write!(f, "rMQR does not support error correction {ec:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EncodeError {}
+
+/// The reasons a symbol may fail to be decoded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The grid is not a square of a valid symbol size
+    InvalidSize {
+        /// The number of modules given
+        modules: usize,
+    },
+    /// Neither copy of the format information is close enough to a
+    /// valid code
+    FormatInformation,
+    /// Neither copy of the version information is close enough to a
+    /// valid code, or it disagrees with the size
+    VersionInformation,
+    /// A block holds more errors than its error correction codewords
+    /// can correct
+    TooManyErrors {
+        /// The index of the block, in the order of the data
+        block: usize,
+    },
+    /// A mode indicator is not one of the defined modes
+    InvalidMode { indicator: u8 },
+    /// The data ends in the middle of a segment
+    UnexpectedEnd,
+    /// A segment holds a value its mode cannot produce
+    InvalidSegmentData { mode: EncodingMode },
+    /// An ECI assignment number is not encoded in one, two or three bytes
+    InvalidEciDesignator,
+    /// The bits after the last segment are not a terminator followed by
+    /// zeros and alternating `0xEC` & `0x11` padding codewords
+    InvalidPadding {
+        /// The index of the first wrong bit
+        position: usize,
+    },
+    /// The pixel buffer does not match the width & height of the image
+    ImageSize { expected: usize, found: usize },
+    /// No three finder patterns forming a symbol were found in the image
+    SymbolNotFound,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSize { modules } => {
+                write!(f, "{modules} modules do not make up a symbol")
+            }
+            Self::FormatInformation => f.write_str("the format information is unreadable"),
+            Self::VersionInformation => f.write_str("the version information is unreadable"),
+            Self::TooManyErrors { block } => {
+                write!(f, "block {block} has too many errors to correct")
+            }
+            Self::InvalidMode { indicator } => {
+                write!(f, "{indicator:#06b} is not a valid mode indicator")
+            }
+            Self::UnexpectedEnd => f.write_str("the data ends in the middle of a segment"),
+            Self::InvalidSegmentData { mode } => {
+                write!(f, "invalid data in a {mode:?} mode segment")
+            }
+            Self::InvalidEciDesignator => f.write_str("invalid ECI assignment number"),
+            Self::InvalidPadding { position } => {
+                write!(f, "invalid terminator or padding at bit {position}")
+            }
+            Self::ImageSize { expected, found } => {
+                write!(f, "expected {expected} pixels but {found} were given")
+            }
+            Self::SymbolNotFound => f.write_str("no symbol was found in the image"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
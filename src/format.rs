@@ -0,0 +1,97 @@
+//! BCH encoded format & version information
+use crate::{mask::Mask, matrix::QrMatrix, ErrorCorrection, QRCodeVersion};
+
+#[cfg(test)]
+mod test;
+
+/// The generator polynomial of the (15, 5) BCH code,
+/// `x^10 + x^8 + x^5 + x^4 + x^2 + x + 1`
+pub(crate) const FORMAT_GENERATOR: u32 = 0b101_0011_0111;
+/// Applied to the format information so that it is never all zero
+const FORMAT_MASK: u16 = 0b101_0100_0001_0010;
+/// The generator polynomial of the (18, 6) BCH code,
+/// `x^12 + x^11 + x^10 + x^9 + x^8 + x^5 + x^2 + 1`
+pub(crate) const VERSION_GENERATOR: u32 = 0b1_1111_0010_0101;
+
+/// Returns the remainder of `data * x^degree` divided by the generator,
+/// whose highest power must be `degree`
+pub(crate) const fn bch_remainder(data: u32, generator: u32, degree: u32) -> u32 {
+    let mut remainder = data << degree;
+    while remainder >= 1 << degree {
+        let top = remainder.ilog2();
+        remainder ^= generator << (top - degree);
+    }
+    remainder
+}
+
+/// Returns the 15 bit format information for the given
+/// [error correction](ErrorCorrection) and [mask](Mask)
+///
+/// The five data bits are followed by ten BCH error correction
+/// bits, and the result is masked with `0b101010000010010`.
+#[inline]
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn format_bits(ec: ErrorCorrection, mask: Mask) -> u16 {
+    let data = (ec.bits() << 3 | mask as u16) as u32;
+    ((data << 10 | bch_remainder(data, FORMAT_GENERATOR, 10)) as u16) ^ FORMAT_MASK
+}
+
+/// Returns the 18 bit version information, which only exists from
+/// [`QRCodeVersion::V7`] onwards
+///
+/// The six bit version number is followed by twelve BCH error
+/// correction bits.
+#[inline]
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn version_bits(version: QRCodeVersion) -> Option<u32> {
+    if (version as u8) < QRCodeVersion::V7 as u8 {
+        return None;
+    }
+    let data = version.number() as u32;
+    Some(data << 12 | bch_remainder(data, VERSION_GENERATOR, 12))
+}
+
+impl QrMatrix {
+    /// Writes the format information into both of its locations,
+    /// around the top left finder pattern and split between the other two
+    pub fn place_format(&mut self, ec: ErrorCorrection, mask: Mask) {
+        let bits = format_bits(ec, mask);
+        let size = self.size();
+        let bit = |i: usize| bits >> i & 1 == 1;
+
+        for i in 0..6 {
+            self.set_function(8, i, bit(i));
+        }
+        self.set_function(8, 7, bit(6));
+        self.set_function(8, 8, bit(7));
+        self.set_function(7, 8, bit(8));
+        for i in 9..15 {
+            self.set_function(14 - i, 8, bit(i));
+        }
+
+        for i in 0..8 {
+            self.set_function(size - 1 - i, 8, bit(i));
+        }
+        for i in 8..15 {
+            self.set_function(8, size - 15 + i, bit(i));
+        }
+    }
+    /// Writes the version information into both of its locations, next to
+    /// the top right and bottom left finder patterns
+    ///
+    /// Does nothing before [`QRCodeVersion::V7`].
+    pub fn place_version(&mut self) {
+        let Some(bits) = version_bits(self.version()) else {
+            return;
+        };
+        let size = self.size();
+        for i in 0..18 {
+            let dark = bits >> i & 1 == 1;
+            let (a, b) = (size - 11 + i % 3, i / 3);
+            self.set_function(a, b, dark);
+            self.set_function(b, a, dark);
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{
+    format::{format_bits, version_bits},
+    mask::Mask,
+    matrix::QrMatrix,
+    ErrorCorrection, QRCodeVersion,
+};
+
+#[test]
+fn format() {
+    // from ISO/IEC 18004 Annex C
+    assert_eq!(
+        format_bits(ErrorCorrection::M, Mask::M5),
+        0b100_0000_1100_1110
+    );
+    assert_eq!(
+        format_bits(ErrorCorrection::L, Mask::M4),
+        0b110_0110_0010_1111
+    );
+    assert_eq!(
+        format_bits(ErrorCorrection::H, Mask::M7),
+        0b000_1000_0011_1011
+    );
+
+    let codes: Vec<_> = ErrorCorrection::ALL
+        .into_iter()
+        .flat_map(|ec| Mask::ALL.map(|mask| format_bits(ec, mask)))
+        .collect();
+    for (i, a) in codes.iter().enumerate() {
+        for b in &codes[i + 1..] {
+            assert!((a ^ b).count_ones() >= 7, "{a:015b} {b:015b}");
+        }
+    }
+}
+
+#[test]
+fn version() {
+    assert_eq!(version_bits(QRCodeVersion::V6), None);
+    // from ISO/IEC 18004 Annex D
+    assert_eq!(version_bits(QRCodeVersion::V7), Some(0x07C94));
+    assert_eq!(version_bits(QRCodeVersion::V21), Some(0x15683));
+    assert_eq!(version_bits(QRCodeVersion::V40), Some(0x28C69));
+
+    let codes: Vec<_> = QRCodeVersion::ALL
+        .iter()
+        .filter_map(|&v| version_bits(v))
+        .collect();
+    assert_eq!(codes.len(), 34);
+    for (i, a) in codes.iter().enumerate() {
+        for b in &codes[i + 1..] {
+            assert!((a ^ b).count_ones() >= 8, "{a:018b} {b:018b}");
+        }
+    }
+}
+
+#[test]
+fn placement() {
+    let mut matrix = QrMatrix::new(QRCodeVersion::V7);
+    matrix.place_format(ErrorCorrection::M, Mask::M5);
+    let bits = format_bits(ErrorCorrection::M, Mask::M5);
+    let size = matrix.size();
+
+    // around the top left finder, most significant bit first
+    let first: Vec<_> = (0..=5)
+        .map(|x| (x, 8))
+        .chain([(7, 8), (8, 8), (8, 7)])
+        .chain((0..=5).rev().map(|y| (8, y)))
+        .collect();
+    // below the top right finder, then to the right of the bottom left one
+    let second: Vec<_> = (size - 7..size)
+        .rev()
+        .map(|y| (8, y))
+        .chain((size - 8..size).map(|x| (x, 8)))
+        .collect();
+    for copy in [first, second] {
+        let read = copy
+            .iter()
+            .fold(0, |acc, &(x, y)| acc << 1 | u16::from(matrix.get(x, y)));
+        assert_eq!(read, bits);
+    }
+
+    let version = version_bits(QRCodeVersion::V7).unwrap();
+    for i in 0..18 {
+        let dark = version >> i & 1 == 1;
+        assert_eq!(matrix.get(size - 11 + i % 3, i / 3), dark);
+        assert_eq!(matrix.get(i / 3, size - 11 + i % 3), dark);
+    }
+    // the dark module survives
+    assert!(matrix.get(8, size - 8));
+}
@@ -0,0 +1,92 @@
+//! Arithmetic over GF(256), using the primitive polynomial
+//! `x^8 + x^4 + x^3 + x^2 + 1` which QR Codes are defined with
+#![allow(clippy::cast_possible_truncation)]
+
+/// The primitive polynomial, `0b1_0001_1101`
+const PRIMITIVE: u16 = 0x11d;
+
+/// Powers of the generator `α = 2`
+///
+/// The table is doubled so that the sum of two logarithms can be
+/// looked up without reducing it modulo 255 first.
+pub const EXP: [u8; 512] = {
+    let mut table = [0; 512];
+    let mut value: u16 = 1;
+    let mut i = 0;
+    while i < 255 {
+        table[i] = value as u8;
+        table[i + 255] = value as u8;
+        value <<= 1;
+        if value > 0xff {
+            value ^= PRIMITIVE;
+        }
+        i += 1;
+    }
+    table
+};
+
+/// Discrete logarithms base `α`
+///
+/// `LOG[0]` is meaningless and set to `0`.
+pub const LOG: [u8; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 255 {
+        table[EXP[i] as usize] = i as u8;
+        i += 1;
+    }
+    table
+};
+
+/// Returns `α^n`
+#[inline]
+#[must_use]
+pub const fn exp(n: usize) -> u8 {
+    EXP[n % 255]
+}
+/// Returns the discrete logarithm of `a`
+///
+/// # Panics
+///
+/// If `a` is zero
+#[inline]
+#[must_use]
+pub const fn log(a: u8) -> u8 {
+    assert!(a != 0, "the logarithm of zero is undefined");
+    LOG[a as usize]
+}
+/// Multiplies two field elements
+#[inline]
+#[must_use]
+pub const fn mul(a: u8, b: u8) -> u8 {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        EXP[LOG[a as usize] as usize + LOG[b as usize] as usize]
+    }
+}
+/// Divides `a` by `b`
+///
+/// # Panics
+///
+/// If `b` is zero
+#[inline]
+#[must_use]
+pub const fn div(a: u8, b: u8) -> u8 {
+    assert!(b != 0, "division by zero");
+    if a == 0 {
+        0
+    } else {
+        EXP[LOG[a as usize] as usize + 255 - LOG[b as usize] as usize]
+    }
+}
+/// Returns the multiplicative inverse of `a`
+///
+/// # Panics
+///
+/// If `a` is zero
+#[inline]
+#[must_use]
+pub const fn inv(a: u8) -> u8 {
+    div(1, a)
+}
@@ -0,0 +1,241 @@
+//! FNC1 mode, marking data as GS1 element strings or as following
+//! another industry standard
+use crate::{
+    segment::{optimize_fnc1, Segment},
+    EncodeError, ErrorCorrection, QRCodeVersion, QrCode,
+};
+
+#[cfg(test)]
+mod test;
+
+/// The group separator, ending a variable length element string which
+/// is followed by another one
+pub const GS: char = '\x1D';
+
+/// Where FNC1 appears in the symbol, which decides how the data is read
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Fnc1 {
+    /// The data is made of GS1 element strings
+    First,
+    /// The data follows the industry standard identified by the
+    /// application indicator
+    Second {
+        /// A letter as its ASCII value plus `100`, or a two digit number
+        application_indicator: u8,
+    },
+}
+
+impl Fnc1 {
+    /// The mode indicator of FNC1 in the first position
+    pub const FIRST_MODE: u16 = 0b0101;
+    /// The mode indicator of FNC1 in the second position
+    pub const SECOND_MODE: u16 = 0b1001;
+
+    /// Creates FNC1 in the second position with an application indicator
+    /// of a single ASCII letter
+    #[must_use]
+    pub const fn with_letter(letter: u8) -> Option<Self> {
+        if letter.is_ascii_alphabetic() {
+            Some(Self::Second {
+                application_indicator: letter + 100,
+            })
+        } else {
+            None
+        }
+    }
+    /// Creates FNC1 in the second position with an application indicator
+    /// of two digits
+    #[must_use]
+    pub const fn with_number(number: u8) -> Option<Self> {
+        if number < 100 {
+            Some(Self::Second {
+                application_indicator: number,
+            })
+        } else {
+            None
+        }
+    }
+    /// Returns the mode indicator, followed by the application indicator
+    /// in the second position, as `(value, width)` pairs
+    #[must_use]
+    pub fn fields(&self) -> Vec<(u16, u16)> {
+        match *self {
+            Self::First => vec![(Self::FIRST_MODE, 4)],
+            Self::Second {
+                application_indicator,
+            } => vec![
+                (Self::SECOND_MODE, 4),
+                (u16::from(application_indicator), 8),
+            ],
+        }
+    }
+    /// Returns the number of bits taken up by [`Fnc1::fields`]
+    #[inline]
+    #[must_use]
+    pub const fn bit_length(&self) -> usize {
+        match self {
+            Self::First => 4,
+            Self::Second { .. } => 12,
+        }
+    }
+}
+
+/// Builds GS1 data from Application Identifiers and their values,
+/// inserting a [`GS`] after every element of variable length that is not
+/// the last one
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Gs1Builder {
+    data: String,
+    /// Whether the last element has a variable length
+    separate: bool,
+}
+
+impl Gs1Builder {
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            data: String::new(),
+            separate: false,
+        }
+    }
+    /// Appends an element string
+    ///
+    /// Elements with a predefined length must match it and hold only
+    /// digits, and the check digit of a GTIN, SSCC or GLN must be right.
+    /// Values may only hold the characters of GS1 character set 82.
+    ///
+    /// # Errors
+    ///
+    /// - [`EncodeError::InvalidApplicationIdentifier`] if the AI is not made
+    ///   of 2 to 4 digits, or not as many as its predefined length requires
+    /// - [`EncodeError::Gs1LengthMismatch`] if the value is not of the
+    ///   predefined length
+    /// - [`EncodeError::InvalidGs1Character`] if the value holds a character
+    ///   not allowed for it
+    /// - [`EncodeError::Gs1CheckDigit`] if the check digit is wrong
+    pub fn push(mut self, ai: &str, value: &str) -> Result<Self, EncodeError> {
+        let invalid_ai = || EncodeError::InvalidApplicationIdentifier { ai: ai.to_owned() };
+        if !(2..=4).contains(&ai.len()) || !ai.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid_ai());
+        }
+        let fixed = predefined_length(ai);
+        if let Some((ai_len, expected)) = fixed {
+            if ai.len() != ai_len {
+                return Err(invalid_ai());
+            }
+            if value.len() != expected {
+                return Err(EncodeError::Gs1LengthMismatch {
+                    ai: ai.to_owned(),
+                    expected,
+                    found: value.len(),
+                });
+            }
+        }
+        let allowed = |c: char| {
+            if fixed.is_some() {
+                c.is_ascii_digit()
+            } else {
+                is_cset82(c)
+            }
+        };
+        if let Some((index, character)) = value.char_indices().find(|&(_, c)| !allowed(c)) {
+            return Err(EncodeError::InvalidGs1Character {
+                ai: ai.to_owned(),
+                character,
+                index,
+            });
+        }
+        if has_check_digit(ai) {
+            let (digits, last) = value.split_at(value.len() - 1);
+            let found = last.as_bytes()[0] - b'0';
+            // the values of these AIs are all digits, as checked above
+            if let Some(expected) = check_digit(digits).filter(|&expected| expected != found) {
+                return Err(EncodeError::Gs1CheckDigit {
+                    ai: ai.to_owned(),
+                    expected,
+                    found,
+                });
+            }
+        }
+
+        if self.separate {
+            self.data.push(GS);
+        }
+        self.data.push_str(ai);
+        self.data.push_str(value);
+        self.separate = fixed.is_none();
+        Ok(self)
+    }
+    /// Returns the element strings, separated by [`GS`] where needed
+    #[inline]
+    #[must_use]
+    pub fn element_string(&self) -> &str {
+        &self.data
+    }
+    /// Returns the [optimal segments](optimize_fnc1) for the given version
+    #[inline]
+    #[must_use]
+    pub fn segments(&self, version: QRCodeVersion) -> Vec<Segment> {
+        optimize_fnc1(&self.data, version)
+    }
+    /// Encodes the element strings after [`Fnc1::First`] using the smallest
+    /// version they fit in
+    ///
+    /// # Errors
+    ///
+    /// If the data does not fit in any version
+    pub fn build(&self, ec: ErrorCorrection) -> Result<QrCode, EncodeError> {
+        QrCode::with_fnc1(&self.data, Fnc1::First, ec)
+    }
+}
+
+/// Returns the number of digits of the AI and the length of its value,
+/// for the AIs whose length is predefined by the GS1 General Specifications
+fn predefined_length(ai: &str) -> Option<(usize, usize)> {
+    Some(match &ai[..2] {
+        "00" => (2, 18),
+        "01" | "02" | "03" => (2, 14),
+        "04" => (2, 16),
+        "11" | "12" | "13" | "14" | "15" | "16" | "17" | "18" | "19" => (2, 6),
+        "20" => (2, 2),
+        "31" | "32" | "33" | "34" | "35" | "36" => (4, 6),
+        "41" => (3, 13),
+        _ => return None,
+    })
+}
+
+/// Returns whether the value of the AI ends in a check digit: the SSCC,
+/// GTINs and GLNs
+fn has_check_digit(ai: &str) -> bool {
+    matches!(&ai[..2], "00" | "01" | "02" | "41")
+}
+
+/// Returns the GS1 mod 10 check digit of the digits
+///
+/// Digits are weighted `3` and `1` alternately, starting from the
+/// rightmost one.
+///
+/// # Returns
+///
+/// [`None`] if anything but an ASCII digit is given
+#[must_use]
+pub fn check_digit(digits: &str) -> Option<u8> {
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let sum: u32 = digits
+        .bytes()
+        .rev()
+        .enumerate()
+        .map(|(i, b)| u32::from(b - b'0') * if i % 2 == 0 { 3 } else { 1 })
+        .sum();
+    #[allow(clippy::cast_possible_truncation)]
+    let digit = ((10 - sum % 10) % 10) as u8;
+    Some(digit)
+}
+
+/// Returns whether the character is in GS1 character set 82
+const fn is_cset82(c: char) -> bool {
+    matches!(c, '!' | '"' | '%'..='?' | 'A'..='Z' | '_' | 'a'..='z')
+}
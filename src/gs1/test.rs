@@ -0,0 +1,126 @@
+use crate::{
+    gs1::{check_digit, Fnc1, Gs1Builder, GS},
+    segment::{optimize_fnc1, Segment},
+    EncodeError, EncodingMode, ErrorCorrection, QRCodeVersion,
+};
+
+#[test]
+fn check_digits() {
+    assert_eq!(check_digit("0950600013435"), Some(2));
+    assert_eq!(check_digit("400638133393"), Some(1));
+    assert_eq!(check_digit("00614141123456789"), Some(0));
+    assert_eq!(check_digit(""), Some(0));
+    // below `0`, above `9` & not ASCII
+    assert_eq!(check_digit("09506 0001343"), None);
+    assert_eq!(check_digit("095060001343A"), None);
+    assert_eq!(check_digit("０９５"), None);
+}
+
+#[test]
+fn fnc1() {
+    assert_eq!(Fnc1::First.fields(), [(0b0101, 4)]);
+    assert_eq!(
+        Fnc1::with_letter(b'a'),
+        Some(Fnc1::Second {
+            application_indicator: 197
+        })
+    );
+    assert_eq!(Fnc1::with_letter(b'1'), None);
+    assert_eq!(
+        Fnc1::with_number(37),
+        Some(Fnc1::Second {
+            application_indicator: 37
+        })
+    );
+    assert_eq!(Fnc1::with_number(100), None);
+    let second = Fnc1::with_number(37).unwrap();
+    assert_eq!(second.fields(), [(0b1001, 4), (37, 8)]);
+    assert_eq!(second.bit_length(), 12);
+}
+
+#[test]
+fn element_strings() {
+    let builder = Gs1Builder::new()
+        .push("01", "09506000134352")
+        .and_then(|b| b.push("10", "ABC123"))
+        .and_then(|b| b.push("17", "250101"))
+        .and_then(|b| b.push("21", "x%1"))
+        .unwrap();
+    assert_eq!(
+        builder.element_string(),
+        format!("010950600013435210ABC123{GS}1725010121x%1")
+    );
+    assert!(builder.build(ErrorCorrection::M).is_ok());
+}
+
+#[test]
+fn invalid_elements() {
+    let push = |ai: &str, value: &str| Gs1Builder::new().push(ai, value).map(|_| ());
+    let ai = |ai: &str| ai.to_owned();
+    assert_eq!(
+        push("1", "A"),
+        Err(EncodeError::InvalidApplicationIdentifier { ai: ai("1") })
+    );
+    assert_eq!(
+        push("10A", "A"),
+        Err(EncodeError::InvalidApplicationIdentifier { ai: ai("10A") })
+    );
+    // net weight takes a four digit AI
+    assert_eq!(
+        push("310", "000123"),
+        Err(EncodeError::InvalidApplicationIdentifier { ai: ai("310") })
+    );
+    assert_eq!(
+        push("17", "2501"),
+        Err(EncodeError::Gs1LengthMismatch {
+            ai: ai("17"),
+            expected: 6,
+            found: 4
+        })
+    );
+    assert_eq!(
+        push("17", "25O101"),
+        Err(EncodeError::InvalidGs1Character {
+            ai: ai("17"),
+            character: 'O',
+            index: 2
+        })
+    );
+    assert_eq!(
+        push("10", "A#1"),
+        Err(EncodeError::InvalidGs1Character {
+            ai: ai("10"),
+            character: '#',
+            index: 1
+        })
+    );
+    assert_eq!(
+        push("01", "09506000134353"),
+        Err(EncodeError::Gs1CheckDigit {
+            ai: ai("01"),
+            expected: 2,
+            found: 3
+        })
+    );
+    assert_eq!(push("3103", "000123"), Ok(()));
+    assert_eq!(push("414", "4006381333931"), Ok(()));
+}
+
+#[test]
+fn separators() {
+    // the separator and percent signs are escaped in alphanumeric mode
+    assert_eq!(
+        optimize_fnc1("A%B\u{1d}CD", QRCodeVersion::V1),
+        [Segment::alphanumeric("A%%B%CD").unwrap()]
+    );
+    // but left as is in byte mode
+    assert_eq!(
+        optimize_fnc1("a%b\u{1d}c", QRCodeVersion::V1),
+        [Segment::byte(b"a%b\x1Dc")]
+    );
+    let segments = optimize_fnc1("0109506000134352\u{1d}10ABC", QRCodeVersion::V1);
+    assert_eq!(segments[0].mode, EncodingMode::Numeric);
+    assert!(segments
+        .iter()
+        .any(|s| s.mode == EncodingMode::Alphanumeric && s.data.contains(&b'%')));
+}
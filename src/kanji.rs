@@ -0,0 +1,59 @@
+//! Conversion between Unicode and the Shift JIS codes used by Kanji mode
+mod table;
+
+/// Returns the Shift JIS code of the given character, if it can be
+/// encoded in Kanji mode
+#[inline]
+#[must_use]
+pub fn to_shift_jis(c: char) -> Option<u16> {
+    table::CHARS
+        .binary_search(&c)
+        .ok()
+        .map(|i| table::SHIFT_JIS[i])
+}
+
+/// Returns whether the given character can be encoded in Kanji mode
+#[inline]
+#[must_use]
+pub fn is_kanji(c: char) -> bool {
+    to_shift_jis(c).is_some()
+}
+
+/// Compacts a double byte Shift JIS code into the 13 bits used by Kanji mode
+///
+/// `0x8140` or `0xC140` is subtracted depending on the range the code is in,
+/// then the high byte is multiplied by `0xC0` and added to the low byte.
+#[inline]
+#[must_use]
+pub const fn compact(code: u16) -> u16 {
+    let code = if code >= 0xE040 {
+        code - 0xC140
+    } else {
+        code - 0x8140
+    };
+    (code >> 8) * 0xC0 + (code & 0xFF)
+}
+
+/// Returns the character of the given Shift JIS code, if it can be
+/// encoded in Kanji mode
+#[inline]
+#[must_use]
+pub fn from_shift_jis(code: u16) -> Option<char> {
+    table::BY_SHIFT_JIS
+        .binary_search_by_key(&code, |&i| table::SHIFT_JIS[usize::from(i)])
+        .ok()
+        .map(|i| table::CHARS[usize::from(table::BY_SHIFT_JIS[i])])
+}
+
+/// Expands the 13 bits used by Kanji mode back into a double byte Shift
+/// JIS code, reversing [`compact`]
+#[inline]
+#[must_use]
+pub const fn expand(bits: u16) -> u16 {
+    let code = ((bits / 0xC0) << 8) | (bits % 0xC0);
+    if code >= 0x1F00 {
+        code + 0xC140
+    } else {
+        code + 0x8140
+    }
+}
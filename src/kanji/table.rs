@@ -0,0 +1,1441 @@
+//! The characters of JIS X 0208 which Kanji mode can encode, along with
+//! their Shift JIS codes
+//!
+//! Only codes within `0x8140..=0x9FFC` and `0xE040..=0xEBBF` are included.
+
+/// Every encodable character, in ascending order
+pub(super) static CHARS: [char; 6879] = [
+    '¢', '£', '§', '¨', '¬', '°', '±', '´', '¶', '×', '÷', 'Α', 'Β', 'Γ', 'Δ', 'Ε', 'Ζ', 'Η', 'Θ',
+    'Ι', 'Κ', 'Λ', 'Μ', 'Ν', 'Ξ', 'Ο', 'Π', 'Ρ', 'Σ', 'Τ', 'Υ', 'Φ', 'Χ', 'Ψ', 'Ω', 'α', 'β', 'γ',
+    'δ', 'ε', 'ζ', 'η', 'θ', 'ι', 'κ', 'λ', 'μ', 'ν', 'ξ', 'ο', 'π', 'ρ', 'σ', 'τ', 'υ', 'φ', 'χ',
+    'ψ', 'ω', 'Ё', 'А', 'Б', 'В', 'Г', 'Д', 'Е', 'Ж', 'З', 'И', 'Й', 'К', 'Л', 'М', 'Н', 'О', 'П',
+    'Р', 'С', 'Т', 'У', 'Ф', 'Х', 'Ц', 'Ч', 'Ш', 'Щ', 'Ъ', 'Ы', 'Ь', 'Э', 'Ю', 'Я', 'а', 'б', 'в',
+    'г', 'д', 'е', 'ж', 'з', 'и', 'й', 'к', 'л', 'м', 'н', 'о', 'п', 'р', 'с', 'т', 'у', 'ф', 'х',
+    'ц', 'ч', 'ш', 'щ', 'ъ', 'ы', 'ь', 'э', 'ю', 'я', 'ё', '‐', '―', '‖', '‘', '’', '“', '”', '†',
+    '‡', '‥', '…', '‰', '′', '″', '※', '℃', '\u{212b}', '←', '↑', '→', '↓', '⇒', '⇔', '∀', '∂',
+    '∃', '∇', '∈', '∋', '−', '√', '∝', '∞', '∠', '∧', '∨', '∩', '∪', '∫', '∬', '∴', '∵', '∽', '≒',
+    '≠', '≡', '≦', '≧', '≪', '≫', '⊂', '⊃', '⊆', '⊇', '⊥', '⌒', '─', '━', '│', '┃', '┌', '┏', '┐',
+    '┓', '└', '┗', '┘', '┛', '├', '┝', '┠', '┣', '┤', '┥', '┨', '┫', '┬', '┯', '┰', '┳', '┴', '┷',
+    '┸', '┻', '┼', '┿', '╂', '╋', '■', '□', '▲', '△', '▼', '▽', '◆', '◇', '○', '◎', '●', '◯', '★',
+    '☆', '♀', '♂', '♪', '♭', '♯', '\u{3000}', '、', '。', '〃', '々', '〆', '〇', '〈', '〉', '《',
+    '》', '「', '」', '『', '』', '【', '】', '〒', '〓', '〔', '〕', '〜', 'ぁ', 'あ', 'ぃ', 'い',
+    'ぅ', 'う', 'ぇ', 'え', 'ぉ', 'お', 'か', 'が', 'き', 'ぎ', 'く', 'ぐ', 'け', 'げ', 'こ', 'ご',
+    'さ', 'ざ', 'し', 'じ', 'す', 'ず', 'せ', 'ぜ', 'そ', 'ぞ', 'た', 'だ', 'ち', 'ぢ', 'っ', 'つ',
+    'づ', 'て', 'で', 'と', 'ど', 'な', 'に', 'ぬ', 'ね', 'の', 'は', 'ば', 'ぱ', 'ひ', 'び', 'ぴ',
+    'ふ', 'ぶ', 'ぷ', 'へ', 'べ', 'ぺ', 'ほ', 'ぼ', 'ぽ', 'ま', 'み', 'む', 'め', 'も', 'ゃ', 'や',
+    'ゅ', 'ゆ', 'ょ', 'よ', 'ら', 'り', 'る', 'れ', 'ろ', 'ゎ', 'わ', 'ゐ', 'ゑ', 'を', 'ん', '゛',
+    '゜', 'ゝ', 'ゞ', 'ァ', 'ア', 'ィ', 'イ', 'ゥ', 'ウ', 'ェ', 'エ', 'ォ', 'オ', 'カ', 'ガ', 'キ',
+    'ギ', 'ク', 'グ', 'ケ', 'ゲ', 'コ', 'ゴ', 'サ', 'ザ', 'シ', 'ジ', 'ス', 'ズ', 'セ', 'ゼ', 'ソ',
+    'ゾ', 'タ', 'ダ', 'チ', 'ヂ', 'ッ', 'ツ', 'ヅ', 'テ', 'デ', 'ト', 'ド', 'ナ', 'ニ', 'ヌ', 'ネ',
+    'ノ', 'ハ', 'バ', 'パ', 'ヒ', 'ビ', 'ピ', 'フ', 'ブ', 'プ', 'ヘ', 'ベ', 'ペ', 'ホ', 'ボ', 'ポ',
+    'マ', 'ミ', 'ム', 'メ', 'モ', 'ャ', 'ヤ', 'ュ', 'ユ', 'ョ', 'ヨ', 'ラ', 'リ', 'ル', 'レ', 'ロ',
+    'ヮ', 'ワ', 'ヰ', 'ヱ', 'ヲ', 'ン', 'ヴ', 'ヵ', 'ヶ', '・', 'ー', 'ヽ', 'ヾ', '一', '丁', '七',
+    '万', '丈', '三', '上', '下', '不', '与', '丐', '丑', '且', '丕', '世', '丗', '丘', '丙', '丞',
+    '両', '並', '个', '中', '丱', '串', '丶', '丸', '丹', '主', '丼', '丿', '乂', '乃', '久', '之',
+    '乍', '乎', '乏', '乕', '乖', '乗', '乘', '乙', '九', '乞', '也', '乢', '乱', '乳', '乾', '亀',
+    '亂', '亅', '了', '予', '争', '亊', '事', '二', '于', '云', '互', '五', '井', '亘', '亙', '些',
+    '亜', '亞', '亟', '亠', '亡', '亢', '交', '亥', '亦', '亨', '享', '京', '亭', '亮', '亰', '亳',
+    '亶', '人', '什', '仁', '仂', '仄', '仆', '仇', '今', '介', '仍', '从', '仏', '仔', '仕', '他',
+    '仗', '付', '仙', '仝', '仞', '仟', '代', '令', '以', '仭', '仮', '仰', '仲', '件', '价', '任',
+    '企', '伉', '伊', '伍', '伎', '伏', '伐', '休', '会', '伜', '伝', '伯', '估', '伴', '伶', '伸',
+    '伺', '似', '伽', '佃', '但', '佇', '位', '低', '住', '佐', '佑', '体', '何', '佗', '余', '佚',
+    '佛', '作', '佝', '佞', '佩', '佯', '佰', '佳', '併', '佶', '佻', '佼', '使', '侃', '來', '侈',
+    '例', '侍', '侏', '侑', '侖', '侘', '供', '依', '侠', '価', '侫', '侭', '侮', '侯', '侵', '侶',
+    '便', '係', '促', '俄', '俊', '俎', '俐', '俑', '俔', '俗', '俘', '俚', '俛', '保', '俟', '信',
+    '俣', '俤', '俥', '修', '俯', '俳', '俵', '俶', '俸', '俺', '俾', '倅', '倆', '倉', '個', '倍',
+    '倏', '們', '倒', '倔', '倖', '候', '倚', '借', '倡', '倣', '値', '倥', '倦', '倨', '倩', '倪',
+    '倫', '倬', '倭', '倶', '倹', '偃', '假', '偈', '偉', '偏', '偐', '偕', '偖', '做', '停', '健',
+    '偬', '偲', '側', '偵', '偶', '偸', '偽', '傀', '傅', '傍', '傑', '傘', '備', '傚', '催', '傭',
+    '傲', '傳', '傴', '債', '傷', '傾', '僂', '僅', '僉', '僊', '働', '像', '僑', '僕', '僖', '僚',
+    '僞', '僣', '僥', '僧', '僭', '僮', '僵', '價', '僻', '儀', '儁', '儂', '億', '儉', '儒', '儔',
+    '儕', '儖', '儘', '儚', '償', '儡', '優', '儲', '儷', '儺', '儻', '儼', '儿', '兀', '允', '元',
+    '兄', '充', '兆', '兇', '先', '光', '克', '兌', '免', '兎', '児', '兒', '兔', '党', '兜', '兢',
+    '入', '全', '兩', '兪', '八', '公', '六', '兮', '共', '兵', '其', '具', '典', '兼', '冀', '冂',
+    '内', '円', '冉', '冊', '册', '再', '冏', '冐', '冑', '冒', '冓', '冕', '冖', '冗', '写', '冠',
+    '冢', '冤', '冥', '冦', '冨', '冩', '冪', '冫', '冬', '冰', '冱', '冲', '决', '冴', '况', '冶',
+    '冷', '冽', '凄', '凅', '准', '凉', '凋', '凌', '凍', '凖', '凛', '凜', '凝', '几', '凡', '処',
+    '凧', '凩', '凪', '凭', '凰', '凱', '凵', '凶', '凸', '凹', '出', '函', '凾', '刀', '刃', '刄',
+    '分', '切', '刈', '刊', '刋', '刎', '刑', '刔', '列', '初', '判', '別', '刧', '利', '刪', '刮',
+    '到', '刳', '制', '刷', '券', '刹', '刺', '刻', '剃', '剄', '則', '削', '剋', '剌', '前', '剏',
+    '剔', '剖', '剛', '剞', '剣', '剤', '剥', '剩', '剪', '副', '剰', '剱', '割', '剳', '剴', '創',
+    '剽', '剿', '劃', '劇', '劈', '劉', '劍', '劑', '劒', '劔', '力', '功', '加', '劣', '助', '努',
+    '劫', '劬', '劭', '励', '労', '劵', '効', '劼', '劾', '勁', '勃', '勅', '勇', '勉', '勍', '勒',
+    '動', '勗', '勘', '務', '勝', '勞', '募', '勠', '勢', '勣', '勤', '勦', '勧', '勲', '勳', '勵',
+    '勸', '勹', '勺', '勾', '勿', '匁', '匂', '包', '匆', '匈', '匍', '匏', '匐', '匕', '化', '北',
+    '匙', '匚', '匝', '匠', '匡', '匣', '匪', '匯', '匱', '匳', '匸', '匹', '区', '医', '匿', '區',
+    '十', '千', '卅', '卆', '升', '午', '卉', '半', '卍', '卑', '卒', '卓', '協', '南', '単', '博',
+    '卜', '卞', '占', '卦', '卩', '卮', '卯', '印', '危', '即', '却', '卵', '卷', '卸', '卻', '卿',
+    '厂', '厄', '厖', '厘', '厚', '原', '厠', '厥', '厦', '厨', '厩', '厭', '厮', '厰', '厳', '厶',
+    '去', '参', '參', '又', '叉', '及', '友', '双', '反', '収', '叔', '取', '受', '叙', '叛', '叟',
+    '叡', '叢', '口', '古', '句', '叨', '叩', '只', '叫', '召', '叭', '叮', '可', '台', '叱', '史',
+    '右', '叶', '号', '司', '叺', '吁', '吃', '各', '合', '吉', '吊', '吋', '同', '名', '后', '吏',
+    '吐', '向', '君', '吝', '吟', '吠', '否', '吩', '含', '听', '吭', '吮', '吶', '吸', '吹', '吻',
+    '吼', '吽', '吾', '呀', '呂', '呆', '呈', '呉', '告', '呎', '呑', '呟', '周', '呪', '呰', '呱',
+    '味', '呵', '呶', '呷', '呻', '呼', '命', '咀', '咄', '咆', '咋', '和', '咎', '咏', '咐', '咒',
+    '咢', '咤', '咥', '咨', '咫', '咬', '咯', '咲', '咳', '咸', '咼', '咽', '咾', '哀', '品', '哂',
+    '哄', '哇', '哈', '哉', '哘', '員', '哢', '哥', '哦', '哨', '哩', '哭', '哮', '哲', '哺', '哽',
+    '唄', '唆', '唇', '唏', '唐', '唔', '唖', '售', '唯', '唱', '唳', '唸', '唹', '唾', '啀', '啄',
+    '啅', '商', '啌', '問', '啓', '啖', '啗', '啜', '啝', '啣', '啻', '啼', '啾', '喀', '喃', '善',
+    '喇', '喉', '喊', '喋', '喘', '喙', '喚', '喜', '喝', '喞', '喟', '喧', '喨', '喩', '喪', '喫',
+    '喬', '單', '喰', '営', '嗄', '嗅', '嗇', '嗔', '嗚', '嗜', '嗟', '嗣', '嗤', '嗷', '嗹', '嗽',
+    '嗾', '嘆', '嘉', '嘔', '嘖', '嘗', '嘘', '嘛', '嘩', '嘯', '嘱', '嘲', '嘴', '嘶', '嘸', '噂',
+    '噌', '噎', '噐', '噛', '噤', '器', '噪', '噫', '噬', '噴', '噸', '噺', '嚀', '嚆', '嚇', '嚊',
+    '嚏', '嚔', '嚠', '嚢', '嚥', '嚮', '嚴', '嚶', '嚼', '囀', '囁', '囂', '囃', '囈', '囎', '囑',
+    '囓', '囗', '囘', '囚', '四', '回', '因', '団', '囮', '困', '囲', '図', '囹', '固', '国', '囿',
+    '圀', '圃', '圄', '圈', '圉', '國', '圍', '圏', '園', '圓', '圖', '團', '圜', '土', '圦', '圧',
+    '在', '圭', '地', '圷', '圸', '圻', '址', '坂', '均', '坊', '坎', '坏', '坐', '坑', '坡', '坤',
+    '坦', '坩', '坪', '坿', '垂', '垈', '垉', '型', '垓', '垠', '垢', '垣', '垤', '垪', '垰', '垳',
+    '埀', '埃', '埆', '埋', '城', '埒', '埓', '埔', '埖', '埜', '域', '埠', '埣', '埴', '執', '培',
+    '基', '埼', '堀', '堂', '堅', '堆', '堊', '堋', '堕', '堙', '堝', '堡', '堤', '堪', '堯', '堰',
+    '報', '場', '堵', '堺', '堽', '塀', '塁', '塊', '塋', '塑', '塒', '塔', '塗', '塘', '塙', '塚',
+    '塞', '塢', '塩', '填', '塰', '塲', '塵', '塹', '塾', '境', '墅', '墓', '増', '墜', '墟', '墨',
+    '墫', '墮', '墳', '墸', '墹', '墺', '墻', '墾', '壁', '壅', '壇', '壊', '壌', '壑', '壓', '壕',
+    '壗', '壘', '壙', '壜', '壞', '壟', '壤', '壥', '士', '壬', '壮', '壯', '声', '壱', '売', '壷',
+    '壹', '壺', '壻', '壼', '壽', '夂', '変', '夊', '夏', '夐', '夕', '外', '夘', '夙', '多', '夛',
+    '夜', '夢', '夥', '大', '天', '太', '夫', '夬', '夭', '央', '失', '夲', '夷', '夸', '夾', '奄',
+    '奇', '奈', '奉', '奎', '奏', '奐', '契', '奔', '奕', '套', '奘', '奚', '奠', '奢', '奥', '奧',
+    '奨', '奩', '奪', '奬', '奮', '女', '奴', '奸', '好', '妁', '如', '妃', '妄', '妊', '妍', '妓',
+    '妖', '妙', '妛', '妝', '妣', '妥', '妨', '妬', '妲', '妹', '妻', '妾', '姆', '姉', '始', '姐',
+    '姑', '姓', '委', '姙', '姚', '姜', '姥', '姦', '姨', '姪', '姫', '姶', '姻', '姿', '威', '娃',
+    '娉', '娑', '娘', '娚', '娜', '娟', '娠', '娥', '娩', '娯', '娵', '娶', '娼', '婀', '婁', '婆',
+    '婉', '婚', '婢', '婦', '婪', '婬', '婿', '媒', '媚', '媛', '媼', '媽', '媾', '嫁', '嫂', '嫉',
+    '嫋', '嫌', '嫐', '嫖', '嫗', '嫡', '嫣', '嫦', '嫩', '嫺', '嫻', '嬉', '嬋', '嬌', '嬖', '嬢',
+    '嬪', '嬬', '嬰', '嬲', '嬶', '嬾', '孀', '孃', '孅', '子', '孑', '孔', '孕', '字', '存', '孚',
+    '孛', '孜', '孝', '孟', '季', '孤', '孥', '学', '孩', '孫', '孰', '孱', '孳', '孵', '學', '孺',
+    '宀', '它', '宅', '宇', '守', '安', '宋', '完', '宍', '宏', '宕', '宗', '官', '宙', '定', '宛',
+    '宜', '宝', '実', '客', '宣', '室', '宥', '宦', '宮', '宰', '害', '宴', '宵', '家', '宸', '容',
+    '宿', '寂', '寃', '寄', '寅', '密', '寇', '寉', '富', '寐', '寒', '寓', '寔', '寛', '寝', '寞',
+    '察', '寡', '寢', '寤', '寥', '實', '寧', '寨', '審', '寫', '寮', '寰', '寳', '寵', '寶', '寸',
+    '寺', '対', '寿', '封', '専', '射', '尅', '将', '將', '專', '尉', '尊', '尋', '對', '導', '小',
+    '少', '尓', '尖', '尚', '尠', '尢', '尤', '尨', '尭', '就', '尸', '尹', '尺', '尻', '尼', '尽',
+    '尾', '尿', '局', '屁', '居', '屆', '屈', '届', '屋', '屍', '屎', '屏', '屐', '屑', '屓', '展',
+    '属', '屠', '屡', '層', '履', '屬', '屮', '屯', '山', '屶', '屹', '岌', '岐', '岑', '岔', '岡',
+    '岨', '岩', '岫', '岬', '岱', '岳', '岶', '岷', '岸', '岻', '岼', '岾', '峅', '峇', '峙', '峠',
+    '峡', '峨', '峩', '峪', '峭', '峯', '峰', '島', '峺', '峻', '峽', '崇', '崋', '崎', '崑', '崔',
+    '崕', '崖', '崗', '崘', '崙', '崚', '崛', '崟', '崢', '崩', '嵋', '嵌', '嵎', '嵐', '嵒', '嵜',
+    '嵩', '嵬', '嵯', '嵳', '嵶', '嶂', '嶄', '嶇', '嶋', '嶌', '嶐', '嶝', '嶢', '嶬', '嶮', '嶷',
+    '嶺', '嶼', '嶽', '巉', '巌', '巍', '巒', '巓', '巖', '巛', '川', '州', '巡', '巣', '工', '左',
+    '巧', '巨', '巫', '差', '己', '已', '巳', '巴', '巵', '巷', '巻', '巽', '巾', '市', '布', '帆',
+    '帋', '希', '帑', '帖', '帙', '帚', '帛', '帝', '帥', '師', '席', '帯', '帰', '帳', '帶', '帷',
+    '常', '帽', '幀', '幃', '幄', '幅', '幇', '幌', '幎', '幔', '幕', '幗', '幟', '幡', '幢', '幣',
+    '幤', '干', '平', '年', '幵', '并', '幸', '幹', '幺', '幻', '幼', '幽', '幾', '广', '庁', '広',
+    '庄', '庇', '床', '序', '底', '庖', '店', '庚', '府', '庠', '度', '座', '庫', '庭', '庵', '庶',
+    '康', '庸', '廁', '廂', '廃', '廈', '廉', '廊', '廏', '廐', '廓', '廖', '廚', '廛', '廝', '廟',
+    '廠', '廡', '廢', '廣', '廨', '廩', '廬', '廰', '廱', '廳', '廴', '延', '廷', '廸', '建', '廻',
+    '廼', '廾', '廿', '弁', '弃', '弄', '弉', '弊', '弋', '弌', '弍', '式', '弐', '弑', '弓', '弔',
+    '引', '弖', '弗', '弘', '弛', '弟', '弥', '弦', '弧', '弩', '弭', '弯', '弱', '張', '強', '弸',
+    '弼', '弾', '彁', '彈', '彊', '彌', '彎', '彑', '当', '彖', '彗', '彙', '彜', '彝', '彡', '形',
+    '彦', '彩', '彪', '彫', '彬', '彭', '彰', '影', '彳', '彷', '役', '彼', '彿', '往', '征', '徂',
+    '徃', '径', '待', '徇', '很', '徊', '律', '後', '徐', '徑', '徒', '従', '得', '徘', '徙', '從',
+    '徠', '御', '徨', '復', '循', '徭', '微', '徳', '徴', '徹', '徼', '徽', '心', '必', '忌', '忍',
+    '忖', '志', '忘', '忙', '応', '忝', '忠', '忤', '快', '忰', '忱', '念', '忸', '忻', '忽', '忿',
+    '怎', '怏', '怐', '怒', '怕', '怖', '怙', '怛', '怜', '思', '怠', '怡', '急', '怦', '性', '怨',
+    '怩', '怪', '怫', '怯', '怱', '怺', '恁', '恂', '恃', '恆', '恊', '恋', '恍', '恐', '恒', '恕',
+    '恙', '恚', '恟', '恠', '恢', '恣', '恤', '恥', '恨', '恩', '恪', '恫', '恬', '恭', '息', '恰',
+    '恵', '恷', '悁', '悃', '悄', '悉', '悋', '悌', '悍', '悒', '悔', '悖', '悗', '悚', '悛', '悟',
+    '悠', '患', '悦', '悧', '悩', '悪', '悲', '悳', '悴', '悵', '悶', '悸', '悼', '悽', '情', '惆',
+    '惇', '惑', '惓', '惘', '惚', '惜', '惟', '惠', '惡', '惣', '惧', '惨', '惰', '惱', '想', '惴',
+    '惶', '惷', '惹', '惺', '惻', '愀', '愁', '愃', '愆', '愈', '愉', '愍', '愎', '意', '愕', '愚',
+    '愛', '感', '愡', '愧', '愨', '愬', '愴', '愼', '愽', '愾', '愿', '慂', '慄', '慇', '慈', '慊',
+    '態', '慌', '慍', '慎', '慓', '慕', '慘', '慙', '慚', '慝', '慟', '慢', '慣', '慥', '慧', '慨',
+    '慫', '慮', '慯', '慰', '慱', '慳', '慴', '慵', '慶', '慷', '慾', '憂', '憇', '憊', '憎', '憐',
+    '憑', '憔', '憖', '憙', '憚', '憤', '憧', '憩', '憫', '憬', '憮', '憲', '憶', '憺', '憾', '懃',
+    '懆', '懇', '懈', '應', '懊', '懋', '懌', '懍', '懐', '懣', '懦', '懲', '懴', '懶', '懷', '懸',
+    '懺', '懼', '懽', '懾', '懿', '戀', '戈', '戉', '戊', '戌', '戍', '戎', '成', '我', '戒', '戔',
+    '或', '戚', '戛', '戝', '戞', '戟', '戡', '戦', '截', '戮', '戯', '戰', '戲', '戳', '戴', '戸',
+    '戻', '房', '所', '扁', '扇', '扈', '扉', '手', '才', '扎', '打', '払', '托', '扛', '扞', '扠',
+    '扣', '扨', '扮', '扱', '扶', '批', '扼', '找', '承', '技', '抂', '抃', '抄', '抉', '把', '抑',
+    '抒', '抓', '抔', '投', '抖', '抗', '折', '抛', '抜', '択', '披', '抬', '抱', '抵', '抹', '抻',
+    '押', '抽', '拂', '担', '拆', '拇', '拈', '拉', '拊', '拌', '拍', '拏', '拐', '拑', '拒', '拓',
+    '拔', '拗', '拘', '拙', '招', '拜', '拝', '拠', '拡', '括', '拭', '拮', '拯', '拱', '拳', '拵',
+    '拶', '拷', '拾', '拿', '持', '挂', '指', '挈', '按', '挌', '挑', '挙', '挟', '挧', '挨', '挫',
+    '振', '挺', '挽', '挾', '挿', '捉', '捌', '捍', '捏', '捐', '捕', '捗', '捜', '捧', '捨', '捩',
+    '捫', '据', '捲', '捶', '捷', '捺', '捻', '掀', '掃', '授', '掉', '掌', '掎', '掏', '排', '掖',
+    '掘', '掛', '掟', '掠', '採', '探', '掣', '接', '控', '推', '掩', '措', '掫', '掬', '掲', '掴',
+    '掵', '掻', '掾', '揀', '揃', '揄', '揆', '揉', '描', '提', '插', '揖', '揚', '換', '握', '揣',
+    '揩', '揮', '援', '揶', '揺', '搆', '損', '搏', '搓', '搖', '搗', '搜', '搦', '搨', '搬', '搭',
+    '搴', '搶', '携', '搾', '摂', '摎', '摘', '摧', '摩', '摯', '摶', '摸', '摺', '撃', '撈', '撒',
+    '撓', '撕', '撚', '撞', '撤', '撥', '撩', '撫', '播', '撮', '撰', '撲', '撹', '撻', '撼', '擁',
+    '擂', '擅', '擇', '操', '擒', '擔', '擘', '據', '擠', '擡', '擢', '擣', '擦', '擧', '擬', '擯',
+    '擱', '擲', '擴', '擶', '擺', '擽', '擾', '攀', '攅', '攘', '攜', '攝', '攣', '攤', '攪', '攫',
+    '攬', '支', '攴', '攵', '收', '攷', '攸', '改', '攻', '放', '政', '故', '效', '敍', '敏', '救',
+    '敕', '敖', '敗', '敘', '教', '敝', '敞', '敢', '散', '敦', '敬', '数', '敲', '整', '敵', '敷',
+    '數', '斂', '斃', '文', '斈', '斉', '斌', '斎', '斐', '斑', '斗', '料', '斛', '斜', '斟', '斡',
+    '斤', '斥', '斧', '斫', '斬', '断', '斯', '新', '斷', '方', '於', '施', '旁', '旃', '旄', '旅',
+    '旆', '旋', '旌', '族', '旒', '旗', '旙', '旛', '无', '旡', '既', '日', '旦', '旧', '旨', '早',
+    '旬', '旭', '旱', '旺', '旻', '昂', '昃', '昆', '昇', '昊', '昌', '明', '昏', '易', '昔', '昜',
+    '星', '映', '春', '昧', '昨', '昭', '是', '昴', '昵', '昶', '昼', '昿', '晁', '時', '晃', '晄',
+    '晉', '晋', '晏', '晒', '晝', '晞', '晟', '晢', '晤', '晦', '晧', '晨', '晩', '普', '景', '晰',
+    '晴', '晶', '智', '暁', '暃', '暄', '暇', '暈', '暉', '暎', '暑', '暖', '暗', '暘', '暝', '暢',
+    '暦', '暫', '暮', '暴', '暸', '暹', '暼', '暾', '曁', '曄', '曇', '曉', '曖', '曙', '曚', '曜',
+    '曝', '曠', '曦', '曩', '曰', '曲', '曳', '更', '曵', '曷', '書', '曹', '曼', '曽', '曾', '替',
+    '最', '會', '月', '有', '朋', '服', '朏', '朔', '朕', '朖', '朗', '望', '朝', '朞', '期', '朦',
+    '朧', '木', '未', '末', '本', '札', '朮', '朱', '朴', '朶', '朷', '朸', '机', '朽', '朿', '杁',
+    '杆', '杉', '李', '杏', '材', '村', '杓', '杖', '杙', '杜', '杞', '束', '杠', '条', '杢', '杣',
+    '杤', '来', '杪', '杭', '杯', '杰', '東', '杲', '杳', '杵', '杷', '杼', '松', '板', '枅', '枇',
+    '枉', '枋', '枌', '析', '枕', '林', '枚', '果', '枝', '枠', '枡', '枢', '枦', '枩', '枯', '枳',
+    '枴', '架', '枷', '枸', '枹', '柁', '柄', '柆', '柊', '柎', '柏', '某', '柑', '染', '柔', '柘',
+    '柚', '柝', '柞', '柢', '柤', '柧', '柩', '柬', '柮', '柯', '柱', '柳', '柴', '柵', '査', '柾',
+    '柿', '栂', '栃', '栄', '栓', '栖', '栗', '栞', '校', '栢', '栩', '株', '栫', '栲', '栴', '核',
+    '根', '格', '栽', '桀', '桁', '桂', '桃', '框', '案', '桍', '桎', '桐', '桑', '桓', '桔', '桙',
+    '桜', '桝', '桟', '档', '桧', '桴', '桶', '桷', '桾', '桿', '梁', '梃', '梅', '梍', '梏', '梓',
+    '梔', '梗', '梛', '條', '梟', '梠', '梢', '梦', '梧', '梨', '梭', '梯', '械', '梱', '梳', '梵',
+    '梶', '梹', '梺', '梼', '棄', '棆', '棉', '棊', '棋', '棍', '棒', '棔', '棕', '棗', '棘', '棚',
+    '棟', '棠', '棡', '棣', '棧', '森', '棯', '棲', '棹', '棺', '椀', '椁', '椄', '椅', '椈', '椋',
+    '椌', '植', '椎', '椏', '椒', '椙', '椚', '椛', '検', '椡', '椢', '椣', '椥', '椦', '椨', '椪',
+    '椰', '椴', '椶', '椹', '椽', '椿', '楊', '楓', '楔', '楕', '楙', '楚', '楜', '楝', '楞', '楠',
+    '楡', '楢', '楪', '楫', '業', '楮', '楯', '楳', '楴', '極', '楷', '楸', '楹', '楼', '楽', '楾',
+    '榁', '概', '榊', '榎', '榑', '榔', '榕', '榛', '榜', '榠', '榧', '榮', '榱', '榲', '榴', '榻',
+    '榾', '榿', '槁', '槃', '槇', '槊', '構', '槌', '槍', '槎', '槐', '槓', '様', '槙', '槝', '槞',
+    '槧', '槨', '槫', '槭', '槲', '槹', '槻', '槽', '槿', '樂', '樅', '樊', '樋', '樌', '樒', '樓',
+    '樔', '樗', '標', '樛', '樞', '樟', '模', '樢', '樣', '権', '横', '樫', '樮', '樵', '樶', '樸',
+    '樹', '樺', '樽', '橄', '橇', '橈', '橋', '橘', '橙', '機', '橡', '橢', '橦', '橲', '橸', '橿',
+    '檀', '檄', '檍', '檎', '檐', '檗', '檜', '檠', '檢', '檣', '檪', '檬', '檮', '檳', '檸', '檻',
+    '櫁', '櫂', '櫃', '櫑', '櫓', '櫚', '櫛', '櫞', '櫟', '櫨', '櫪', '櫺', '櫻', '欄', '欅', '權',
+    '欒', '欖', '欝', '欟', '欠', '次', '欣', '欧', '欲', '欷', '欸', '欹', '欺', '欽', '款', '歃',
+    '歇', '歉', '歌', '歎', '歐', '歓', '歔', '歙', '歛', '歟', '歡', '止', '正', '此', '武', '歩',
+    '歪', '歯', '歳', '歴', '歸', '歹', '死', '歿', '殀', '殃', '殄', '殆', '殉', '殊', '残', '殍',
+    '殕', '殖', '殘', '殞', '殤', '殪', '殫', '殯', '殱', '殲', '殳', '殴', '段', '殷', '殺', '殻',
+    '殼', '殿', '毀', '毅', '毆', '毋', '母', '毎', '毒', '毓', '比', '毘', '毛', '毟', '毫', '毬',
+    '毯', '毳', '氈', '氏', '民', '氓', '气', '気', '氛', '氣', '氤', '水', '氷', '永', '氾', '汀',
+    '汁', '求', '汎', '汐', '汕', '汗', '汚', '汝', '汞', '江', '池', '汢', '汨', '汪', '汰', '汲',
+    '汳', '決', '汽', '汾', '沁', '沂', '沃', '沈', '沌', '沍', '沐', '沒', '沓', '沖', '沙', '沚',
+    '沛', '没', '沢', '沫', '沮', '沱', '河', '沸', '油', '沺', '治', '沼', '沽', '沾', '沿', '況',
+    '泄', '泅', '泉', '泊', '泌', '泓', '法', '泗', '泙', '泛', '泝', '泡', '波', '泣', '泥', '注',
+    '泪', '泯', '泰', '泱', '泳', '洋', '洌', '洒', '洗', '洙', '洛', '洞', '洟', '津', '洩', '洪',
+    '洫', '洲', '洳', '洵', '洶', '洸', '活', '洽', '派', '流', '浄', '浅', '浙', '浚', '浜', '浣',
+    '浤', '浦', '浩', '浪', '浬', '浮', '浴', '海', '浸', '浹', '涅', '消', '涌', '涎', '涓', '涕',
+    '涙', '涛', '涜', '涯', '液', '涵', '涸', '涼', '淀', '淅', '淆', '淇', '淋', '淌', '淑', '淒',
+    '淕', '淘', '淙', '淞', '淡', '淤', '淦', '淨', '淪', '淫', '淬', '淮', '深', '淳', '淵', '混',
+    '淹', '淺', '添', '清', '渇', '済', '渉', '渊', '渋', '渓', '渕', '渙', '渚', '減', '渝', '渟',
+    '渠', '渡', '渣', '渤', '渥', '渦', '温', '渫', '測', '渭', '渮', '港', '游', '渺', '渾', '湃',
+    '湊', '湍', '湎', '湖', '湘', '湛', '湟', '湧', '湫', '湮', '湯', '湲', '湶', '湾', '湿', '満',
+    '溂', '溌', '溏', '源', '準', '溘', '溜', '溝', '溟', '溢', '溥', '溪', '溯', '溲', '溶', '溷',
+    '溺', '溽', '滂', '滄', '滅', '滉', '滋', '滌', '滑', '滓', '滔', '滕', '滝', '滞', '滬', '滯',
+    '滲', '滴', '滷', '滸', '滾', '滿', '漁', '漂', '漆', '漉', '漏', '漑', '漓', '演', '漕', '漠',
+    '漢', '漣', '漫', '漬', '漱', '漲', '漸', '漾', '漿', '潁', '潅', '潔', '潘', '潛', '潜', '潟',
+    '潤', '潦', '潭', '潮', '潯', '潰', '潴', '潸', '潺', '潼', '澀', '澁', '澂', '澄', '澆', '澎',
+    '澑', '澗', '澡', '澣', '澤', '澪', '澱', '澳', '澹', '激', '濁', '濂', '濃', '濆', '濔', '濕',
+    '濘', '濛', '濟', '濠', '濡', '濤', '濫', '濬', '濮', '濯', '濱', '濳', '濶', '濺', '濾', '瀁',
+    '瀉', '瀋', '瀏', '瀑', '瀕', '瀘', '瀚', '瀛', '瀝', '瀞', '瀟', '瀦', '瀧', '瀬', '瀰', '瀲',
+    '瀾', '灌', '灑', '灘', '灣', '火', '灯', '灰', '灸', '灼', '災', '炉', '炊', '炎', '炒', '炙',
+    '炬', '炭', '炮', '炯', '炳', '炸', '点', '為', '烈', '烋', '烏', '烙', '烝', '烟', '烱', '烹',
+    '烽', '焉', '焔', '焙', '焚', '焜', '無', '焦', '然', '焼', '煉', '煌', '煎', '煕', '煖', '煙',
+    '煢', '煤', '煥', '煦', '照', '煩', '煬', '煮', '煽', '熄', '熈', '熊', '熏', '熔', '熕', '熙',
+    '熟', '熨', '熬', '熱', '熹', '熾', '燃', '燈', '燉', '燎', '燐', '燒', '燔', '燕', '燗', '營',
+    '燠', '燥', '燦', '燧', '燬', '燭', '燮', '燵', '燹', '燻', '燼', '燿', '爆', '爍', '爐', '爛',
+    '爨', '爪', '爬', '爭', '爰', '爲', '爵', '父', '爺', '爻', '爼', '爽', '爾', '爿', '牀', '牆',
+    '片', '版', '牋', '牌', '牒', '牘', '牙', '牛', '牝', '牟', '牡', '牢', '牧', '物', '牲', '牴',
+    '特', '牽', '牾', '犀', '犁', '犂', '犇', '犒', '犖', '犠', '犢', '犧', '犬', '犯', '犲', '状',
+    '犹', '狂', '狃', '狄', '狆', '狎', '狐', '狒', '狗', '狙', '狛', '狠', '狡', '狢', '狩', '独',
+    '狭', '狷', '狸', '狹', '狼', '狽', '猊', '猖', '猗', '猛', '猜', '猝', '猟', '猥', '猩', '猪',
+    '猫', '献', '猯', '猴', '猶', '猷', '猾', '猿', '獄', '獅', '獎', '獏', '獗', '獣', '獨', '獪',
+    '獰', '獲', '獵', '獸', '獺', '獻', '玄', '率', '玉', '王', '玖', '玩', '玲', '玳', '玻', '珀',
+    '珂', '珈', '珊', '珍', '珎', '珞', '珠', '珥', '珪', '班', '珮', '珱', '珸', '現', '球', '琅',
+    '理', '琉', '琢', '琥', '琲', '琳', '琴', '琵', '琶', '琺', '琿', '瑁', '瑕', '瑙', '瑚', '瑛',
+    '瑜', '瑞', '瑟', '瑠', '瑣', '瑤', '瑩', '瑪', '瑯', '瑰', '瑳', '瑶', '瑾', '璃', '璋', '璞',
+    '璢', '璧', '環', '璽', '瓊', '瓏', '瓔', '瓜', '瓠', '瓢', '瓣', '瓦', '瓧', '瓩', '瓮', '瓰',
+    '瓱', '瓲', '瓶', '瓷', '瓸', '甃', '甄', '甅', '甌', '甍', '甎', '甑', '甓', '甕', '甘', '甚',
+    '甜', '甞', '生', '産', '甥', '甦', '用', '甫', '甬', '田', '由', '甲', '申', '男', '甸', '町',
+    '画', '甼', '畄', '畆', '畉', '畊', '畋', '界', '畍', '畏', '畑', '畔', '留', '畚', '畛', '畜',
+    '畝', '畠', '畢', '畤', '略', '畦', '畧', '畩', '番', '畫', '畭', '異', '畳', '畴', '當', '畷',
+    '畸', '畿', '疂', '疆', '疇', '疉', '疊', '疋', '疎', '疏', '疑', '疔', '疚', '疝', '疣', '疥',
+    '疫', '疱', '疲', '疳', '疵', '疸', '疹', '疼', '疽', '疾', '痂', '痃', '病', '症', '痊', '痍',
+    '痒', '痔', '痕', '痘', '痙', '痛', '痞', '痢', '痣', '痩', '痰', '痲', '痳', '痴', '痺', '痼',
+    '痾', '痿', '瘁', '瘉', '瘋', '瘍', '瘟', '瘠', '瘡', '瘢', '瘤', '瘧', '瘰', '瘴', '瘻', '療',
+    '癆', '癇', '癈', '癌', '癒', '癖', '癘', '癜', '癡', '癢', '癧', '癨', '癩', '癪', '癬', '癰',
+    '癲', '癶', '癸', '発', '登', '發', '白', '百', '皀', '皃', '的', '皆', '皇', '皈', '皋', '皎',
+    '皐', '皓', '皖', '皙', '皚', '皮', '皰', '皴', '皷', '皸', '皹', '皺', '皿', '盂', '盃', '盆',
+    '盈', '益', '盍', '盒', '盖', '盗', '盛', '盜', '盞', '盟', '盡', '監', '盤', '盥', '盧', '盪',
+    '目', '盲', '直', '相', '盻', '盾', '省', '眄', '眇', '眈', '眉', '看', '県', '眛', '眞', '真',
+    '眠', '眤', '眥', '眦', '眩', '眷', '眸', '眺', '眼', '着', '睇', '睚', '睛', '睡', '督', '睥',
+    '睦', '睨', '睫', '睹', '睾', '睿', '瞋', '瞎', '瞑', '瞞', '瞠', '瞥', '瞬', '瞭', '瞰', '瞳',
+    '瞶', '瞹', '瞻', '瞼', '瞽', '瞿', '矇', '矍', '矗', '矚', '矛', '矜', '矢', '矣', '知', '矧',
+    '矩', '短', '矮', '矯', '石', '矼', '砂', '砌', '砒', '研', '砕', '砠', '砥', '砦', '砧', '砲',
+    '破', '砺', '砿', '硅', '硝', '硫', '硬', '硯', '硲', '硴', '硼', '碁', '碆', '碇', '碌', '碍',
+    '碎', '碑', '碓', '碕', '碗', '碚', '碣', '碧', '碩', '碪', '碯', '碵', '確', '碼', '碾', '磁',
+    '磅', '磆', '磊', '磋', '磐', '磑', '磔', '磚', '磧', '磨', '磬', '磯', '磴', '磽', '礁', '礇',
+    '礎', '礑', '礒', '礙', '礦', '礪', '礫', '礬', '示', '礼', '社', '祀', '祁', '祇', '祈', '祉',
+    '祐', '祓', '祕', '祖', '祗', '祚', '祝', '神', '祟', '祠', '祢', '祥', '票', '祭', '祷', '祺',
+    '祿', '禀', '禁', '禄', '禅', '禊', '禍', '禎', '福', '禝', '禦', '禧', '禪', '禮', '禰', '禳',
+    '禹', '禺', '禽', '禾', '禿', '秀', '私', '秉', '秋', '科', '秒', '秕', '秘', '租', '秡', '秣',
+    '秤', '秦', '秧', '秩', '秬', '称', '移', '稀', '稈', '程', '稍', '税', '稔', '稗', '稘', '稙',
+    '稚', '稜', '稟', '稠', '種', '稱', '稲', '稷', '稻', '稼', '稽', '稾', '稿', '穀', '穂', '穃',
+    '穆', '穉', '積', '穎', '穏', '穐', '穗', '穡', '穢', '穣', '穩', '穫', '穰', '穴', '究', '穹',
+    '空', '穽', '穿', '突', '窃', '窄', '窈', '窒', '窓', '窕', '窖', '窗', '窘', '窟', '窩', '窪',
+    '窮', '窯', '窰', '窶', '窺', '窿', '竃', '竄', '竅', '竇', '竈', '竊', '立', '竍', '竏', '竒',
+    '竓', '竕', '站', '竚', '竜', '竝', '竟', '章', '竡', '竢', '竣', '童', '竦', '竪', '竭', '端',
+    '竰', '競', '竸', '竹', '竺', '竿', '笂', '笄', '笆', '笈', '笊', '笋', '笏', '笑', '笘', '笙',
+    '笛', '笞', '笠', '笥', '符', '笨', '第', '笳', '笵', '笶', '笹', '筅', '筆', '筈', '等', '筋',
+    '筌', '筍', '筏', '筐', '筑', '筒', '答', '策', '筝', '筥', '筧', '筬', '筮', '筰', '筱', '筴',
+    '筵', '筺', '箆', '箇', '箋', '箍', '箏', '箒', '箔', '箕', '算', '箘', '箙', '箚', '箜', '箝',
+    '箟', '管', '箪', '箭', '箱', '箴', '箸', '節', '篁', '範', '篆', '篇', '築', '篋', '篌', '篏',
+    '篝', '篠', '篤', '篥', '篦', '篩', '篭', '篳', '篶', '篷', '簀', '簇', '簍', '簑', '簒', '簓',
+    '簔', '簗', '簟', '簡', '簣', '簧', '簪', '簫', '簷', '簸', '簽', '簾', '簿', '籀', '籃', '籌',
+    '籍', '籏', '籐', '籔', '籖', '籘', '籟', '籠', '籤', '籥', '籬', '米', '籵', '籾', '粁', '粂',
+    '粃', '粉', '粋', '粍', '粐', '粒', '粕', '粗', '粘', '粛', '粟', '粡', '粢', '粤', '粥', '粧',
+    '粨', '粫', '粭', '粮', '粱', '粲', '粳', '粹', '粽', '精', '糀', '糂', '糅', '糊', '糎', '糒',
+    '糖', '糘', '糜', '糞', '糟', '糠', '糢', '糧', '糯', '糲', '糴', '糶', '糸', '糺', '系', '糾',
+    '紀', '紂', '約', '紅', '紆', '紊', '紋', '納', '紐', '純', '紕', '紗', '紘', '紙', '級', '紛',
+    '紜', '素', '紡', '索', '紫', '紬', '紮', '累', '細', '紲', '紳', '紵', '紹', '紺', '紿', '終',
+    '絃', '組', '絅', '絆', '絋', '経', '絎', '絏', '結', '絖', '絛', '絞', '絡', '絢', '絣', '給',
+    '絨', '絮', '統', '絲', '絳', '絵', '絶', '絹', '絽', '綉', '綏', '經', '継', '続', '綛', '綜',
+    '綟', '綢', '綣', '綫', '綬', '維', '綮', '綯', '綰', '綱', '網', '綴', '綵', '綸', '綺', '綻',
+    '綽', '綾', '綿', '緇', '緊', '緋', '総', '緑', '緒', '緕', '緘', '線', '緜', '緝', '緞', '締',
+    '緡', '緤', '編', '緩', '緬', '緯', '緲', '練', '緻', '縁', '縄', '縅', '縉', '縊', '縋', '縒',
+    '縛', '縞', '縟', '縡', '縢', '縣', '縦', '縫', '縮', '縱', '縲', '縵', '縷', '縹', '縺', '縻',
+    '總', '績', '繁', '繃', '繆', '繊', '繋', '繍', '織', '繕', '繖', '繙', '繚', '繝', '繞', '繦',
+    '繧', '繩', '繪', '繭', '繰', '繹', '繻', '繼', '繽', '繿', '纂', '纃', '纈', '纉', '續', '纎',
+    '纏', '纐', '纒', '纓', '纔', '纖', '纛', '纜', '缶', '缸', '缺', '罅', '罌', '罍', '罎', '罐',
+    '网', '罔', '罕', '罘', '罟', '罠', '罧', '罨', '罩', '罪', '罫', '置', '罰', '署', '罵', '罷',
+    '罸', '罹', '羂', '羃', '羅', '羆', '羇', '羈', '羊', '羌', '美', '羔', '羚', '羝', '羞', '羣',
+    '群', '羨', '義', '羮', '羯', '羲', '羶', '羸', '羹', '羽', '翁', '翅', '翆', '翊', '翌', '習',
+    '翔', '翕', '翠', '翡', '翦', '翩', '翫', '翰', '翳', '翹', '翻', '翼', '耀', '老', '考', '耄',
+    '者', '耆', '耋', '而', '耐', '耒', '耕', '耗', '耘', '耙', '耜', '耡', '耨', '耳', '耶', '耻',
+    '耽', '耿', '聆', '聊', '聒', '聖', '聘', '聚', '聞', '聟', '聡', '聢', '聨', '聯', '聰', '聲',
+    '聳', '聴', '聶', '職', '聹', '聽', '聾', '聿', '肄', '肅', '肆', '肇', '肉', '肋', '肌', '肓',
+    '肖', '肘', '肚', '肛', '肝', '股', '肢', '肥', '肩', '肪', '肬', '肭', '肯', '肱', '育', '肴',
+    '肺', '胃', '胄', '胆', '背', '胎', '胖', '胙', '胚', '胛', '胝', '胞', '胡', '胤', '胥', '胯',
+    '胱', '胴', '胸', '胼', '能', '脂', '脅', '脆', '脇', '脈', '脉', '脊', '脚', '脛', '脣', '脩',
+    '脯', '脱', '脳', '脹', '脾', '腆', '腋', '腎', '腐', '腑', '腓', '腔', '腕', '腟', '腥', '腦',
+    '腫', '腮', '腰', '腱', '腴', '腸', '腹', '腺', '腿', '膀', '膂', '膃', '膈', '膊', '膏', '膓',
+    '膕', '膚', '膜', '膝', '膠', '膣', '膤', '膨', '膩', '膰', '膳', '膵', '膸', '膺', '膽', '膾',
+    '膿', '臀', '臂', '臆', '臈', '臉', '臍', '臑', '臓', '臘', '臙', '臚', '臟', '臠', '臣', '臥',
+    '臧', '臨', '自', '臭', '至', '致', '臺', '臻', '臼', '臾', '舁', '舂', '舅', '與', '興', '舉',
+    '舊', '舌', '舍', '舎', '舐', '舒', '舖', '舗', '舘', '舛', '舜', '舞', '舟', '舩', '航', '舫',
+    '般', '舮', '舳', '舵', '舶', '舷', '舸', '船', '艀', '艇', '艘', '艙', '艚', '艝', '艟', '艢',
+    '艤', '艦', '艨', '艪', '艫', '艮', '良', '艱', '色', '艶', '艷', '艸', '艾', '芋', '芍', '芒',
+    '芙', '芝', '芟', '芥', '芦', '芫', '芬', '芭', '芯', '花', '芳', '芸', '芹', '芻', '芽', '苅',
+    '苑', '苒', '苓', '苔', '苗', '苙', '苛', '苜', '苞', '苟', '苡', '苣', '若', '苦', '苧', '苫',
+    '英', '苳', '苴', '苹', '苺', '苻', '茂', '范', '茄', '茅', '茆', '茉', '茎', '茖', '茗', '茘',
+    '茜', '茣', '茨', '茫', '茯', '茱', '茲', '茴', '茵', '茶', '茸', '茹', '荀', '荅', '草', '荊',
+    '荏', '荐', '荒', '荘', '荳', '荵', '荷', '荻', '荼', '莅', '莇', '莉', '莊', '莎', '莓', '莖',
+    '莚', '莞', '莟', '莠', '莢', '莨', '莪', '莫', '莱', '莵', '莽', '菁', '菅', '菊', '菌', '菎',
+    '菓', '菖', '菘', '菜', '菟', '菠', '菩', '菫', '華', '菰', '菱', '菲', '菴', '菷', '菻', '菽',
+    '萃', '萄', '萇', '萋', '萌', '萍', '萎', '萓', '萠', '萢', '萩', '萪', '萬', '萱', '萵', '萸',
+    '萼', '落', '葆', '葉', '葎', '著', '葛', '葡', '葢', '董', '葦', '葩', '葫', '葬', '葭', '葮',
+    '葯', '葱', '葵', '葷', '葹', '葺', '蒂', '蒄', '蒋', '蒐', '蒔', '蒙', '蒜', '蒟', '蒡', '蒭',
+    '蒲', '蒸', '蒹', '蒻', '蒼', '蒿', '蓁', '蓄', '蓆', '蓉', '蓊', '蓋', '蓍', '蓐', '蓑', '蓖',
+    '蓙', '蓚', '蓬', '蓮', '蓴', '蓼', '蓿', '蔀', '蔆', '蔑', '蔓', '蔔', '蔕', '蔗', '蔘', '蔚',
+    '蔟', '蔡', '蔦', '蔬', '蔭', '蔵', '蔽', '蕀', '蕁', '蕃', '蕈', '蕉', '蕊', '蕋', '蕎', '蕕',
+    '蕗', '蕘', '蕚', '蕣', '蕨', '蕩', '蕪', '蕭', '蕷', '蕾', '薀', '薄', '薇', '薈', '薊', '薐',
+    '薑', '薔', '薗', '薙', '薛', '薜', '薤', '薦', '薨', '薩', '薪', '薫', '薬', '薮', '薯', '薹',
+    '薺', '藁', '藉', '藍', '藏', '藐', '藕', '藜', '藝', '藤', '藥', '藩', '藪', '藷', '藹', '藺',
+    '藻', '藾', '蘂', '蘆', '蘇', '蘊', '蘋', '蘓', '蘖', '蘗', '蘚', '蘢', '蘭', '蘯', '蘰', '蘿',
+    '虍', '虎', '虐', '虔', '處', '虚', '虜', '虞', '號', '虧', '虫', '虱', '虹', '虻', '蚊', '蚋',
+    '蚌', '蚓', '蚕', '蚣', '蚤', '蚩', '蚪', '蚫', '蚯', '蚰', '蚶', '蛄', '蛆', '蛇', '蛉', '蛋',
+    '蛍', '蛎', '蛔', '蛙', '蛛', '蛞', '蛟', '蛤', '蛩', '蛬', '蛭', '蛮', '蛯', '蛸', '蛹', '蛻',
+    '蛾', '蜀', '蜂', '蜃', '蜆', '蜈', '蜉', '蜊', '蜍', '蜑', '蜒', '蜘', '蜚', '蜜', '蜥', '蜩',
+    '蜴', '蜷', '蜻', '蜿', '蝉', '蝋', '蝌', '蝎', '蝓', '蝕', '蝗', '蝙', '蝟', '蝠', '蝣', '蝦',
+    '蝨', '蝪', '蝮', '蝴', '蝶', '蝸', '蝿', '螂', '融', '螟', '螢', '螫', '螯', '螳', '螺', '螻',
+    '螽', '蟀', '蟄', '蟆', '蟇', '蟋', '蟐', '蟒', '蟠', '蟯', '蟲', '蟶', '蟷', '蟹', '蟻', '蟾',
+    '蠅', '蠍', '蠎', '蠏', '蠑', '蠕', '蠖', '蠡', '蠢', '蠣', '蠧', '蠱', '蠶', '蠹', '蠻', '血',
+    '衂', '衄', '衆', '行', '衍', '衒', '術', '街', '衙', '衛', '衝', '衞', '衡', '衢', '衣', '表',
+    '衫', '衰', '衲', '衵', '衷', '衽', '衾', '衿', '袁', '袂', '袈', '袋', '袍', '袒', '袖', '袗',
+    '袙', '袞', '袢', '袤', '被', '袮', '袰', '袱', '袴', '袵', '袷', '袿', '裁', '裂', '裃', '裄',
+    '装', '裏', '裔', '裕', '裘', '裙', '補', '裝', '裟', '裡', '裨', '裲', '裳', '裴', '裸', '裹',
+    '裼', '製', '裾', '褂', '褄', '複', '褊', '褌', '褐', '褒', '褓', '褝', '褞', '褥', '褪', '褫',
+    '褶', '褸', '褻', '襁', '襃', '襄', '襌', '襍', '襖', '襞', '襟', '襠', '襤', '襦', '襪', '襭',
+    '襯', '襲', '襴', '襷', '襾', '西', '要', '覃', '覆', '覇', '覈', '覊', '見', '規', '覓', '視',
+    '覗', '覘', '覚', '覡', '覦', '覧', '覩', '親', '覬', '覯', '覲', '観', '覺', '覽', '覿', '觀',
+    '角', '觚', '觜', '觝', '解', '触', '觧', '觴', '觸', '言', '訂', '訃', '計', '訊', '訌', '討',
+    '訐', '訓', '訖', '託', '記', '訛', '訝', '訟', '訣', '訥', '訪', '設', '許', '訳', '訴', '訶',
+    '診', '註', '証', '詁', '詆', '詈', '詐', '詑', '詒', '詔', '評', '詛', '詞', '詠', '詢', '詣',
+    '試', '詩', '詫', '詬', '詭', '詮', '詰', '話', '該', '詳', '詼', '誂', '誄', '誅', '誇', '誉',
+    '誌', '認', '誑', '誓', '誕', '誘', '誚', '語', '誠', '誡', '誣', '誤', '誥', '誦', '誨', '説',
+    '読', '誰', '課', '誹', '誼', '調', '諂', '諄', '談', '請', '諌', '諍', '諏', '諒', '論', '諚',
+    '諛', '諜', '諞', '諠', '諡', '諢', '諤', '諦', '諧', '諫', '諭', '諮', '諱', '諳', '諷', '諸',
+    '諺', '諾', '謀', '謁', '謂', '謄', '謇', '謌', '謎', '謐', '謔', '謖', '謗', '謙', '謚', '講',
+    '謝', '謠', '謡', '謦', '謨', '謫', '謬', '謳', '謹', '謾', '譁', '證', '譌', '譎', '譏', '譖',
+    '識', '譚', '譛', '譜', '譟', '警', '譫', '譬', '譯', '議', '譱', '譲', '譴', '護', '譽', '讀',
+    '讃', '變', '讌', '讎', '讐', '讒', '讓', '讖', '讙', '讚', '谷', '谺', '谿', '豁', '豆', '豈',
+    '豊', '豌', '豎', '豐', '豕', '豚', '象', '豢', '豪', '豫', '豬', '豸', '豹', '豺', '豼', '貂',
+    '貅', '貉', '貊', '貌', '貍', '貎', '貔', '貘', '貝', '貞', '負', '財', '貢', '貧', '貨', '販',
+    '貪', '貫', '責', '貭', '貮', '貯', '貰', '貲', '貳', '貴', '貶', '買', '貸', '費', '貼', '貽',
+    '貿', '賀', '賁', '賂', '賃', '賄', '資', '賈', '賊', '賍', '賎', '賑', '賓', '賚', '賛', '賜',
+    '賞', '賠', '賢', '賣', '賤', '賦', '質', '賭', '賺', '賻', '購', '賽', '贄', '贅', '贇', '贈',
+    '贊', '贋', '贍', '贏', '贐', '贓', '贔', '贖', '赤', '赦', '赧', '赫', '赭', '走', '赱', '赳',
+    '赴', '起', '趁', '超', '越', '趙', '趣', '趨', '足', '趺', '趾', '跂', '跋', '跌', '跏', '跖',
+    '跚', '跛', '距', '跟', '跡', '跣', '跨', '跪', '跫', '路', '跳', '践', '跼', '跿', '踈', '踉',
+    '踊', '踏', '踐', '踝', '踞', '踟', '踪', '踰', '踴', '踵', '蹂', '蹄', '蹇', '蹈', '蹉', '蹊',
+    '蹌', '蹐', '蹕', '蹙', '蹟', '蹠', '蹣', '蹤', '蹲', '蹴', '蹶', '蹼', '躁', '躄', '躅', '躇',
+    '躊', '躋', '躍', '躑', '躓', '躔', '躙', '躡', '躪', '身', '躬', '躯', '躰', '躱', '躾', '軅',
+    '軆', '軈', '車', '軋', '軌', '軍', '軒', '軛', '軟', '転', '軣', '軫', '軸', '軻', '軼', '軽',
+    '軾', '較', '輅', '載', '輊', '輌', '輒', '輓', '輔', '輕', '輙', '輛', '輜', '輝', '輟', '輦',
+    '輩', '輪', '輯', '輳', '輸', '輹', '輻', '輾', '輿', '轂', '轄', '轅', '轆', '轉', '轌', '轍',
+    '轎', '轗', '轜', '轟', '轡', '轢', '轣', '轤', '辛', '辜', '辞', '辟', '辣', '辧', '辨', '辭',
+    '辮', '辯', '辰', '辱', '農', '辷', '辺', '辻', '込', '辿', '迂', '迄', '迅', '迎', '近', '返',
+    '迚', '迢', '迥', '迦', '迩', '迪', '迫', '迭', '迯', '述', '迴', '迷', '迸', '迹', '迺', '追',
+    '退', '送', '逃', '逅', '逆', '逋', '逍', '逎', '透', '逐', '逑', '逓', '途', '逕', '逖', '逗',
+    '這', '通', '逝', '逞', '速', '造', '逡', '逢', '連', '逧', '逮', '週', '進', '逵', '逶', '逸',
+    '逹', '逼', '逾', '遁', '遂', '遅', '遇', '遉', '遊', '運', '遍', '過', '遏', '遐', '遑', '遒',
+    '道', '達', '違', '遖', '遘', '遙', '遜', '遞', '遠', '遡', '遣', '遥', '遨', '適', '遭', '遮',
+    '遯', '遲', '遵', '遶', '遷', '選', '遺', '遼', '遽', '避', '邀', '邁', '邂', '邃', '還', '邇',
+    '邉', '邊', '邏', '邑', '那', '邦', '邨', '邪', '邯', '邱', '邵', '邸', '郁', '郊', '郎', '郛',
+    '郡', '郢', '郤', '部', '郭', '郵', '郷', '都', '鄂', '鄒', '鄙', '鄭', '鄰', '鄲', '酉', '酊',
+    '酋', '酌', '配', '酎', '酒', '酔', '酖', '酘', '酢', '酣', '酥', '酩', '酪', '酬', '酲', '酳',
+    '酵', '酷', '酸', '醂', '醇', '醉', '醋', '醍', '醐', '醒', '醗', '醜', '醢', '醤', '醪', '醫',
+    '醯', '醴', '醵', '醸', '醺', '釀', '釁', '釆', '采', '釈', '釉', '釋', '里', '重', '野', '量',
+    '釐', '金', '釖', '釘', '釛', '釜', '針', '釟', '釡', '釣', '釦', '釧', '釵', '釶', '釼', '釿',
+    '鈍', '鈎', '鈑', '鈔', '鈕', '鈞', '鈩', '鈬', '鈴', '鈷', '鈿', '鉄', '鉅', '鉈', '鉉', '鉋',
+    '鉐', '鉗', '鉚', '鉛', '鉞', '鉢', '鉤', '鉦', '鉱', '鉾', '銀', '銃', '銅', '銑', '銓', '銕',
+    '銖', '銘', '銚', '銛', '銜', '銭', '銷', '銹', '鋏', '鋒', '鋤', '鋩', '鋪', '鋭', '鋲', '鋳',
+    '鋸', '鋺', '鋼', '錆', '錏', '錐', '錘', '錙', '錚', '錠', '錢', '錣', '錦', '錨', '錫', '錬',
+    '錮', '錯', '録', '錵', '錺', '錻', '鍄', '鍋', '鍍', '鍔', '鍖', '鍛', '鍜', '鍠', '鍬', '鍮',
+    '鍵', '鍼', '鍾', '鎌', '鎔', '鎖', '鎗', '鎚', '鎧', '鎬', '鎭', '鎮', '鎰', '鎹', '鏃', '鏈',
+    '鏐', '鏑', '鏖', '鏗', '鏘', '鏝', '鏡', '鏤', '鏥', '鏨', '鐃', '鐇', '鐐', '鐓', '鐔', '鐘',
+    '鐙', '鐚', '鐡', '鐫', '鐵', '鐶', '鐸', '鐺', '鑁', '鑄', '鑑', '鑒', '鑓', '鑚', '鑛', '鑞',
+    '鑠', '鑢', '鑪', '鑰', '鑵', '鑷', '鑼', '鑽', '鑾', '鑿', '钁', '長', '門', '閂', '閃', '閇',
+    '閉', '閊', '開', '閏', '閑', '間', '閔', '閖', '閘', '閙', '閠', '関', '閣', '閤', '閥', '閧',
+    '閨', '閭', '閲', '閹', '閻', '閼', '閾', '闃', '闇', '闊', '闌', '闍', '闔', '闕', '闖', '闘',
+    '關', '闡', '闢', '闥', '阜', '阡', '阨', '阪', '阮', '阯', '防', '阻', '阿', '陀', '陂', '附',
+    '陋', '陌', '降', '陏', '限', '陛', '陜', '陝', '陞', '陟', '院', '陣', '除', '陥', '陦', '陪',
+    '陬', '陰', '陲', '陳', '陵', '陶', '陷', '陸', '険', '陽', '隅', '隆', '隈', '隊', '隋', '隍',
+    '階', '随', '隔', '隕', '隗', '隘', '隙', '際', '障', '隠', '隣', '隧', '隨', '險', '隰', '隱',
+    '隲', '隴', '隶', '隷', '隸', '隹', '隻', '隼', '雀', '雁', '雄', '雅', '集', '雇', '雉', '雋',
+    '雌', '雍', '雎', '雑', '雕', '雖', '雙', '雛', '雜', '離', '難', '雨', '雪', '雫', '雰', '雲',
+    '零', '雷', '雹', '電', '需', '霄', '霆', '震', '霈', '霊', '霍', '霎', '霏', '霑', '霓', '霖',
+    '霙', '霜', '霞', '霤', '霧', '霪', '霰', '露', '霸', '霹', '霽', '霾', '靂', '靄', '靆', '靈',
+    '靉', '青', '靖', '静', '靜', '非', '靠', '靡', '面', '靤', '靦', '靨', '革', '靫', '靭', '靱',
+    '靴', '靹', '靺', '靼', '鞁', '鞄', '鞅', '鞆', '鞋', '鞍', '鞏', '鞐', '鞘', '鞜', '鞠', '鞣',
+    '鞦', '鞨', '鞫', '鞭', '鞳', '鞴', '韃', '韆', '韈', '韋', '韓', '韜', '韭', '韮', '韲', '音',
+    '韵', '韶', '韻', '響', '頁', '頂', '頃', '項', '順', '須', '頌', '頏', '預', '頑', '頒', '頓',
+    '頗', '領', '頚', '頡', '頤', '頬', '頭', '頴', '頷', '頸', '頻', '頼', '頽', '顆', '顋', '題',
+    '額', '顎', '顏', '顔', '顕', '願', '顛', '類', '顧', '顫', '顯', '顰', '顱', '顳', '顴', '風',
+    '颪', '颯', '颱', '颶', '飃', '飄', '飆', '飛', '飜', '食', '飢', '飩', '飫', '飭', '飮', '飯',
+    '飲', '飴', '飼', '飽', '飾', '餃', '餅', '餉', '養', '餌', '餐', '餒', '餓', '餔', '餘', '餝',
+    '餞', '餠', '餡', '餤', '館', '餬', '餮', '餽', '餾', '饂', '饅', '饉', '饋', '饌', '饐', '饑',
+    '饒', '饕', '饗', '首', '馗', '馘', '香', '馥', '馨', '馬', '馭', '馮', '馳', '馴', '馼', '駁',
+    '駄', '駅', '駆', '駈', '駐', '駑', '駒', '駕', '駘', '駛', '駝', '駟', '駢', '駭', '駮', '駱',
+    '駲', '駸', '駻', '駿', '騁', '騅', '騎', '騏', '騒', '験', '騙', '騨', '騫', '騰', '騷', '騾',
+    '驀', '驂', '驃', '驅', '驍', '驕', '驗', '驚', '驛', '驟', '驢', '驤', '驥', '驩', '驪', '驫',
+    '骨', '骭', '骰', '骸', '骼', '髀', '髄', '髏', '髑', '髓', '體', '高', '髞', '髟', '髢', '髣',
+    '髦', '髪', '髫', '髭', '髮', '髯', '髱', '髴', '髷', '髻', '鬆', '鬘', '鬚', '鬟', '鬢', '鬣',
+    '鬥', '鬧', '鬨', '鬩', '鬪', '鬮', '鬯', '鬱', '鬲', '鬻', '鬼', '魁', '魂', '魃', '魄', '魅',
+    '魍', '魎', '魏', '魑', '魔', '魘', '魚', '魯', '魴', '鮃', '鮎', '鮑', '鮒', '鮓', '鮖', '鮗',
+    '鮟', '鮠', '鮨', '鮪', '鮫', '鮭', '鮮', '鮴', '鮹', '鯀', '鯆', '鯉', '鯊', '鯏', '鯑', '鯒',
+    '鯔', '鯖', '鯛', '鯡', '鯢', '鯣', '鯤', '鯨', '鯰', '鯱', '鯲', '鯵', '鰄', '鰆', '鰈', '鰉',
+    '鰊', '鰌', '鰍', '鰐', '鰒', '鰓', '鰔', '鰕', '鰛', '鰡', '鰤', '鰥', '鰭', '鰮', '鰯', '鰰',
+    '鰲', '鰹', '鰺', '鰻', '鰾', '鱆', '鱇', '鱈', '鱒', '鱗', '鱚', '鱠', '鱧', '鱶', '鱸', '鳥',
+    '鳧', '鳩', '鳫', '鳬', '鳰', '鳳', '鳴', '鳶', '鴃', '鴆', '鴇', '鴈', '鴉', '鴎', '鴒', '鴕',
+    '鴛', '鴟', '鴣', '鴦', '鴨', '鴪', '鴫', '鴬', '鴻', '鴾', '鴿', '鵁', '鵄', '鵆', '鵈', '鵐',
+    '鵑', '鵙', '鵜', '鵝', '鵞', '鵠', '鵡', '鵤', '鵬', '鵯', '鵲', '鵺', '鶇', '鶉', '鶏', '鶚',
+    '鶤', '鶩', '鶫', '鶯', '鶲', '鶴', '鶸', '鶺', '鶻', '鷁', '鷂', '鷄', '鷆', '鷏', '鷓', '鷙',
+    '鷦', '鷭', '鷯', '鷲', '鷸', '鷹', '鷺', '鷽', '鸚', '鸛', '鸞', '鹵', '鹸', '鹹', '鹽', '鹿',
+    '麁', '麈', '麋', '麌', '麑', '麒', '麓', '麕', '麗', '麝', '麟', '麥', '麦', '麩', '麪', '麭',
+    '麸', '麹', '麺', '麻', '麼', '麾', '麿', '黄', '黌', '黍', '黎', '黏', '黐', '黒', '黔', '默',
+    '黙', '黛', '黜', '黝', '點', '黠', '黥', '黨', '黯', '黴', '黶', '黷', '黹', '黻', '黼', '黽',
+    '鼇', '鼈', '鼎', '鼓', '鼕', '鼠', '鼡', '鼬', '鼻', '鼾', '齊', '齋', '齎', '齏', '齒', '齔',
+    '齟', '齠', '齡', '齢', '齣', '齦', '齧', '齪', '齬', '齲', '齶', '齷', '龍', '龕', '龜', '龝',
+    '龠', '！', '＃', '＄', '％', '＆', '（', '）', '＊', '＋', '，', '．', '／', '０', '１', '２',
+    '３', '４', '５', '６', '７', '８', '９', '：', '；', '＜', '＝', '＞', '？', '＠', 'Ａ', 'Ｂ',
+    'Ｃ', 'Ｄ', 'Ｅ', 'Ｆ', 'Ｇ', 'Ｈ', 'Ｉ', 'Ｊ', 'Ｋ', 'Ｌ', 'Ｍ', 'Ｎ', 'Ｏ', 'Ｐ', 'Ｑ', 'Ｒ',
+    'Ｓ', 'Ｔ', 'Ｕ', 'Ｖ', 'Ｗ', 'Ｘ', 'Ｙ', 'Ｚ', '［', '＼', '］', '＾', '＿', '｀', 'ａ', 'ｂ',
+    'ｃ', 'ｄ', 'ｅ', 'ｆ', 'ｇ', 'ｈ', 'ｉ', 'ｊ', 'ｋ', 'ｌ', 'ｍ', 'ｎ', 'ｏ', 'ｐ', 'ｑ', 'ｒ',
+    'ｓ', 'ｔ', 'ｕ', 'ｖ', 'ｗ', 'ｘ', 'ｙ', 'ｚ', '｛', '｜', '｝', '￣', '￥',
+];
+
+/// The Shift JIS code of the character at the same index in [`CHARS`]
+pub(super) static SHIFT_JIS: [u16; 6879] = [
+    0x8191, 0x8192, 0x8198, 0x814E, 0x81CA, 0x818B, 0x817D, 0x814C, 0x81F7, 0x817E, 0x8180, 0x839F,
+    0x83A0, 0x83A1, 0x83A2, 0x83A3, 0x83A4, 0x83A5, 0x83A6, 0x83A7, 0x83A8, 0x83A9, 0x83AA, 0x83AB,
+    0x83AC, 0x83AD, 0x83AE, 0x83AF, 0x83B0, 0x83B1, 0x83B2, 0x83B3, 0x83B4, 0x83B5, 0x83B6, 0x83BF,
+    0x83C0, 0x83C1, 0x83C2, 0x83C3, 0x83C4, 0x83C5, 0x83C6, 0x83C7, 0x83C8, 0x83C9, 0x83CA, 0x83CB,
+    0x83CC, 0x83CD, 0x83CE, 0x83CF, 0x83D0, 0x83D1, 0x83D2, 0x83D3, 0x83D4, 0x83D5, 0x83D6, 0x8446,
+    0x8440, 0x8441, 0x8442, 0x8443, 0x8444, 0x8445, 0x8447, 0x8448, 0x8449, 0x844A, 0x844B, 0x844C,
+    0x844D, 0x844E, 0x844F, 0x8450, 0x8451, 0x8452, 0x8453, 0x8454, 0x8455, 0x8456, 0x8457, 0x8458,
+    0x8459, 0x845A, 0x845B, 0x845C, 0x845D, 0x845E, 0x845F, 0x8460, 0x8470, 0x8471, 0x8472, 0x8473,
+    0x8474, 0x8475, 0x8477, 0x8478, 0x8479, 0x847A, 0x847B, 0x847C, 0x847D, 0x847E, 0x8480, 0x8481,
+    0x8482, 0x8483, 0x8484, 0x8485, 0x8486, 0x8487, 0x8488, 0x8489, 0x848A, 0x848B, 0x848C, 0x848D,
+    0x848E, 0x848F, 0x8490, 0x8491, 0x8476, 0x815D, 0x815C, 0x8161, 0x8165, 0x8166, 0x8167, 0x8168,
+    0x81F5, 0x81F6, 0x8164, 0x8163, 0x81F1, 0x818C, 0x818D, 0x81A6, 0x818E, 0x81F0, 0x81A9, 0x81AA,
+    0x81A8, 0x81AB, 0x81CB, 0x81CC, 0x81CD, 0x81DD, 0x81CE, 0x81DE, 0x81B8, 0x81B9, 0x817C, 0x81E3,
+    0x81E5, 0x8187, 0x81DA, 0x81C8, 0x81C9, 0x81BF, 0x81BE, 0x81E7, 0x81E8, 0x8188, 0x81E6, 0x81E4,
+    0x81E0, 0x8182, 0x81DF, 0x8185, 0x8186, 0x81E1, 0x81E2, 0x81BC, 0x81BD, 0x81BA, 0x81BB, 0x81DB,
+    0x81DC, 0x849F, 0x84AA, 0x84A0, 0x84AB, 0x84A1, 0x84AC, 0x84A2, 0x84AD, 0x84A4, 0x84AF, 0x84A3,
+    0x84AE, 0x84A5, 0x84BA, 0x84B5, 0x84B0, 0x84A7, 0x84BC, 0x84B7, 0x84B2, 0x84A6, 0x84B6, 0x84BB,
+    0x84B1, 0x84A8, 0x84B8, 0x84BD, 0x84B3, 0x84A9, 0x84B9, 0x84BE, 0x84B4, 0x81A1, 0x81A0, 0x81A3,
+    0x81A2, 0x81A5, 0x81A4, 0x819F, 0x819E, 0x819B, 0x819D, 0x819C, 0x81FC, 0x819A, 0x8199, 0x818A,
+    0x8189, 0x81F4, 0x81F3, 0x81F2, 0x8140, 0x8141, 0x8142, 0x8156, 0x8158, 0x8159, 0x815A, 0x8171,
+    0x8172, 0x8173, 0x8174, 0x8175, 0x8176, 0x8177, 0x8178, 0x8179, 0x817A, 0x81A7, 0x81AC, 0x816B,
+    0x816C, 0x8160, 0x829F, 0x82A0, 0x82A1, 0x82A2, 0x82A3, 0x82A4, 0x82A5, 0x82A6, 0x82A7, 0x82A8,
+    0x82A9, 0x82AA, 0x82AB, 0x82AC, 0x82AD, 0x82AE, 0x82AF, 0x82B0, 0x82B1, 0x82B2, 0x82B3, 0x82B4,
+    0x82B5, 0x82B6, 0x82B7, 0x82B8, 0x82B9, 0x82BA, 0x82BB, 0x82BC, 0x82BD, 0x82BE, 0x82BF, 0x82C0,
+    0x82C1, 0x82C2, 0x82C3, 0x82C4, 0x82C5, 0x82C6, 0x82C7, 0x82C8, 0x82C9, 0x82CA, 0x82CB, 0x82CC,
+    0x82CD, 0x82CE, 0x82CF, 0x82D0, 0x82D1, 0x82D2, 0x82D3, 0x82D4, 0x82D5, 0x82D6, 0x82D7, 0x82D8,
+    0x82D9, 0x82DA, 0x82DB, 0x82DC, 0x82DD, 0x82DE, 0x82DF, 0x82E0, 0x82E1, 0x82E2, 0x82E3, 0x82E4,
+    0x82E5, 0x82E6, 0x82E7, 0x82E8, 0x82E9, 0x82EA, 0x82EB, 0x82EC, 0x82ED, 0x82EE, 0x82EF, 0x82F0,
+    0x82F1, 0x814A, 0x814B, 0x8154, 0x8155, 0x8340, 0x8341, 0x8342, 0x8343, 0x8344, 0x8345, 0x8346,
+    0x8347, 0x8348, 0x8349, 0x834A, 0x834B, 0x834C, 0x834D, 0x834E, 0x834F, 0x8350, 0x8351, 0x8352,
+    0x8353, 0x8354, 0x8355, 0x8356, 0x8357, 0x8358, 0x8359, 0x835A, 0x835B, 0x835C, 0x835D, 0x835E,
+    0x835F, 0x8360, 0x8361, 0x8362, 0x8363, 0x8364, 0x8365, 0x8366, 0x8367, 0x8368, 0x8369, 0x836A,
+    0x836B, 0x836C, 0x836D, 0x836E, 0x836F, 0x8370, 0x8371, 0x8372, 0x8373, 0x8374, 0x8375, 0x8376,
+    0x8377, 0x8378, 0x8379, 0x837A, 0x837B, 0x837C, 0x837D, 0x837E, 0x8380, 0x8381, 0x8382, 0x8383,
+    0x8384, 0x8385, 0x8386, 0x8387, 0x8388, 0x8389, 0x838A, 0x838B, 0x838C, 0x838D, 0x838E, 0x838F,
+    0x8390, 0x8391, 0x8392, 0x8393, 0x8394, 0x8395, 0x8396, 0x8145, 0x815B, 0x8152, 0x8153, 0x88EA,
+    0x929A, 0x8EB5, 0x969C, 0x8FE4, 0x8E4F, 0x8FE3, 0x89BA, 0x9573, 0x975E, 0x98A0, 0x894E, 0x8A8E,
+    0x98A1, 0x90A2, 0x99C0, 0x8B75, 0x95B8, 0x8FE5, 0x97BC, 0x95C0, 0x98A2, 0x9286, 0x98A3, 0x8BF8,
+    0x98A4, 0x8ADB, 0x924F, 0x8EE5, 0x98A5, 0x98A6, 0x98A7, 0x9454, 0x8B76, 0x9456, 0x93E1, 0x8CC1,
+    0x9652, 0xE568, 0x98A8, 0x8FE6, 0x98A9, 0x89B3, 0x8BE3, 0x8CEE, 0x96E7, 0x9BA4, 0x9790, 0x93FB,
+    0x8AA3, 0x8B54, 0x98AA, 0x98AB, 0x97B9, 0x975C, 0x9188, 0x98AD, 0x8E96, 0x93F1, 0x98B0, 0x895D,
+    0x8CDD, 0x8CDC, 0x88E4, 0x986A, 0x9869, 0x8DB1, 0x889F, 0x98B1, 0x98B2, 0x98B3, 0x9653, 0x98B4,
+    0x8CF0, 0x88E5, 0x9692, 0x8B9C, 0x8B9D, 0x8B9E, 0x92E0, 0x97BA, 0x98B5, 0x98B6, 0x98B7, 0x906C,
+    0x8F59, 0x906D, 0x98BC, 0x98BA, 0x98BB, 0x8B77, 0x8DA1, 0x89EE, 0x98B9, 0x98B8, 0x95A7, 0x8E65,
+    0x8E64, 0x91BC, 0x98BD, 0x9574, 0x90E5, 0x8157, 0x98BE, 0x98C0, 0x91E3, 0x97DF, 0x88C8, 0x98BF,
+    0x89BC, 0x8BC2, 0x9287, 0x8C8F, 0x98C1, 0x9443, 0x8AE9, 0x98C2, 0x88C9, 0x8CDE, 0x8AEA, 0x959A,
+    0x94B0, 0x8B78, 0x89EF, 0x98E5, 0x9360, 0x948C, 0x98C4, 0x94BA, 0x97E0, 0x904C, 0x8E66, 0x8E97,
+    0x89BE, 0x92CF, 0x9241, 0x98C8, 0x88CA, 0x92E1, 0x8F5A, 0x8DB2, 0x9743, 0x91CC, 0x89BD, 0x98C7,
+    0x975D, 0x98C3, 0x98C5, 0x8DEC, 0x98C6, 0x9B43, 0x98CE, 0x98D1, 0x98CF, 0x89C0, 0x95B9, 0x98C9,
+    0x98CD, 0x8CF1, 0x8E67, 0x8AA4, 0x98D2, 0x98CA, 0x97E1, 0x8E98, 0x98CB, 0x98D0, 0x98D3, 0x98CC,
+    0x8B9F, 0x88CB, 0x8BA0, 0x89BF, 0x9B44, 0x9699, 0x958E, 0x8CF2, 0x904E, 0x97B5, 0x95D6, 0x8C57,
+    0x91A3, 0x89E2, 0x8F72, 0x98D7, 0x98DC, 0x98DA, 0x98D5, 0x91AD, 0x98D8, 0x98DB, 0x98D9, 0x95DB,
+    0x98D6, 0x904D, 0x9693, 0x98DD, 0x98DE, 0x8F43, 0x98EB, 0x946F, 0x9555, 0x98E6, 0x95EE, 0x89B4,
+    0x98EA, 0x98E4, 0x98ED, 0x9171, 0x8CC2, 0x947B, 0xE0C5, 0x98EC, 0x937C, 0x98E1, 0x8CF4, 0x8CF3,
+    0x98DF, 0x8ED8, 0x98E7, 0x95ED, 0x926C, 0x98E3, 0x8C91, 0x98E0, 0x98E8, 0x98E2, 0x97CF, 0x98E9,
+    0x9860, 0x8BE4, 0x8C90, 0x98EE, 0x98EF, 0x98F3, 0x88CC, 0x95CE, 0x98F2, 0x98F1, 0x98F5, 0x98F4,
+    0x92E2, 0x8C92, 0x98F6, 0x8EC3, 0x91A4, 0x92E3, 0x8BF4, 0x98F7, 0x8B55, 0x98F8, 0x98FA, 0x9654,
+    0x8C86, 0x8E50, 0x94F5, 0x98F9, 0x8DC3, 0x9762, 0x98FC, 0x9942, 0x98FB, 0x8DC2, 0x8F9D, 0x8C58,
+    0x9943, 0x8BCD, 0x9940, 0x9941, 0x93AD, 0x919C, 0x8BA1, 0x966C, 0x9944, 0x97BB, 0x9945, 0x9948,
+    0x9946, 0x916D, 0x9947, 0x9949, 0x994B, 0x994A, 0x95C6, 0x8B56, 0x994D, 0x994E, 0x89AD, 0x994C,
+    0x8EF2, 0x9951, 0x9950, 0x994F, 0x98D4, 0x9952, 0x8F9E, 0x9953, 0x9744, 0x96D7, 0x9955, 0x9954,
+    0x9957, 0x9956, 0x9958, 0x9959, 0x88F2, 0x8CB3, 0x8C5A, 0x8F5B, 0x929B, 0x8BA2, 0x90E6, 0x8CF5,
+    0x8D8E, 0x995B, 0x96C6, 0x9365, 0x8E99, 0x995A, 0x995C, 0x937D, 0x8A95, 0x995D, 0x93FC, 0x9153,
+    0x995F, 0x9960, 0x94AA, 0x8CF6, 0x985A, 0x9961, 0x8BA4, 0x95BA, 0x91B4, 0x8BEF, 0x9354, 0x8C93,
+    0x9962, 0x9963, 0x93E0, 0x897E, 0x9966, 0x8DFB, 0x9965, 0x8DC4, 0x9967, 0xE3EC, 0x9968, 0x9660,
+    0x9969, 0x996A, 0x996B, 0x8FE7, 0x8ECA, 0x8AA5, 0x996E, 0x996C, 0x96BB, 0x996D, 0x9579, 0x996F,
+    0x9970, 0x9971, 0x937E, 0x9975, 0x9973, 0x9974, 0x9972, 0x8DE1, 0x9976, 0x96E8, 0x97E2, 0x9977,
+    0x90A6, 0x9978, 0x8F79, 0x9979, 0x929C, 0x97BD, 0x9380, 0x99C3, 0x997A, 0xEAA3, 0x8BC3, 0x997B,
+    0x967D, 0x8F88, 0x91FA, 0x997D, 0x93E2, 0x997E, 0x9980, 0x8A4D, 0x9981, 0x8BA5, 0x93CA, 0x899A,
+    0x8F6F, 0x949F, 0x9982, 0x9381, 0x906E, 0x9983, 0x95AA, 0x90D8, 0x8AA0, 0x8AA7, 0x9984, 0x9986,
+    0x8C59, 0x9985, 0x97F1, 0x8F89, 0x94BB, 0x95CA, 0x9987, 0x9798, 0x9988, 0x9989, 0x939E, 0x998A,
+    0x90A7, 0x8DFC, 0x8C94, 0x998B, 0x8E68, 0x8D8F, 0x92E4, 0x998D, 0x91A5, 0x8DED, 0x998E, 0x998F,
+    0x914F, 0x998C, 0x9991, 0x9655, 0x8D84, 0x9990, 0x8C95, 0x8DDC, 0x948D, 0x9994, 0x9992, 0x959B,
+    0x8FE8, 0x999B, 0x8A84, 0x9995, 0x9993, 0x916E, 0x9997, 0x9996, 0x8A63, 0x8C80, 0x999C, 0x97AB,
+    0x9998, 0x999D, 0x999A, 0x9999, 0x97CD, 0x8CF7, 0x89C1, 0x97F2, 0x8F95, 0x9377, 0x8D85, 0x99A0,
+    0x99A1, 0x97E3, 0x984A, 0x99A3, 0x8CF8, 0x99A2, 0x8A4E, 0x99A4, 0x9675, 0x92BA, 0x9745, 0x95D7,
+    0x99A5, 0xE8D3, 0x93AE, 0x99A6, 0x8AA8, 0x96B1, 0x8F9F, 0x99A7, 0x95E5, 0x99AB, 0x90A8, 0x99A8,
+    0x8BCE, 0x99A9, 0x8AA9, 0x8C4D, 0x99AC, 0x99AD, 0x99AE, 0x99AF, 0x8ED9, 0x8CF9, 0x96DC, 0x96E6,
+    0x93F5, 0x95EF, 0x99B0, 0x99B1, 0x99B3, 0x99B5, 0x99B4, 0x99B6, 0x89BB, 0x966B, 0x8DFA, 0x99B7,
+    0x9178, 0x8FA0, 0x8BA7, 0x99B8, 0x94D9, 0x99B9, 0x99BA, 0x99BB, 0x99BC, 0x9543, 0x8BE6, 0x88E3,
+    0x93BD, 0x99BD, 0x8F5C, 0x90E7, 0x99BF, 0x99BE, 0x8FA1, 0x8CDF, 0x99C1, 0x94BC, 0x99C2, 0x94DA,
+    0x91B2, 0x91EC, 0x8BA6, 0x93EC, 0x9250, 0x948E, 0x966D, 0x99C4, 0x90E8, 0x8C54, 0x99C5, 0x99C6,
+    0x894B, 0x88F3, 0x8AEB, 0x91A6, 0x8B70, 0x9791, 0x99C9, 0x89B5, 0x99C8, 0x8BA8, 0x99CA, 0x96EF,
+    0x99CB, 0x97D0, 0x8CFA, 0x8CB4, 0x99CC, 0x99CE, 0x99CD, 0x907E, 0x8958, 0x897D, 0x99CF, 0x99D0,
+    0x8CB5, 0x99D1, 0x8B8E, 0x8E51, 0x99D2, 0x9694, 0x8DB3, 0x8B79, 0x9746, 0x916F, 0x94BD, 0x8EFB,
+    0x8F66, 0x8EE6, 0x8EF3, 0x8F96, 0x94BE, 0x99D5, 0x8962, 0x9170, 0x8CFB, 0x8CC3, 0x8BE5, 0x99D9,
+    0x9240, 0x91FC, 0x8BA9, 0x8FA2, 0x99DA, 0x99D8, 0x89C2, 0x91E4, 0x8EB6, 0x8E6A, 0x8945, 0x8A90,
+    0x8D86, 0x8E69, 0x99DB, 0x99DC, 0x8B68, 0x8A65, 0x8D87, 0x8B67, 0x92DD, 0x8944, 0x93AF, 0x96BC,
+    0x8D40, 0x9799, 0x9366, 0x8CFC, 0x8C4E, 0x99E5, 0x8BE1, 0x9669, 0x94DB, 0x99E4, 0x8ADC, 0x99DF,
+    0x99E0, 0x99E2, 0x99E3, 0x8B7A, 0x9081, 0x95AB, 0x99E1, 0x99DD, 0x8CE1, 0x99DE, 0x9843, 0x95F0,
+    0x92E6, 0x8CE0, 0x8D90, 0x99E6, 0x93DB, 0x99EA, 0x8EFC, 0x8EF4, 0x99ED, 0x99EB, 0x96A1, 0x99E8,
+    0x99F1, 0x99EC, 0x99EF, 0x8CC4, 0x96BD, 0x99F0, 0x99F2, 0x99F4, 0x8DEE, 0x9861, 0x99E9, 0x99E7,
+    0x99F3, 0x99EE, 0x99F6, 0x9A42, 0x99F8, 0x99FC, 0x9A40, 0x99F9, 0x9A5D, 0x8DE7, 0x8A50, 0x99F7,
+    0x9A44, 0x88F4, 0x9A43, 0x88A3, 0x9569, 0x9A41, 0x99FA, 0x99F5, 0x99FB, 0x8DC6, 0x9A45, 0x88F5,
+    0x9A4E, 0x9A46, 0x9A47, 0x8FA3, 0x9689, 0x9A4C, 0x9A4B, 0x934E, 0x9A4D, 0x9A4A, 0x8953, 0x8DB4,
+    0x904F, 0x9A48, 0x9382, 0x9A49, 0x88A0, 0x9A53, 0x9742, 0x8FA5, 0x9A59, 0x9A58, 0x9A4F, 0x91C1,
+    0x9A50, 0x91ED, 0x9A55, 0x8FA4, 0x9A52, 0x96E2, 0x8C5B, 0x9A56, 0x9A57, 0x9A54, 0x9A5A, 0x9A51,
+    0x9A60, 0x9A65, 0x9A61, 0x9A5C, 0x9A66, 0x9150, 0x9A68, 0x8D41, 0x9A5E, 0x929D, 0x9A62, 0x9A5B,
+    0x8AAB, 0x8AEC, 0x8A85, 0x9A63, 0x9A5F, 0x8C96, 0x9A69, 0x9A67, 0x9172, 0x8B69, 0x8BAA, 0x9A64,
+    0x8BF2, 0x8963, 0x9A6D, 0x9A6B, 0x9AA5, 0x9A70, 0x9A6A, 0x9A6E, 0x9A6C, 0x8E6B, 0x9A6F, 0x9A72,
+    0x9A77, 0x9A75, 0x9A74, 0x9251, 0x89C3, 0x9A71, 0x9A73, 0x8FA6, 0x8952, 0x9A76, 0x89DC, 0x9A82,
+    0x8FFA, 0x9A7D, 0x9A7B, 0x9A7C, 0x9A7E, 0x895C, 0x9158, 0x9A78, 0x9A79, 0x8A9A, 0x9A81, 0x8AED,
+    0x9A84, 0x9A80, 0x9A83, 0x95AC, 0x93D3, 0x94B6, 0x9A86, 0x9A85, 0x8A64, 0x9A87, 0x9A8A, 0x9A89,
+    0x9A88, 0x9458, 0x9A8B, 0x9A8C, 0x9A8E, 0x9A8D, 0x9A90, 0x9A93, 0x9A91, 0x9A8F, 0x9A92, 0x9A94,
+    0x9A95, 0x9A96, 0x9A97, 0x9A98, 0x9964, 0x8EFA, 0x8E6C, 0x89F1, 0x88F6, 0x9263, 0x9A99, 0x8DA2,
+    0x88CD, 0x907D, 0x9A9A, 0x8CC5, 0x8D91, 0x9A9C, 0x9A9B, 0x95DE, 0x9A9D, 0x9A9F, 0x9A9E, 0x9AA0,
+    0x9AA1, 0x8C97, 0x8980, 0x9AA2, 0x9AA4, 0x9AA3, 0x9AA6, 0x9379, 0x9AA7, 0x88B3, 0x8DDD, 0x8C5C,
+    0x926E, 0x9AA8, 0x9AA9, 0x9AAB, 0x9AAC, 0x8DE2, 0x8BCF, 0x9656, 0x9AAA, 0x9AAD, 0x8DBF, 0x8D42,
+    0x9AB1, 0x8DA3, 0x9252, 0x9AAE, 0x92D8, 0x9AB2, 0x9082, 0x9AB0, 0x9AB3, 0x8C5E, 0x9AB4, 0x9AB5,
+    0x8D43, 0x8A5F, 0x9AB7, 0x9AB8, 0x9AB9, 0x9AB6, 0x9AAF, 0x9ABA, 0x9ABB, 0x9684, 0x8FE9, 0x9ABD,
+    0x9ABE, 0x9ABC, 0x9AC0, 0x9457, 0x88E6, 0x9575, 0x9AC1, 0x8FFB, 0x8EB7, 0x947C, 0x8AEE, 0x8DE9,
+    0x9678, 0x93B0, 0x8C98, 0x91CD, 0x9ABF, 0x9AC2, 0x91C2, 0x9AC3, 0x9AC4, 0x9AC6, 0x92E7, 0x8AAC,
+    0xEA9F, 0x8981, 0x95F1, 0x8FEA, 0x9367, 0x8DE4, 0x9ACC, 0x95BB, 0x97DB, 0x89F2, 0x9AC8, 0x9159,
+    0x9ACB, 0x9383, 0x9368, 0x9384, 0x94B7, 0x92CB, 0x8DC7, 0x9AC7, 0x8996, 0x9355, 0x9AC9, 0x9AC5,
+    0x906F, 0x9ACD, 0x8F6D, 0x8BAB, 0x9ACE, 0x95E6, 0x919D, 0x92C4, 0x9AD0, 0x966E, 0x9AD1, 0x9AD6,
+    0x95AD, 0x9AD5, 0x9ACF, 0x9AD2, 0x9AD4, 0x8DA4, 0x95C7, 0x9AD7, 0x9264, 0x89F3, 0x8FEB, 0x9AD9,
+    0x9AD8, 0x8D88, 0x9ADA, 0x9ADC, 0x9ADB, 0x9ADE, 0x9AD3, 0x9AE0, 0x9ADF, 0x9ADD, 0x8E6D, 0x9070,
+    0x9173, 0x9AE1, 0x90BA, 0x88EB, 0x9484, 0x92D9, 0x9AE3, 0x9AE2, 0x9AE4, 0x9AE5, 0x9AE6, 0x9AE7,
+    0x95CF, 0x9AE8, 0x89C4, 0x9AE9, 0x975B, 0x8A4F, 0x99C7, 0x8F67, 0x91BD, 0x9AEA, 0x96E9, 0x96B2,
+    0x9AEC, 0x91E5, 0x9356, 0x91BE, 0x9576, 0x9AED, 0x9AEE, 0x899B, 0x8EB8, 0x9AEF, 0x88CE, 0x9AF0,
+    0x9AF1, 0x8982, 0x8AEF, 0x93DE, 0x95F2, 0x9AF5, 0x9174, 0x9AF4, 0x8C5F, 0x967A, 0x9AF3, 0x9385,
+    0x9AF7, 0x9AF6, 0x9AF9, 0x9AF8, 0x899C, 0x9AFA, 0x8FA7, 0x9AFC, 0x9244, 0x9AFB, 0x95B1, 0x8F97,
+    0x937A, 0x9B40, 0x8D44, 0x9B41, 0x9440, 0x94DC, 0x96CF, 0x9444, 0x9B4A, 0x8B57, 0x9764, 0x96AD,
+    0x9BAA, 0x9B42, 0x9B45, 0x91C3, 0x9657, 0x9369, 0x9B46, 0x9685, 0x8DC8, 0x8FA8, 0x9B47, 0x8E6F,
+    0x8E6E, 0x88B7, 0x8CC6, 0x90A9, 0x88CF, 0x9B4B, 0x9B4C, 0x9B49, 0x8957, 0x8AAD, 0x9B48, 0x96C3,
+    0x9550, 0x88A6, 0x88F7, 0x8E70, 0x88D0, 0x88A1, 0x9B51, 0x9B4F, 0x96BA, 0x9B52, 0x9B50, 0x9B4E,
+    0x9050, 0x9B4D, 0x95D8, 0x8CE2, 0x9B56, 0x9B57, 0x8FA9, 0x9B53, 0x984B, 0x946B, 0x9B55, 0x8DA5,
+    0x9B58, 0x9577, 0x9B59, 0x9B54, 0x96B9, 0x947D, 0x9B5A, 0x9551, 0x9B5B, 0x9B5F, 0x9B5C, 0x89C5,
+    0x9B5E, 0x8EB9, 0x9B5D, 0x8C99, 0x9B6B, 0x9B64, 0x9B61, 0x9284, 0x9B60, 0x9B62, 0x9B63, 0x9B65,
+    0x9B66, 0x8AF0, 0x9B68, 0x9B67, 0x9B69, 0x8FEC, 0x9B6C, 0x92DA, 0x8964, 0x9B6A, 0x9B6D, 0x9B6E,
+    0x9B71, 0x9B6F, 0x9B70, 0x8E71, 0x9B72, 0x8D45, 0x9B73, 0x8E9A, 0x91B6, 0x9B74, 0x9B75, 0x8E79,
+    0x8D46, 0x96D0, 0x8B47, 0x8CC7, 0x9B76, 0x8A77, 0x9B77, 0x91B7, 0x9B78, 0x9BA1, 0x9B79, 0x9B7A,
+    0x9B7B, 0x9B7D, 0x9B7E, 0x9B80, 0x91EE, 0x8946, 0x8EE7, 0x88C0, 0x9176, 0x8AAE, 0x8EB3, 0x8D47,
+    0x9386, 0x8F40, 0x8AAF, 0x9288, 0x92E8, 0x88B6, 0x8B58, 0x95F3, 0x8EC0, 0x8B71, 0x90E9, 0x8EBA,
+    0x9747, 0x9B81, 0x8B7B, 0x8DC9, 0x8A51, 0x8983, 0x8FAA, 0x89C6, 0x9B82, 0x9765, 0x8F68, 0x8EE2,
+    0x9B83, 0x8AF1, 0x93D0, 0x96A7, 0x9B84, 0x9B85, 0x9578, 0x9B87, 0x8AA6, 0x8BF5, 0x9B86, 0x8AB0,
+    0x9051, 0x9B8B, 0x8E40, 0x89C7, 0x9B8A, 0x9B88, 0x9B8C, 0x9B89, 0x944A, 0x9ECB, 0x9052, 0x9B8D,
+    0x97BE, 0x9B8E, 0x9B90, 0x929E, 0x9B8F, 0x90A1, 0x8E9B, 0x91CE, 0x8EF5, 0x9595, 0x90EA, 0x8ECB,
+    0x9B91, 0x8FAB, 0x9B92, 0x9B93, 0x88D1, 0x91B8, 0x9071, 0x9B94, 0x93B1, 0x8FAC, 0x8FAD, 0x9B95,
+    0x90EB, 0x8FAE, 0x9B96, 0x9B97, 0x96DE, 0x9B98, 0x8BC4, 0x8F41, 0x9B99, 0x9B9A, 0x8EDA, 0x904B,
+    0x93F2, 0x9073, 0x94F6, 0x9441, 0x8BC7, 0x9B9B, 0x8B8F, 0x9B9C, 0x8BFC, 0x93CD, 0x89AE, 0x8E72,
+    0x9B9D, 0x9BA0, 0x9B9F, 0x8BFB, 0x9B9E, 0x9357, 0x91AE, 0x936A, 0x8EC6, 0x9177, 0x979A, 0x9BA2,
+    0x9BA3, 0x93D4, 0x8E52, 0x9BA5, 0x9BA6, 0x9BA7, 0x8AF2, 0x9BA8, 0x9BA9, 0x89AA, 0x915A, 0x8AE2,
+    0x9BAB, 0x96A6, 0x91D0, 0x8A78, 0x9BAD, 0x9BAF, 0x8ADD, 0x9BAC, 0x9BAE, 0x9BB1, 0x9BB0, 0x9BB2,
+    0x9BB3, 0x93BB, 0x8BAC, 0x89E3, 0x9BB4, 0x9BB9, 0x9BB7, 0x95F5, 0x95F4, 0x9387, 0x9BB6, 0x8F73,
+    0x9BB5, 0x9092, 0x9BBA, 0x8DE8, 0x9BC0, 0x9BC1, 0x9BBB, 0x8A52, 0x9BBC, 0x9BC5, 0x9BC4, 0x9BC3,
+    0x9BBF, 0x9BBE, 0x9BC2, 0x95F6, 0x9BC9, 0x9BC6, 0x9BC8, 0x9792, 0x9BC7, 0x9BBD, 0x9093, 0x9BCA,
+    0x8DB5, 0x9BCB, 0x9BCC, 0x9BCF, 0x9BCE, 0x9BCD, 0x9388, 0x9BB8, 0x9BD5, 0x9BD1, 0x9BD0, 0x9BD2,
+    0x9BD3, 0x9BD6, 0x97E4, 0x9BD7, 0x9BD4, 0x9BD8, 0x8ADE, 0x9BD9, 0x9BDB, 0x9BDA, 0x9BDC, 0x9BDD,
+    0x90EC, 0x8F42, 0x8F84, 0x9183, 0x8D48, 0x8DB6, 0x8D49, 0x8B90, 0x9BDE, 0x8DB7, 0x8CC8, 0x9BDF,
+    0x96A4, 0x9462, 0x9BE0, 0x8D4A, 0x8AAA, 0x9246, 0x8BD0, 0x8E73, 0x957A, 0x94BF, 0x9BE1, 0x8AF3,
+    0x9BE4, 0x929F, 0x9BE3, 0x9BE2, 0x9BE5, 0x92E9, 0x9083, 0x8E74, 0x90C8, 0x91D1, 0x8B41, 0x92A0,
+    0x9BE6, 0x9BE7, 0x8FED, 0x9658, 0x9BEA, 0x9BE9, 0x9BE8, 0x959D, 0x9BF1, 0x9679, 0x9BEB, 0x9BED,
+    0x968B, 0x9BEC, 0x9BEE, 0x94A6, 0x9BEF, 0x95BC, 0x9BF0, 0x8AB1, 0x95BD, 0x944E, 0x9BF2, 0x9BF3,
+    0x8D4B, 0x8AB2, 0x9BF4, 0x8CB6, 0x9763, 0x9748, 0x8AF4, 0x9BF6, 0x92A1, 0x8D4C, 0x8FAF, 0x94DD,
+    0x8FB0, 0x8F98, 0x92EA, 0x95F7, 0x9358, 0x8D4D, 0x957B, 0x9BF7, 0x9378, 0x8DC0, 0x8CC9, 0x92EB,
+    0x88C1, 0x8F8E, 0x8D4E, 0x9766, 0x9BF8, 0x9BF9, 0x9470, 0x9BFA, 0x97F5, 0x984C, 0x9BFC, 0x9BFB,
+    0x8A66, 0x9C40, 0x9C43, 0x9C44, 0x9C42, 0x955F, 0x8FB1, 0x9C46, 0x9C45, 0x9C41, 0x9C47, 0x9C48,
+    0x9C49, 0x9C4C, 0x9C4A, 0x9C4B, 0x9C4D, 0x8984, 0x92EC, 0x9C4E, 0x8C9A, 0x89F4, 0x9455, 0x9C4F,
+    0x93F9, 0x95D9, 0x9C50, 0x984D, 0x9C51, 0x95BE, 0x9C54, 0x989F, 0x98AF, 0x8EAE, 0x93F3, 0x9C55,
+    0x8B7C, 0x92A2, 0x88F8, 0x9C56, 0x95A4, 0x8D4F, 0x926F, 0x92ED, 0x96ED, 0x8CB7, 0x8CCA, 0x9C57,
+    0x9C58, 0x9C5E, 0x8EE3, 0x92A3, 0x8BAD, 0x9C59, 0x954A, 0x9265, 0x9C5A, 0x9C5B, 0x8BAE, 0x9C5C,
+    0x9C5D, 0x9C5F, 0x9396, 0x9C60, 0x9C61, 0x9C62, 0x9C53, 0x9C52, 0x9C63, 0x8C60, 0x9546, 0x8DCA,
+    0x9556, 0x92A4, 0x956A, 0x9C64, 0x8FB2, 0x8965, 0x9C65, 0x9C66, 0x96F0, 0x94DE, 0x9C69, 0x899D,
+    0x90AA, 0x9C68, 0x9C67, 0x8C61, 0x91D2, 0x9C6D, 0x9C6B, 0x9C6A, 0x97A5, 0x8CE3, 0x8F99, 0x9C6C,
+    0x936B, 0x8F5D, 0x93BE, 0x9C70, 0x9C6F, 0x9C6E, 0x9C71, 0x8CE4, 0x9C72, 0x959C, 0x8F7A, 0x9C73,
+    0x94F7, 0x93BF, 0x92A5, 0x934F, 0x9C74, 0x8B4A, 0x9053, 0x954B, 0x8AF5, 0x9445, 0x9C75, 0x8E75,
+    0x9659, 0x965A, 0x899E, 0x9C7A, 0x9289, 0x9C77, 0x89F5, 0x9CAB, 0x9C79, 0x944F, 0x9C78, 0x9C76,
+    0x8D9A, 0x9C7C, 0x9C83, 0x9C89, 0x9C81, 0x937B, 0x9C86, 0x957C, 0x9C80, 0x9C85, 0x97E5, 0x8E76,
+    0x91D3, 0x9C7D, 0x8B7D, 0x9C88, 0x90AB, 0x8985, 0x9C82, 0x89F6, 0x9C87, 0x8BAF, 0x9C84, 0x9C8A,
+    0x9C8C, 0x9C96, 0x9C94, 0x9C91, 0x9C90, 0x97F6, 0x9C92, 0x8BB0, 0x8D50, 0x8F9A, 0x9C99, 0x9C8B,
+    0x9C8F, 0x9C7E, 0x89F8, 0x9C93, 0x9C95, 0x9270, 0x8DA6, 0x89B6, 0x9C8D, 0x9C98, 0x9C97, 0x8BB1,
+    0x91A7, 0x8A86, 0x8C62, 0x9C8E, 0x9C9A, 0x9C9D, 0x9C9F, 0x8EBB, 0x9CA5, 0x92EE, 0x9C9B, 0x9CA3,
+    0x89F7, 0x9CA1, 0x9CA2, 0x9C9E, 0x9CA0, 0x8CE5, 0x9749, 0x8AB3, 0x8978, 0x9CA4, 0x9459, 0x88AB,
+    0x94DF, 0x9C7B, 0x9CAA, 0x9CAE, 0x96E3, 0x9CA7, 0x9389, 0x9CAC, 0x8FEE, 0x9CAD, 0x93D5, 0x9866,
+    0x9CA9, 0x9CAF, 0x8D9B, 0x90C9, 0x88D2, 0x9CA8, 0x9CA6, 0x9179, 0x9C9C, 0x8E53, 0x91C4, 0x9CBB,
+    0x917A, 0x9CB6, 0x9CB3, 0x9CB4, 0x8EE4, 0x9CB7, 0x9CBA, 0x9CB5, 0x8F44, 0x9CB8, 0x9CB2, 0x96FA,
+    0x96F9, 0x9CBC, 0x9CBD, 0x88D3, 0x9CB1, 0x8BF0, 0x88A4, 0x8AB4, 0x9CB9, 0x9CC1, 0x9CC0, 0x9CC5,
+    0x9CC6, 0x9CC4, 0x9CC7, 0x9CBF, 0x9CC3, 0x9CC8, 0x9CC9, 0x9CBE, 0x8E9C, 0x9CC2, 0x91D4, 0x8D51,
+    0x9CB0, 0x9054, 0x9CD6, 0x95E7, 0x9CCC, 0x9CCD, 0x9CCE, 0x9CD5, 0x9CD4, 0x969D, 0x8AB5, 0x9CD2,
+    0x8C64, 0x8A53, 0x9CCF, 0x97B6, 0x9CD1, 0x88D4, 0x9CD3, 0x9CCA, 0x9CD0, 0x9CD7, 0x8C63, 0x9CCB,
+    0x977C, 0x974A, 0x9CDA, 0x9CDE, 0x919E, 0x97F7, 0x9CDF, 0x9CDC, 0x9CD9, 0x9CD8, 0x9CDD, 0x95AE,
+    0x93B2, 0x8C65, 0x9CE0, 0x9CDB, 0x9CE1, 0x8C9B, 0x89AF, 0x9CE9, 0x8AB6, 0x9CE7, 0x9CE8, 0x8DA7,
+    0x9CE6, 0x9CE4, 0x9CE3, 0x9CEA, 0x9CE2, 0x9CEC, 0x89F9, 0x9CEE, 0x9CED, 0x92A6, 0x9CF1, 0x9CEF,
+    0x9CE5, 0x8C9C, 0x9CF0, 0x9CF4, 0x9CF3, 0x9CF5, 0x9CF2, 0x9CF6, 0x9CF7, 0x9CF8, 0x95E8, 0x9CFA,
+    0x9CF9, 0x8F5E, 0x90AC, 0x89E4, 0x89FA, 0x9CFB, 0x88BD, 0x90CA, 0x9CFC, 0xE6C1, 0x9D40, 0x8C81,
+    0x9D41, 0x90ED, 0x9D42, 0x9D43, 0x8B59, 0x9D44, 0x9D45, 0x9D46, 0x91D5, 0x8CCB, 0x96DF, 0x965B,
+    0x8F8A, 0x9D47, 0x90EE, 0xE7BB, 0x94E0, 0x8EE8, 0x8DCB, 0x9D48, 0x91C5, 0x95A5, 0x91EF, 0x9D4B,
+    0x9D49, 0x9D4C, 0x9D4A, 0x9D4D, 0x95AF, 0x88B5, 0x957D, 0x94E1, 0x9D4E, 0x9D51, 0x8FB3, 0x8B5A,
+    0x9D4F, 0x9D56, 0x8FB4, 0x9D50, 0x9463, 0x977D, 0x9D52, 0x9D53, 0x9D57, 0x938A, 0x9D54, 0x8D52,
+    0x90DC, 0x9D65, 0x94B2, 0x91F0, 0x94E2, 0x9DAB, 0x95F8, 0x92EF, 0x9695, 0x9D5A, 0x899F, 0x928A,
+    0x9D63, 0x9253, 0x9D5D, 0x9D64, 0x9D5F, 0x9D66, 0x9D62, 0x9D61, 0x948F, 0x9D5B, 0x89FB, 0x9D59,
+    0x8B91, 0x91F1, 0x9D55, 0x9D58, 0x8D53, 0x90D9, 0x8FB5, 0x9D60, 0x9471, 0x8B92, 0x8A67, 0x8A87,
+    0x9040, 0x9D68, 0x9D6D, 0x9D69, 0x8C9D, 0x9D6E, 0x8E41, 0x8D89, 0x8F45, 0x9D5C, 0x8E9D, 0x9D6B,
+    0x8E77, 0x9D6C, 0x88C2, 0x9D67, 0x92A7, 0x8B93, 0x8BB2, 0x9D6A, 0x88A5, 0x8DC1, 0x9055, 0x92F0,
+    0x94D2, 0x9D70, 0x917D, 0x91A8, 0x8E4A, 0x9D71, 0x9D73, 0x9D6F, 0x95DF, 0x92BB, 0x917B, 0x95F9,
+    0x8ECC, 0x9D80, 0x9D7E, 0x9098, 0x8C9E, 0x9D78, 0x8FB7, 0x93E6, 0x9450, 0x9D76, 0x917C, 0x8EF6,
+    0x9D7B, 0x8FB6, 0x9D75, 0x9D7A, 0x9472, 0x9D74, 0x8C40, 0x8A7C, 0x9D7C, 0x97A9, 0x8DCC, 0x9254,
+    0x9D79, 0x90DA, 0x8D54, 0x9084, 0x8986, 0x915B, 0x9D77, 0x8B64, 0x8C66, 0x92CD, 0x9D7D, 0x917E,
+    0x9D81, 0x9D83, 0x91B5, 0x9D89, 0x9D84, 0x9D86, 0x9560, 0x92F1, 0x9D87, 0x974B, 0x9767, 0x8AB7,
+    0x88AC, 0x9D85, 0x9D82, 0x8AF6, 0x8987, 0x9D88, 0x9768, 0x9D8C, 0x91B9, 0x9D93, 0x9D8D, 0x9D8A,
+    0x9D91, 0x9D72, 0x9D8E, 0x9D92, 0x94C0, 0x938B, 0x9D8B, 0x9D8F, 0x8C67, 0x8DEF, 0x90DB, 0x9D97,
+    0x9345, 0x9D94, 0x9680, 0x9D95, 0x9D96, 0x96CC, 0x90A0, 0x8C82, 0x9D9D, 0x8E54, 0x9D9A, 0x9D99,
+    0x9451, 0x93B3, 0x9350, 0x9D9B, 0x9D9C, 0x958F, 0x9464, 0x8E42, 0x90EF, 0x966F, 0x8A68, 0x9DA3,
+    0x9D9E, 0x9769, 0x9DA5, 0x9DA1, 0x9DA2, 0x9180, 0x9DA0, 0x9D5E, 0x9DA4, 0x9D9F, 0x9DA9, 0x9DAA,
+    0x9346, 0x9DAC, 0x8E43, 0x9DA7, 0x8B5B, 0x9DAD, 0x9DA6, 0x9DB1, 0x9DB0, 0x9DAF, 0x9DB2, 0x9DB4,
+    0x8FEF, 0x9DB3, 0x9DB7, 0x9DB5, 0x9DB6, 0x9D90, 0x9DB9, 0x9DB8, 0x9D98, 0x9DBA, 0x9DAE, 0x8E78,
+    0x9DBB, 0x9DBC, 0x9DBE, 0x9DBD, 0x9DBF, 0x89FC, 0x8D55, 0x95FA, 0x90AD, 0x8CCC, 0x9DC1, 0x9DC4,
+    0x9571, 0x8B7E, 0x9DC3, 0x9DC2, 0x9473, 0x9DC5, 0x8BB3, 0x9DC7, 0x9DC6, 0x8AB8, 0x8E55, 0x93D6,
+    0x8C68, 0x9094, 0x9DC8, 0x90AE, 0x9347, 0x957E, 0x9DC9, 0x9DCA, 0x9DCB, 0x95B6, 0x9B7C, 0x90C4,
+    0x956B, 0x8DD6, 0x94E3, 0x94C1, 0x936C, 0x97BF, 0x9DCD, 0x8ECE, 0x9DCE, 0x88B4, 0x8BD2, 0x90CB,
+    0x9580, 0x9DCF, 0x8E61, 0x9266, 0x8E7A, 0x9056, 0x9DD0, 0x95FB, 0x8997, 0x8E7B, 0x9DD3, 0x9DD1,
+    0x9DD4, 0x97B7, 0x9DD2, 0x90F9, 0x9DD5, 0x91B0, 0x9DD6, 0x8AF8, 0x9DD8, 0x9DD7, 0x9DD9, 0x9DDA,
+    0x8AF9, 0x93FA, 0x9255, 0x8B8C, 0x8E7C, 0x9181, 0x8F7B, 0x88AE, 0x9DDB, 0x89A0, 0x9DDF, 0x8D56,
+    0x9DDE, 0x8DA9, 0x8FB8, 0x9DDD, 0x8FB9, 0x96BE, 0x8DA8, 0x88D5, 0x90CC, 0x9DE4, 0x90AF, 0x8966,
+    0x8F74, 0x9686, 0x8DF0, 0x8FBA, 0x90A5, 0x9DE3, 0x9DE1, 0x9DE2, 0x928B, 0x9E45, 0x9DE8, 0x8E9E,
+    0x8D57, 0x9DE6, 0x9DE7, 0x9057, 0x9DE5, 0x8E4E, 0x9DEA, 0x9DE9, 0x9DEE, 0x9DEF, 0x9DEB, 0x8A41,
+    0x9DEC, 0x9DED, 0x94D3, 0x9581, 0x8C69, 0x9DF0, 0x90B0, 0x8FBB, 0x9271, 0x8BC5, 0x9DF1, 0x9DF5,
+    0x89C9, 0x9DF2, 0x9DF4, 0x9DF3, 0x8F8B, 0x9267, 0x88C3, 0x9DF6, 0x9DF7, 0x92A8, 0x97EF, 0x8E62,
+    0x95E9, 0x965C, 0x9E41, 0x9DF9, 0x9DFC, 0x9DFB, 0x9DF8, 0x9E40, 0x93DC, 0x9DFA, 0x9E42, 0x8F8C,
+    0x9E43, 0x976A, 0x9498, 0x9E44, 0x9E46, 0x9E47, 0x9E48, 0x8BC8, 0x8967, 0x8D58, 0x9E49, 0x9E4A,
+    0x8F91, 0x9182, 0x99D6, 0x915D, 0x915C, 0x91D6, 0x8DC5, 0x98F0, 0x8C8E, 0x974C, 0x95FC, 0x959E,
+    0x9E4B, 0x8DF1, 0x92BD, 0x9E4C, 0x984E, 0x965D, 0x92A9, 0x9E4D, 0x8AFA, 0x9E4E, 0x9E4F, 0x96D8,
+    0x96A2, 0x9696, 0x967B, 0x8E44, 0x9E51, 0x8EE9, 0x9670, 0x9E53, 0x9E56, 0x9E55, 0x8AF7, 0x8B80,
+    0x9E52, 0x9E54, 0x9E57, 0x9099, 0x979B, 0x88C7, 0x8DDE, 0x91BA, 0x8EDB, 0x8FF1, 0x9E5A, 0x936D,
+    0x9E58, 0x91A9, 0x9E59, 0x8FF0, 0x96DB, 0x9E5B, 0x9E5C, 0x9788, 0x9E61, 0x8D59, 0x9474, 0x9E5E,
+    0x938C, 0x9DDC, 0x9DE0, 0x8B6E, 0x9466, 0x9E60, 0x8FBC, 0x94C2, 0x9E66, 0x94F8, 0x9E5D, 0x9E63,
+    0x9E62, 0x90CD, 0x968D, 0x97D1, 0x9687, 0x89CA, 0x8E7D, 0x9867, 0x9E65, 0x9095, 0x9E64, 0x9E5F,
+    0x8CCD, 0x9E6B, 0x9E69, 0x89CB, 0x9E67, 0x9E6D, 0x9E73, 0x91C6, 0x95BF, 0x9E75, 0x9541, 0x9E74,
+    0x9490, 0x965E, 0x8AB9, 0x90F5, 0x8F5F, 0x92D1, 0x974D, 0x9E70, 0x9E6F, 0x9E71, 0x9E6E, 0x9E76,
+    0x9E6C, 0x9E6A, 0x9E72, 0x9E68, 0x928C, 0x96F6, 0x8EC4, 0x8DF2, 0x8DB8, 0x968F, 0x8A60, 0x92CC,
+    0x93C8, 0x8968, 0x90F0, 0x90B2, 0x8C49, 0x9E78, 0x8D5A, 0x8A9C, 0x9E7A, 0x8A94, 0x9E81, 0x9E7D,
+    0x90F1, 0x8A6A, 0x8DAA, 0x8A69, 0x8DCD, 0x9E7B, 0x8C85, 0x8C6A, 0x938D, 0x9E79, 0x88C4, 0x9E7C,
+    0x9E7E, 0x8BCB, 0x8C4B, 0x8ABA, 0x8B6A, 0x9E82, 0x8DF7, 0x9691, 0x8E56, 0x9E83, 0x954F, 0x9E8F,
+    0x89B1, 0x9E84, 0x9E95, 0x9E85, 0x97C0, 0x9E8C, 0x947E, 0x9E94, 0x9E87, 0x88B2, 0x9E89, 0x8D5B,
+    0x9E8B, 0x9E8A, 0x9E86, 0x9E91, 0x8FBD, 0x9AEB, 0x8CE6, 0x979C, 0x9E88, 0x92F2, 0x8A42, 0x8DAB,
+    0x9E80, 0x9E90, 0x8A81, 0x9E8E, 0x9E92, 0x938E, 0x8AFC, 0x9EB0, 0x96C7, 0x9E97, 0x8AFB, 0x9E9E,
+    0x965F, 0x9E9F, 0x9EA1, 0x9EA5, 0x9E99, 0x9249, 0x938F, 0x9EA9, 0x9E9C, 0x9EA6, 0x9EA0, 0x9058,
+    0x9EAA, 0x90B1, 0x9EA8, 0x8ABB, 0x986F, 0x9E96, 0x9EA4, 0x88D6, 0x9E98, 0x96B8, 0x9E9D, 0x9041,
+    0x92C5, 0x9E93, 0x9EA3, 0x909A, 0x9EAD, 0x8A91, 0x8C9F, 0x9EAF, 0x9E9A, 0x9EAE, 0x9EA7, 0x9E9B,
+    0x9EAB, 0x9EAC, 0x9EBD, 0x93CC, 0x9EA2, 0x9EB9, 0x9EBB, 0x92D6, 0x976B, 0x9596, 0x9EB6, 0x91C8,
+    0x9EBC, 0x915E, 0x9EB3, 0x9EC0, 0x9EBF, 0x93ED, 0x9EBE, 0x93E8, 0x9EC2, 0x9EB5, 0x8BC6, 0x9EB8,
+    0x8F7C, 0x9480, 0x9EBA, 0x8BC9, 0x9EB2, 0x9EB4, 0x9EB1, 0x984F, 0x8A79, 0x9EB7, 0x9EC1, 0x8A54,
+    0x8DE5, 0x897C, 0x9ED2, 0x9850, 0x9ED5, 0x9059, 0x9ED4, 0x9ED3, 0x9ED0, 0x9EC4, 0x9EE1, 0x9EC3,
+    0x9ED6, 0x9ECE, 0x9EC9, 0x9EC6, 0x9EC7, 0x9ECF, 0xEAA0, 0x9ECC, 0x8D5C, 0x92C6, 0x9184, 0x9ECA,
+    0x9EC5, 0x9EC8, 0x976C, 0x968A, 0x9ECD, 0x9ED7, 0x9EDF, 0x9ED8, 0x9EE5, 0x9EE3, 0x9EDE, 0x9EDD,
+    0x92CE, 0x9185, 0x9EDB, 0x9ED9, 0x9EE0, 0x9EE6, 0x94F3, 0x9EEC, 0x9EE7, 0x9EEA, 0x9EE4, 0x9294,
+    0x9557, 0x9EDA, 0x9EE2, 0x8FBE, 0x96CD, 0x9EF6, 0x9EE9, 0x8CA0, 0x89A1, 0x8A7E, 0x9ED1, 0x8FBF,
+    0x9EEE, 0x9EF5, 0x8EF7, 0x8A92, 0x924D, 0x9EEB, 0x9EF0, 0x9EF4, 0x8BB4, 0x8B6B, 0x9EF2, 0x8B40,
+    0x93C9, 0x9EF1, 0x9EF3, 0x9EED, 0x9EEF, 0x8A80, 0x9268, 0x9EFA, 0x9EF8, 0x8CE7, 0x9EF7, 0x9F40,
+    0x9E77, 0x9EF9, 0x9EFB, 0x9EFC, 0x9F4B, 0x9F47, 0x9E8D, 0x9F46, 0x9F45, 0x9F42, 0x9EE8, 0x9F44,
+    0x9F43, 0x9F49, 0x9845, 0x9F4C, 0x8BF9, 0x9F48, 0x9F4A, 0x94A5, 0x9F4D, 0x9F51, 0x9F4E, 0x9793,
+    0x9F4F, 0x9EDC, 0x9F52, 0x9F53, 0x8954, 0x9F55, 0x8C87, 0x8E9F, 0x8BD3, 0x89A2, 0x977E, 0x9F57,
+    0x9F56, 0x9F59, 0x8B5C, 0x8BD4, 0x8ABC, 0x9F5C, 0x9F5B, 0x9F5D, 0x89CC, 0x9256, 0x9F5E, 0x8ABD,
+    0x9F60, 0x9F5F, 0x9F61, 0x9F62, 0x9F63, 0x8E7E, 0x90B3, 0x8D9F, 0x9590, 0x95E0, 0x9863, 0x8E95,
+    0x8DCE, 0x97F0, 0x9F64, 0x9F65, 0x8E80, 0x9F66, 0x9F67, 0x9F69, 0x9F68, 0x9677, 0x8F7D, 0x8EEA,
+    0x8E63, 0x9F6A, 0x9F6C, 0x9042, 0x9F6B, 0x9F6D, 0x9F6E, 0x9F6F, 0x9F70, 0x9F71, 0x9F73, 0x9F72,
+    0x9F74, 0x89A3, 0x9269, 0x9F75, 0x8E45, 0x8A6B, 0x9F76, 0x9361, 0x9ACA, 0x8B42, 0x9F77, 0x9F78,
+    0x95EA, 0x9688, 0x93C5, 0x9F79, 0x94E4, 0x94F9, 0x96D1, 0x9F7A, 0x9F7C, 0x9F7B, 0x9F7E, 0x9F7D,
+    0x9F81, 0x8E81, 0x96AF, 0x9F82, 0x9F83, 0x8B43, 0x9F84, 0x9F86, 0x9F85, 0x9085, 0x9558, 0x8969,
+    0x94C3, 0x92F3, 0x8F60, 0x8B81, 0x94C4, 0x8EAC, 0x9F88, 0x8ABE, 0x8998, 0x93F0, 0x9F87, 0x8D5D,
+    0x9272, 0x9F89, 0x9F91, 0x9F8A, 0x91BF, 0x8B82, 0x9F92, 0x8C88, 0x8B44, 0x9F90, 0x9F8E, 0x9F8B,
+    0x9780, 0x92BE, 0x93D7, 0x9F8C, 0x9F94, 0x9F93, 0x8C42, 0x89AB, 0x8DB9, 0x9F8D, 0x9F8F, 0x9676,
+    0x91F2, 0x9697, 0x9F9C, 0x9F9D, 0x89CD, 0x95A6, 0x96FB, 0x9F9F, 0x8EA1, 0x8FC0, 0x9F98, 0x9F9E,
+    0x8988, 0x8BB5, 0x9F95, 0x9F9A, 0x90F2, 0x9491, 0x94E5, 0x9F97, 0x9640, 0x9F99, 0x9FA2, 0x9FA0,
+    0x9F9B, 0x9641, 0x9467, 0x8B83, 0x9344, 0x928D, 0x9FA3, 0x9FA1, 0x91D7, 0x9F96, 0x896A, 0x976D,
+    0x9FAE, 0x9FAD, 0x90F4, 0x9FAA, 0x978C, 0x93B4, 0x9FA4, 0x92C3, 0x896B, 0x8D5E, 0x9FA7, 0x8F46,
+    0x9FAC, 0x9FAB, 0x9FA6, 0x9FA9, 0x8A88, 0x9FA8, 0x9468, 0x97AC, 0x8FF2, 0x90F3, 0x9FB4, 0x9FB2,
+    0x956C, 0x9FAF, 0x9FB1, 0x8959, 0x8D5F, 0x9851, 0x8A5C, 0x9582, 0x9781, 0x8A43, 0x905A, 0x9FB3,
+    0x9FB8, 0x8FC1, 0x974F, 0x9FB5, 0x9FB0, 0x9FB6, 0x97DC, 0x9393, 0x93C0, 0x8A55, 0x8974, 0x9FBC,
+    0x9FBF, 0x97C1, 0x9784, 0x9FC6, 0x9FC0, 0x9FBD, 0x97D2, 0x9FC3, 0x8F69, 0x9FC5, 0x9FCA, 0x9391,
+    0x9FC8, 0x9FC2, 0x9257, 0x9FC9, 0x9FBE, 0x9FC4, 0x9FCB, 0x88FA, 0x9FC1, 0x9FCC, 0x905B, 0x8F7E,
+    0x95A3, 0x8DAC, 0x9FB9, 0x9FC7, 0x9359, 0x90B4, 0x8A89, 0x8DCF, 0x8FC2, 0x9FBB, 0x8F61, 0x8C6B,
+    0x9FBA, 0x9FD0, 0x8F8D, 0x8CB8, 0x9FDF, 0x9FD9, 0x8B94, 0x936E, 0x9FD4, 0x9FDD, 0x88AD, 0x8951,
+    0x89B7, 0x9FD6, 0x91AA, 0x9FCD, 0x9FCF, 0x8D60, 0x9FE0, 0x9FDB, 0x9FD3, 0x9FDA, 0x96A9, 0x9FD8,
+    0x9FDC, 0x8CCE, 0x8FC3, 0x9258, 0x9FD2, 0x974E, 0x9FD5, 0x9FCE, 0x9392, 0x9FD1, 0x9FD7, 0x9870,
+    0x8EBC, 0x969E, 0x9FE1, 0x94AC, 0x9FED, 0x8CB9, 0x8F80, 0x9FE3, 0x97AD, 0x8D61, 0x9FF0, 0x88EC,
+    0x9FEE, 0x9FE2, 0x9FE8, 0x9FEA, 0x976E, 0x9FE5, 0x934D, 0x9FE7, 0x9FEF, 0x9FE9, 0x96C5, 0x9FE4,
+    0x8EA0, 0x9FFC, 0x8A8A, 0x9FE6, 0x9FEB, 0x9FEC, 0x91EA, 0x91D8, 0x9FF4, 0x9FFA, 0x9FF8, 0x9348,
+    0xE042, 0x9FF5, 0x9FF6, 0x9FDE, 0x8B99, 0x9559, 0x8EBD, 0x8D97, 0x9852, 0x9FF2, 0xE041, 0x8989,
+    0x9186, 0x9499, 0x8ABF, 0x97F8, 0x969F, 0x92D0, 0x9FF9, 0x9FFB, 0x9151, 0xE040, 0x9FF7, 0x9FF1,
+    0x8AC1, 0x8C89, 0xE04E, 0xE049, 0x90F6, 0x8A83, 0x8F81, 0xE052, 0xE04B, 0x92AA, 0xE048, 0x92D7,
+    0xE06B, 0xE045, 0xE044, 0xE04D, 0xE047, 0xE046, 0xE04C, 0x909F, 0xE043, 0xE04F, 0xE050, 0x8AC0,
+    0xE055, 0xE054, 0xE056, 0xE059, 0x9362, 0xE053, 0xE057, 0x8C83, 0x91F7, 0xE051, 0x945A, 0xE058,
+    0xE05D, 0xE05B, 0xE05E, 0xE061, 0xE05A, 0x8D8A, 0x9447, 0x9FB7, 0x9794, 0xE05C, 0xE060, 0x91F3,
+    0xE05F, 0xE04A, 0xE889, 0xE064, 0xE068, 0xE066, 0xE062, 0xE063, 0xE067, 0xE065, 0x956D, 0xE06D,
+    0xE06A, 0xE069, 0xE06C, 0x93D2, 0xE06E, 0x9295, 0x91EB, 0x90A3, 0xE06F, 0xE071, 0xE070, 0x9FF3,
+    0xE072, 0x93E5, 0xE073, 0x89CE, 0x9394, 0x8A44, 0x8B84, 0x8EDC, 0x8DD0, 0x9846, 0x9086, 0x898A,
+    0xE075, 0xE074, 0xE078, 0x9259, 0xE07B, 0xE076, 0xE07A, 0xE079, 0x935F, 0x88D7, 0x97F3, 0xE07D,
+    0x8947, 0xE080, 0xE07E, 0xE07C, 0xE077, 0x9642, 0xE082, 0xE081, 0x898B, 0xE084, 0x95B0, 0xE083,
+    0x96B3, 0x8FC5, 0x9152, 0x8FC4, 0x97F9, 0xE08A, 0x90F7, 0xE086, 0xE08B, 0x898C, 0xE089, 0x9481,
+    0xE085, 0xE088, 0x8FC6, 0x94CF, 0xE08C, 0x8ECF, 0x90F8, 0xE08F, 0xE087, 0x8C46, 0xE08D, 0x976F,
+    0xE090, 0xEAA4, 0x8F6E, 0xE091, 0xE092, 0x944D, 0xE094, 0xE095, 0x9452, 0x9395, 0xE097, 0xE099,
+    0x97D3, 0xE096, 0xE098, 0x898D, 0xE093, 0x9A7A, 0xE09A, 0x9187, 0x8E57, 0xE09C, 0xE09B, 0x9043,
+    0x99D7, 0xE09D, 0xE09F, 0xE08E, 0xE09E, 0xE0A0, 0x949A, 0xE0A1, 0xE0A2, 0xE0A3, 0xE0A4, 0x92DC,
+    0xE0A6, 0xE0A5, 0xE0A7, 0xE0A8, 0x8EDD, 0x9583, 0x96EA, 0xE0A9, 0xE0AA, 0x9175, 0x8EA2, 0xE0AB,
+    0xE0AC, 0xE0AD, 0x95D0, 0x94C5, 0xE0AE, 0x9476, 0x92AB, 0xE0AF, 0x89E5, 0x8B8D, 0x96C4, 0x96B4,
+    0x89B2, 0x9853, 0x9671, 0x95A8, 0x90B5, 0xE0B0, 0x93C1, 0x8CA1, 0xE0B1, 0x8DD2, 0xE0B3, 0xE0B2,
+    0xE0B4, 0xE0B5, 0xE0B6, 0x8B5D, 0xE0B7, 0xE0B8, 0x8CA2, 0x94C6, 0xE0BA, 0x8FF3, 0xE0B9, 0x8BB6,
+    0xE0BB, 0xE0BD, 0xE0BC, 0xE0BE, 0x8CCF, 0xE0BF, 0x8BE7, 0x915F, 0x8D9D, 0xE0C1, 0xE0C2, 0xE0C0,
+    0x8EEB, 0x93C6, 0x8BB7, 0xE0C4, 0x924B, 0xE0C3, 0x9854, 0x9482, 0xE0C7, 0xE0C9, 0xE0C6, 0x96D2,
+    0xE0C8, 0xE0CA, 0x97C2, 0xE0CE, 0xE0CD, 0x9296, 0x944C, 0x8CA3, 0xE0CC, 0xE0CB, 0x9750, 0x9751,
+    0xE0CF, 0x898E, 0x8D96, 0x8E82, 0xE0D0, 0xE0D1, 0xE0D3, 0x8F62, 0xE0D5, 0xE0D4, 0xE0D6, 0x8A6C,
+    0xE0D8, 0xE0D7, 0xE0DA, 0xE0D9, 0x8CBA, 0x97A6, 0x8BCA, 0x89A4, 0x8BE8, 0x8ADF, 0x97E6, 0xE0DC,
+    0xE0DE, 0xE0DF, 0x89CF, 0xE0DB, 0x8E58, 0x92BF, 0xE0DD, 0xE0E2, 0x8EEC, 0xE0E0, 0x8C5D, 0x94C7,
+    0xE0E1, 0xE0FC, 0xE0E7, 0x8CBB, 0x8B85, 0xE0E4, 0x979D, 0x97AE, 0x91F4, 0xE0E6, 0xE0E8, 0x97D4,
+    0x8BD5, 0x94FA, 0x9469, 0xE0E9, 0xE0EB, 0xE0EE, 0xE0EA, 0xE0ED, 0x8CE8, 0x896C, 0xE0EF, 0x9090,
+    0xE0EC, 0x97DA, 0xE0F2, 0xEAA2, 0xE0F0, 0xE0F3, 0xE0E5, 0xE0F1, 0x8DBA, 0xE0F4, 0xE0F5, 0x979E,
+    0xE0F6, 0xE0F7, 0xE0E3, 0xE0F8, 0x8AC2, 0x8EA3, 0xE0F9, 0xE0FA, 0xE0FB, 0x895A, 0xE140, 0x955A,
+    0xE141, 0x8AA2, 0xE142, 0xE143, 0xE144, 0xE146, 0xE147, 0xE145, 0x9572, 0xE149, 0xE148, 0xE14B,
+    0xE14A, 0xE14C, 0xE14D, 0xE14F, 0xE14E, 0x8D99, 0xE151, 0xE150, 0x8AC3, 0x9072, 0x935B, 0xE152,
+    0x90B6, 0x8E59, 0x8999, 0xE153, 0x9770, 0x95E1, 0xE154, 0x9363, 0x9752, 0x8D62, 0x905C, 0x926A,
+    0x99B2, 0x92AC, 0x89E6, 0xE155, 0xE156, 0xE15B, 0xE159, 0xE158, 0x9DC0, 0x8A45, 0xE157, 0x88D8,
+    0x94A8, 0x94C8, 0x97AF, 0xE15C, 0xE15A, 0x927B, 0x90A4, 0x94A9, 0x954C, 0xE15E, 0x97AA, 0x8C6C,
+    0xE15F, 0xE15D, 0x94D4, 0xE160, 0xE161, 0x88D9, 0x8FF4, 0xE166, 0xE163, 0x93EB, 0xE162, 0x8B45,
+    0xE169, 0xE164, 0xE165, 0xE168, 0xE167, 0x9544, 0x9161, 0x9160, 0x8B5E, 0xE16A, 0xE16B, 0xE16C,
+    0xE16E, 0xE16D, 0x8975, 0xE176, 0x94E6, 0xE170, 0xE172, 0xE174, 0x905D, 0xE175, 0xE173, 0x8EBE,
+    0xE16F, 0xE171, 0x9561, 0x8FC7, 0xE178, 0xE177, 0xE179, 0x8EA4, 0x8DAD, 0x9397, 0xE17A, 0x92C9,
+    0xE17C, 0x979F, 0xE17B, 0x9189, 0xE182, 0xE184, 0xE185, 0x9273, 0xE183, 0xE180, 0xE17D, 0xE17E,
+    0xE181, 0xE188, 0xE186, 0xE187, 0xE189, 0xE18B, 0xE18C, 0xE18D, 0xE18E, 0xE18A, 0xE190, 0xE18F,
+    0xE191, 0x97C3, 0xE194, 0xE192, 0xE193, 0x8AE0, 0x96FC, 0x95C8, 0xE196, 0xE195, 0xE197, 0xE198,
+    0xE19C, 0xE199, 0xE19A, 0xE19B, 0xE19D, 0xE19E, 0xE19F, 0xE1A0, 0xE1A1, 0x94AD, 0x936F, 0xE1A2,
+    0x9492, 0x9553, 0xE1A3, 0xE1A4, 0x9349, 0x8A46, 0x8D63, 0xE1A5, 0xE1A6, 0xE1A7, 0x8E48, 0xE1A9,
+    0xE1A8, 0xE1AA, 0xE1AB, 0x94E7, 0xE1AC, 0xE1AD, 0xEA89, 0xE1AE, 0xE1AF, 0xE1B0, 0x8E4D, 0xE1B1,
+    0x9475, 0x967E, 0x896D, 0x8976, 0xE1B2, 0xE1B4, 0xE1B3, 0x9390, 0x90B7, 0x9F58, 0xE1B5, 0x96BF,
+    0xE1B6, 0x8AC4, 0x94D5, 0xE1B7, 0xE1B8, 0xE1B9, 0x96DA, 0x96D3, 0x92BC, 0x918A, 0xE1BB, 0x8F82,
+    0x8FC8, 0xE1BE, 0xE1BD, 0xE1BC, 0x94FB, 0x8AC5, 0x8CA7, 0xE1C4, 0xE1C1, 0x905E, 0x96B0, 0xE1C0,
+    0xE1C2, 0xE1C3, 0xE1BF, 0xE1C5, 0xE1C6, 0x92AD, 0x8AE1, 0x9285, 0xE1C7, 0xE1C8, 0xE1CB, 0x9087,
+    0x93C2, 0xE1CC, 0x9672, 0xE1C9, 0xE1CA, 0xE1CF, 0xE1CE, 0xE1CD, 0xE1D1, 0xE1D0, 0xE1D2, 0xE1D4,
+    0xE1D3, 0x95CB, 0x8F75, 0x97C4, 0xE1D5, 0x93B5, 0xE1D6, 0xE1D7, 0xE1DB, 0xE1D9, 0xE1DA, 0xE1D8,
+    0xE1DC, 0xE1DD, 0xE1DE, 0xE1DF, 0x96B5, 0xE1E0, 0x96EE, 0xE1E1, 0x926D, 0x948A, 0x8BE9, 0x925A,
+    0xE1E2, 0x8BB8, 0x90CE, 0xE1E3, 0x8DBB, 0xE1E4, 0xE1E5, 0x8CA4, 0x8DD3, 0xE1E7, 0x9375, 0x8DD4,
+    0x8B6D, 0x9643, 0x946A, 0x9376, 0x8D7B, 0xE1E9, 0x8FC9, 0x97B0, 0x8D64, 0x8CA5, 0x94A1, 0xE1EB,
+    0xE1ED, 0x8CE9, 0xE1EC, 0x92F4, 0xE1EF, 0x8A56, 0xE1EA, 0x94E8, 0x894F, 0x8DEA, 0x9871, 0xE1EE,
+    0xE1F0, 0x95C9, 0x90D7, 0xE1F2, 0xE1F3, 0xE1F1, 0x8A6D, 0xE1F9, 0xE1F8, 0x8EA5, 0xE1FA, 0xE1F5,
+    0xE1FB, 0xE1F6, 0x94D6, 0xE1F4, 0xE1F7, 0xE241, 0xE240, 0x9681, 0xE1FC, 0x88E9, 0xE243, 0xE242,
+    0x8FCA, 0xE244, 0x9162, 0xE246, 0xE245, 0xE247, 0xE1E6, 0xE1E8, 0xE249, 0xE248, 0x8EA6, 0x97E7,
+    0x8ED0, 0xE24A, 0x8C56, 0x8B5F, 0x8B46, 0x8E83, 0x9753, 0xE250, 0xE24F, 0x9163, 0xE24C, 0xE24E,
+    0x8F6A, 0x905F, 0xE24D, 0xE24B, 0x9449, 0x8FCB, 0x955B, 0x8DD5, 0x9398, 0xE251, 0xE252, 0xE268,
+    0x8BD6, 0x985C, 0x9154, 0xE253, 0x89D0, 0x92F5, 0x959F, 0xE254, 0x8B9A, 0xE255, 0xE257, 0xE258,
+    0x9448, 0xE259, 0xE25A, 0xE25B, 0x8BD7, 0x89D1, 0x93C3, 0x8F47, 0x8E84, 0xE25C, 0x8F48, 0x89C8,
+    0x9562, 0xE25D, 0x94E9, 0x9164, 0xE260, 0xE261, 0x9489, 0x9060, 0xE25E, 0x9281, 0xE25F, 0x8FCC,
+    0x88DA, 0x8B48, 0xE262, 0x92F6, 0xE263, 0x90C5, 0x96AB, 0x9542, 0xE264, 0xE265, 0x9274, 0x97C5,
+    0xE267, 0xE266, 0x8EED, 0xE269, 0x88EE, 0xE26C, 0xE26A, 0x89D2, 0x8C6D, 0xE26B, 0x8D65, 0x8D92,
+    0x95E4, 0xE26D, 0x9673, 0xE26F, 0x90CF, 0x896E, 0x89B8, 0x88AA, 0xE26E, 0xE270, 0xE271, 0x8FF5,
+    0xE272, 0x8A6E, 0xE274, 0x8C8A, 0x8B86, 0xE275, 0x8BF3, 0xE276, 0x90FA, 0x93CB, 0x90DE, 0x8DF3,
+    0xE277, 0x9282, 0x918B, 0xE279, 0xE27B, 0xE278, 0xE27A, 0x8C41, 0xE27C, 0x8C45, 0x8B87, 0x9771,
+    0xE27E, 0xE280, 0x894D, 0xE283, 0x8A96, 0xE282, 0xE281, 0xE285, 0xE27D, 0xE286, 0x97A7, 0xE287,
+    0xE288, 0x9AF2, 0xE28A, 0xE289, 0xE28B, 0xE28C, 0x97B3, 0xE28D, 0xE8ED, 0x8FCD, 0xE28E, 0xE28F,
+    0x8F76, 0x93B6, 0xE290, 0x9247, 0xE291, 0x925B, 0xE292, 0x8BA3, 0x995E, 0x927C, 0x8EB1, 0x8AC6,
+    0xE293, 0xE2A0, 0xE296, 0x8B88, 0xE295, 0xE2A2, 0xE294, 0x8FCE, 0xE298, 0xE299, 0x934A, 0xE29A,
+    0x8A7D, 0x9079, 0x9584, 0xE29C, 0x91E6, 0xE297, 0xE29B, 0xE29D, 0x8DF9, 0xE2A4, 0x954D, 0x94A4,
+    0x9399, 0x8BD8, 0xE2A3, 0xE2A1, 0x94B3, 0xE29E, 0x927D, 0x939B, 0x939A, 0x8DF4, 0xE2B6, 0xE2A6,
+    0xE2A8, 0xE2AB, 0xE2AC, 0xE2A9, 0xE2AA, 0xE2A7, 0xE2A5, 0xE29F, 0x95CD, 0x89D3, 0xE2B3, 0xE2B0,
+    0xE2B5, 0xE2B4, 0x9493, 0x96A5, 0x8E5A, 0xE2AE, 0xE2B7, 0xE2B2, 0xE2B1, 0xE2AD, 0xE2AF, 0x8AC7,
+    0x925C, 0x90FB, 0x94A0, 0xE2BC, 0x94A2, 0x90DF, 0xE2B9, 0x94CD, 0xE2BD, 0x95D1, 0x927A, 0xE2B8,
+    0xE2BA, 0xE2BB, 0xE2BE, 0x8EC2, 0x93C4, 0xE2C3, 0xE2C2, 0xE2BF, 0x9855, 0xE2C8, 0xE2CC, 0xE2C9,
+    0xE2C5, 0xE2C6, 0xE2CB, 0xE2C0, 0x99D3, 0xE2C7, 0xE2C1, 0xE2CA, 0xE2D0, 0x8AC8, 0xE2CD, 0xE2CE,
+    0xE2CF, 0xE2D2, 0xE2D1, 0x94F4, 0xE2D3, 0x97FA, 0x95EB, 0xE2D8, 0xE2D5, 0xE2D4, 0x90D0, 0xE2D7,
+    0xE2D9, 0xE2D6, 0xE2DD, 0xE2DA, 0xE2DB, 0xE2C4, 0xE2DC, 0xE2DE, 0xE2DF, 0x95C4, 0xE2E0, 0x96E0,
+    0x8BCC, 0x8C48, 0xE2E1, 0x95B2, 0x9088, 0x96AE, 0xE2E2, 0x97B1, 0x9494, 0x9165, 0x9453, 0x8F6C,
+    0x88BE, 0xE2E7, 0xE2E5, 0xE2E3, 0x8A9F, 0x8FCF, 0xE2E8, 0xE2E6, 0xE2E4, 0xE2EC, 0xE2EB, 0xE2EA,
+    0xE2E9, 0xE2ED, 0xE2EE, 0x90B8, 0xE2EF, 0xE2F1, 0xE2F0, 0x8CD0, 0x9157, 0xE2F3, 0x939C, 0xE2F2,
+    0xE2F4, 0x95B3, 0x918C, 0x8D66, 0xE2F5, 0x97C6, 0xE2F7, 0xE2F8, 0xE2F9, 0xE2FA, 0x8E85, 0xE2FB,
+    0x8C6E, 0x8B8A, 0x8B49, 0xE340, 0x96F1, 0x8D67, 0xE2FC, 0xE343, 0x96E4, 0x945B, 0x9552, 0x8F83,
+    0xE342, 0x8ED1, 0x8D68, 0x8E86, 0x8B89, 0x95B4, 0xE341, 0x9166, 0x9661, 0x8DF5, 0x8E87, 0x92DB,
+    0xE346, 0x97DD, 0x8DD7, 0xE347, 0x9061, 0xE349, 0x8FD0, 0x8DAE, 0xE348, 0x8F49, 0x8CBC, 0x9167,
+    0xE344, 0xE34A, 0xE345, 0x8C6F, 0xE34D, 0xE351, 0x8C8B, 0xE34C, 0xE355, 0x8D69, 0x978D, 0x88BA,
+    0xE352, 0x8B8B, 0xE34F, 0xE350, 0x939D, 0xE34E, 0xE34B, 0x8A47, 0x90E2, 0x8CA6, 0xE357, 0xE354,
+    0xE356, 0xE353, 0x8C70, 0x91B1, 0xE358, 0x918E, 0xE365, 0xE361, 0xE35B, 0xE35F, 0x8EF8, 0x88DB,
+    0xE35A, 0xE362, 0xE366, 0x8D6A, 0x96D4, 0x92D4, 0xE35C, 0xE364, 0xE359, 0x925D, 0xE35E, 0x88BB,
+    0x96C8, 0xE35D, 0x8BD9, 0x94EA, 0x918D, 0x97CE, 0x8F8F, 0xE38E, 0xE367, 0x90FC, 0xE363, 0xE368,
+    0xE36A, 0x92F7, 0xE36D, 0xE369, 0x95D2, 0x8AC9, 0x96C9, 0x88DC, 0xE36C, 0x97FB, 0xE36B, 0x898F,
+    0x93EA, 0xE36E, 0xE375, 0xE36F, 0xE376, 0xE372, 0x949B, 0x8EC8, 0xE374, 0xE371, 0xE377, 0xE370,
+    0x8F63, 0x9644, 0x8F6B, 0xE373, 0xE380, 0xE37B, 0xE37E, 0xE37C, 0xE381, 0xE37A, 0xE360, 0x90D1,
+    0x94C9, 0xE37D, 0xE378, 0x9140, 0x8C71, 0x8F4A, 0x9044, 0x9155, 0xE384, 0xE386, 0xE387, 0xE383,
+    0xE385, 0xE379, 0xE382, 0xE38A, 0xE389, 0x969A, 0x8C4A, 0xE388, 0xE38C, 0xE38B, 0xE38F, 0xE391,
+    0x8E5B, 0xE38D, 0xE392, 0xE393, 0xE394, 0xE39A, 0x935A, 0xE396, 0xE395, 0xE397, 0xE398, 0xE399,
+    0xE39B, 0xE39C, 0x8ACA, 0xE39D, 0xE39E, 0xE39F, 0xE3A0, 0xE3A1, 0xE3A2, 0xE3A3, 0xE3A4, 0xE3A6,
+    0xE3A5, 0xE3A7, 0xE3A8, 0xE3A9, 0xE3AC, 0xE3AA, 0xE3AB, 0x8DDF, 0x8C72, 0x9275, 0x94B1, 0x8F90,
+    0x946C, 0x94EB, 0xE3AD, 0x9CEB, 0xE3AE, 0xE3B0, 0x9785, 0xE3AF, 0xE3B2, 0xE3B1, 0x9772, 0xE3B3,
+    0x94FC, 0xE3B4, 0xE3B7, 0xE3B6, 0xE3B5, 0xE3B8, 0x8C51, 0x9141, 0x8B60, 0xE3BC, 0xE3B9, 0xE3BA,
+    0xE3BD, 0xE3BE, 0xE3BB, 0x8948, 0x89A5, 0xE3C0, 0xE3C1, 0xE3C2, 0x9782, 0x8F4B, 0xE3C4, 0xE3C3,
+    0x9089, 0xE3C5, 0xE3C6, 0xE3C7, 0x8AE3, 0x8ACB, 0xE3C8, 0xE3C9, 0x967C, 0x9783, 0x9773, 0x9856,
+    0x8D6C, 0xE3CC, 0x8ED2, 0xE3CB, 0xE3CD, 0x8EA7, 0x91CF, 0xE3CE, 0x8D6B, 0x96D5, 0xE3CF, 0xE3D0,
+    0xE3D1, 0xE3D2, 0xE3D3, 0x8EA8, 0x96EB, 0xE3D5, 0x925E, 0xE3D4, 0xE3D7, 0xE3D6, 0xE3D8, 0x90B9,
+    0xE3D9, 0xE3DA, 0x95B7, 0xE3DB, 0x918F, 0xE3DC, 0xE3DD, 0x97FC, 0xE3E0, 0xE3DF, 0xE3DE, 0x92AE,
+    0xE3E1, 0x9045, 0xE3E2, 0xE3E3, 0x9857, 0xE3E4, 0xE3E5, 0xE3E7, 0xE3E6, 0x94A3, 0x93F7, 0x985D,
+    0x94A7, 0xE3E9, 0x8FD1, 0x9549, 0xE3EA, 0xE3E8, 0x8ACC, 0x8CD2, 0x8E88, 0x94EC, 0x8CA8, 0x9662,
+    0xE3ED, 0xE3EB, 0x8D6D, 0x8D6E, 0x88E7, 0x8DE6, 0x9478, 0x88DD, 0xE3F2, 0x925F, 0x9477, 0x91D9,
+    0xE3F4, 0xE3F0, 0xE3F3, 0xE3EE, 0xE3F1, 0x9645, 0x8CD3, 0x88FB, 0xE3EF, 0xE3F6, 0xE3F7, 0x93B7,
+    0x8BB9, 0xE445, 0x945C, 0x8E89, 0x8BBA, 0x90C6, 0x9865, 0x96AC, 0xE3F5, 0x90D2, 0x8B72, 0xE3F8,
+    0xE3FA, 0xE3F9, 0xE3FB, 0x9245, 0x945D, 0x92AF, 0xE442, 0xE441, 0xE3FC, 0x9074, 0x9585, 0xE444,
+    0xE443, 0x8D6F, 0x9872, 0xE454, 0xE448, 0xE449, 0x8EEE, 0xE447, 0x8D98, 0xE446, 0xE44A, 0x92B0,
+    0x95A0, 0x9142, 0x91DA, 0xE44E, 0xE44F, 0xE44B, 0xE44C, 0xE44D, 0x8D70, 0xE455, 0xE451, 0x9586,
+    0x968C, 0x9547, 0xE450, 0xE453, 0xE452, 0x9663, 0xE456, 0xE457, 0x9156, 0xE458, 0xE45A, 0xE45E,
+    0xE45B, 0xE459, 0x945E, 0xE45C, 0xE45D, 0x89B0, 0xE464, 0xE45F, 0xE460, 0xE461, 0x919F, 0xE463,
+    0xE462, 0xE465, 0xE466, 0xE467, 0x9062, 0x89E7, 0xE468, 0x97D5, 0x8EA9, 0x8F4C, 0x8E8A, 0x9276,
+    0xE469, 0xE46A, 0x8950, 0xE46B, 0xE46C, 0xE46D, 0xE46E, 0xE46F, 0x8BBB, 0x9DA8, 0xE470, 0x90E3,
+    0xE471, 0x8EC9, 0xE472, 0x98AE, 0xE473, 0x95DC, 0x8ADA, 0x9143, 0x8F77, 0x9591, 0x8F4D, 0xE474,
+    0x8D71, 0xE475, 0x94CA, 0xE484, 0xE477, 0x91C7, 0x9495, 0x8CBD, 0xE476, 0x9144, 0xE478, 0x92F8,
+    0xE47A, 0xE479, 0xE47C, 0xE47B, 0xE47D, 0xE480, 0xE47E, 0x8ACD, 0xE481, 0xE482, 0xE483, 0x8DAF,
+    0x97C7, 0xE485, 0x9046, 0x8990, 0xE486, 0xE487, 0xE488, 0x88F0, 0xE489, 0xE48A, 0x9587, 0x8EC5,
+    0xE48C, 0x8A48, 0x88B0, 0xE48B, 0xE48E, 0x946D, 0x9063, 0x89D4, 0x9646, 0x8C7C, 0x8BDA, 0xE48D,
+    0x89E8, 0x8AA1, 0x8991, 0xE492, 0x97E8, 0x91DB, 0x9563, 0xE49E, 0x89D5, 0xE49C, 0xE49A, 0xE491,
+    0xE48F, 0xE490, 0x8EE1, 0x8BEA, 0x9297, 0x93CF, 0x8970, 0xE494, 0xE493, 0xE499, 0xE495, 0xE498,
+    0x96CE, 0xE497, 0x89D6, 0x8A9D, 0xE49B, 0xE49D, 0x8C73, 0xE4A1, 0xE4AA, 0xE4AB, 0x88A9, 0xE4B2,
+    0x88EF, 0xE4A9, 0xE4A8, 0xE4A3, 0xE4A2, 0xE4A0, 0xE49F, 0x9283, 0x91F9, 0xE4A5, 0xE4A4, 0xE4A7,
+    0x9190, 0x8C74, 0x8960, 0xE4A6, 0x8D72, 0x9191, 0xE4B8, 0xE4B9, 0x89D7, 0x89AC, 0xE4B6, 0xE4AC,
+    0xE4B4, 0xE4BB, 0xE4B5, 0xE4B3, 0xE496, 0xE4B1, 0xE4AD, 0x8ACE, 0xE4AF, 0xE4BA, 0xE4B0, 0xE4BC,
+    0xE4AE, 0x949C, 0x9789, 0xE4B7, 0xE4CD, 0xE4C5, 0x909B, 0x8B65, 0x8BDB, 0xE4C0, 0x89D9, 0x8FD2,
+    0xE4C3, 0x8DD8, 0x9370, 0xE4C8, 0x95EC, 0xE4BF, 0x89D8, 0x8CD4, 0x9548, 0xE4C9, 0xE4BD, 0xE4C6,
+    0xE4D0, 0xE4C1, 0xE4C2, 0x93B8, 0xE4C7, 0xE4C4, 0x9647, 0xE4CA, 0x88DE, 0xE4BE, 0xE4CC, 0xE4CB,
+    0x948B, 0xE4D2, 0xE4DD, 0x8A9E, 0xE4E0, 0xE4CE, 0xE4D3, 0x978E, 0xE4DC, 0x9774, 0x97A8, 0x9298,
+    0x8A8B, 0x9592, 0xE4E2, 0x939F, 0x88AF, 0xE4DB, 0xE4D7, 0x9192, 0xE4D1, 0xE4D9, 0xE4DE, 0x944B,
+    0x88A8, 0xE4D6, 0xE4DF, 0x9598, 0xE4DA, 0xE4D5, 0x8FD3, 0x8F4E, 0x8EAA, 0x96D6, 0x9566, 0xE4E5,
+    0xE4EE, 0xE4D8, 0x8A97, 0x8FF6, 0xE4E3, 0xE4E8, 0x9193, 0xE4E4, 0xE4EB, 0x927E, 0xE4EC, 0x9775,
+    0xE4E1, 0x8A57, 0xE4E7, 0xE4EA, 0x96AA, 0xE4ED, 0xE4E6, 0xE4E9, 0x9648, 0x9840, 0xE4F1, 0xE4F8,
+    0xE4F0, 0x8EC1, 0xE4CF, 0x95CC, 0x96A0, 0xE4F7, 0xE4F6, 0xE4F2, 0xE4F3, 0x8955, 0xE4F5, 0xE4EF,
+    0x92D3, 0xE4F4, 0x88FC, 0x91A0, 0x95C1, 0xE4F9, 0xE540, 0x94D7, 0xE4FC, 0x8FD4, 0x8EC7, 0xE542,
+    0x8BBC, 0xE543, 0x9599, 0xE4FB, 0xE4D4, 0xE4FA, 0x986E, 0x93A0, 0x9593, 0xE54A, 0xE550, 0xE551,
+    0xE544, 0x9496, 0xE54E, 0xE546, 0xE548, 0xE552, 0xE547, 0xE54B, 0x8992, 0x93E3, 0xE54C, 0xE54F,
+    0xE545, 0x9145, 0xE549, 0x8E46, 0x9064, 0x8C4F, 0x96F2, 0x96F7, 0x8F92, 0xE556, 0xE554, 0x986D,
+    0xE553, 0x9795, 0xE555, 0xE557, 0xE558, 0xE55B, 0xE559, 0x93A1, 0xE55A, 0x94CB, 0xE54D, 0x8F93,
+    0xE55C, 0xE561, 0x9194, 0xE560, 0xE541, 0xE562, 0x9168, 0xE55D, 0xE55F, 0xE55E, 0x9F50, 0x9F41,
+    0xE564, 0xE563, 0x9796, 0xE1BA, 0xE565, 0xE566, 0xE567, 0x8CD5, 0x8B73, 0xE569, 0x997C, 0x8B95,
+    0x97B8, 0x8BF1, 0xE56A, 0xE56B, 0x928E, 0xE56C, 0x93F8, 0x88B8, 0x89E1, 0xE571, 0xE572, 0xE56D,
+    0x8E5C, 0xE56E, 0x9461, 0xE56F, 0xE570, 0xE57A, 0xE574, 0xE577, 0xE573, 0xE575, 0xE576, 0x8ED6,
+    0xE578, 0x9260, 0x8C75, 0x8A61, 0xE57B, 0x8A5E, 0xE581, 0xE57C, 0xE580, 0x94B8, 0xE57D, 0xE57E,
+    0x9567, 0x94D8, 0xE582, 0x91FB, 0xE58C, 0xE588, 0x89E9, 0xE586, 0x9649, 0xE587, 0xE584, 0xE585,
+    0xE58A, 0xE58D, 0xE58B, 0xE589, 0xE583, 0x9277, 0xE594, 0x96A8, 0xE592, 0xE593, 0xE58E, 0xE590,
+    0xE591, 0xE58F, 0x90E4, 0x9858, 0xE598, 0xE599, 0xE59F, 0x9049, 0xE59B, 0xE59E, 0xE596, 0xE595,
+    0xE5A0, 0x89DA, 0xE59C, 0xE5A1, 0xE59D, 0xE59A, 0x92B1, 0xE597, 0x9488, 0xE5A5, 0x975A, 0xE5A4,
+    0xE5A3, 0xE5AC, 0xE5A6, 0xE5AE, 0x9786, 0xE5B1, 0xE5A8, 0xE5A9, 0xE5AD, 0xE5B0, 0xE5AF, 0xE5A7,
+    0xE5AA, 0xE5BB, 0xE5B4, 0xE5B2, 0xE5B3, 0xE5B8, 0xE5B9, 0x8A49, 0x8B61, 0xE5B7, 0xE5A2, 0xE5B6,
+    0xE5BA, 0xE5B5, 0xE5BC, 0xE5BE, 0xE5BD, 0xE5C0, 0xE5BF, 0xE579, 0xE5C4, 0xE5C1, 0xE5C2, 0xE5C3,
+    0xE5C5, 0x8C8C, 0xE5C7, 0xE5C6, 0x8F4F, 0x8D73, 0x9FA5, 0xE5C8, 0x8F70, 0x8A58, 0xE5C9, 0x8971,
+    0x8FD5, 0xE5CA, 0x8D74, 0xE5CB, 0x88DF, 0x955C, 0xE5CC, 0x908A, 0xE5D3, 0xE5D0, 0x928F, 0xE5D1,
+    0xE5CE, 0x8BDC, 0xE5CD, 0xE5D4, 0x8C55, 0x91DC, 0xE5DA, 0xE5D6, 0x91B3, 0xE5D5, 0xE5D8, 0xE5CF,
+    0xE5D9, 0xE5DB, 0x94ED, 0xE5D7, 0xE5DC, 0xE5DE, 0x8CD1, 0xE5D2, 0x88BF, 0xE5DD, 0x8DD9, 0x97F4,
+    0xE5DF, 0xE5E0, 0x9195, 0x97A0, 0xE5E1, 0x9754, 0xE5E2, 0xE5E3, 0x95E2, 0xE5E4, 0x8DBE, 0x97A1,
+    0xE5E9, 0xE5EA, 0x8FD6, 0xE5E8, 0x9787, 0xE5E5, 0xE5E7, 0x90BB, 0x909E, 0xE5E6, 0xE5EB, 0x95A1,
+    0xE5ED, 0xE5EC, 0x8A8C, 0x964A, 0xE5EE, 0xE5FA, 0xE5F0, 0xE5F1, 0xE5F2, 0xE5F3, 0xE5F7, 0xE5F8,
+    0xE5F6, 0xE5F4, 0xE5EF, 0xE5F5, 0xE5F9, 0xE8B5, 0x89A6, 0xE5FC, 0x8BDD, 0xE5FB, 0xE641, 0xE640,
+    0xE643, 0xE642, 0xE644, 0x8F50, 0xE645, 0xE646, 0xE647, 0x90BC, 0x9776, 0xE648, 0x95A2, 0x9465,
+    0xE649, 0xE64A, 0x8CA9, 0x8B4B, 0xE64B, 0x8E8B, 0x9460, 0xE64C, 0x8A6F, 0xE64D, 0xE64F, 0x9797,
+    0xE64E, 0x9065, 0xE650, 0xE651, 0xE652, 0x8ACF, 0xE653, 0xE654, 0xE655, 0xE656, 0x8A70, 0xE657,
+    0xE658, 0xE659, 0x89F0, 0x9047, 0xE65A, 0xE65B, 0xE65C, 0x8CBE, 0x92F9, 0xE65D, 0x8C76, 0x9075,
+    0xE660, 0x93A2, 0xE65F, 0x8C50, 0xE65E, 0x91F5, 0x8B4C, 0xE661, 0xE662, 0x8FD7, 0x8C8D, 0xE663,
+    0x964B, 0x90DD, 0x8B96, 0x96F3, 0x9169, 0xE664, 0x9066, 0x9290, 0x8FD8, 0xE665, 0xE668, 0xE669,
+    0x8DBC, 0x91C0, 0xE667, 0x8FD9, 0x955D, 0xE666, 0x8E8C, 0x8972, 0xE66D, 0x8C77, 0x8E8E, 0x8E8D,
+    0x986C, 0xE66C, 0xE66B, 0x9146, 0x8B6C, 0x9862, 0x8A59, 0x8FDA, 0xE66A, 0xE66F, 0xE670, 0xE66E,
+    0x8CD6, 0x975F, 0x8E8F, 0x9446, 0xE673, 0x90BE, 0x9261, 0x9755, 0xE676, 0x8CEA, 0x90BD, 0xE672,
+    0xE677, 0x8CEB, 0xE674, 0xE675, 0xE671, 0x90E0, 0x93C7, 0x924E, 0x89DB, 0x94EE, 0x8B62, 0x92B2,
+    0xE67A, 0xE678, 0x926B, 0x90BF, 0x8AD0, 0xE679, 0x907A, 0x97C8, 0x985F, 0xE67B, 0xE687, 0x92B3,
+    0xE686, 0xE683, 0xE68B, 0xE684, 0xE680, 0x92FA, 0xE67E, 0xE67C, 0x9740, 0x8E90, 0xE681, 0xE67D,
+    0xE685, 0x8F94, 0x8CBF, 0x91F8, 0x9664, 0x8979, 0x88E0, 0x93A3, 0xE689, 0xE688, 0x93E4, 0xE68D,
+    0xE682, 0xE68C, 0xE68E, 0x8CAA, 0xE68A, 0x8D75, 0x8ED3, 0xE68F, 0x9777, 0xE692, 0xE695, 0xE693,
+    0x9554, 0xE690, 0x8BDE, 0xE694, 0xE696, 0xE69A, 0xE697, 0xE699, 0xE698, 0xE69B, 0x8EAF, 0xE69D,
+    0xE69C, 0x9588, 0xE69F, 0x8C78, 0xE69E, 0xE6A0, 0xE6A1, 0x8B63, 0xE3BF, 0x8FF7, 0xE6A2, 0x8CEC,
+    0xE6A3, 0xE6A4, 0x8E5D, 0x9DCC, 0xE6A5, 0xE6A6, 0x8F51, 0xE6A7, 0xE6A8, 0xE6A9, 0xE6AA, 0xE6AB,
+    0x924A, 0xE6AC, 0xE6AE, 0xE6AD, 0x93A4, 0xE6AF, 0x964C, 0xE6B0, 0xE6B1, 0xE6B2, 0xE6B3, 0x93D8,
+    0x8FDB, 0xE6B4, 0x8D8B, 0x98AC, 0xE6B5, 0xE6B6, 0x955E, 0xE6B7, 0xE6BF, 0xE6B8, 0xE6BA, 0xE6B9,
+    0xE6BB, 0x9665, 0xE6BC, 0xE6BD, 0xE6BE, 0xE6C0, 0x8A4C, 0x92E5, 0x9589, 0x8DE0, 0x8D76, 0x956E,
+    0x89DD, 0x94CC, 0xE6C3, 0x8AD1, 0x90D3, 0xE6C2, 0xE6C7, 0x9299, 0x96E1, 0xE6C5, 0xE6C6, 0x8B4D,
+    0xE6C8, 0x9483, 0x91DD, 0x94EF, 0x935C, 0xE6C4, 0x9666, 0x89EA, 0xE6CA, 0x9847, 0x92C0, 0x9864,
+    0x8E91, 0xE6C9, 0x91AF, 0xE6DA, 0x9147, 0x93F6, 0x956F, 0xE6CD, 0x8E5E, 0x8E92, 0x8FDC, 0x9485,
+    0x8CAB, 0xE6CC, 0xE6CB, 0x958A, 0x8EBF, 0x9371, 0xE6CF, 0xE6D0, 0x8D77, 0xE6CE, 0xE6D1, 0xE6D2,
+    0xE6D4, 0x91A1, 0xE6D3, 0x8AE4, 0xE6D6, 0xE6D5, 0xE6D7, 0xE6D9, 0xE6DB, 0xE6DC, 0x90D4, 0x8ECD,
+    0xE6DD, 0x8A71, 0xE6DE, 0x9196, 0xE6DF, 0xE6E0, 0x958B, 0x8B4E, 0xE6E1, 0x92B4, 0x897A, 0xE6E2,
+    0x8EEF, 0x9096, 0x91AB, 0xE6E5, 0xE6E4, 0xE6E3, 0xE6EB, 0xE6E9, 0xE6E6, 0xE6E8, 0xE6E7, 0xE6EA,
+    0x8B97, 0xE6EE, 0x90D5, 0xE6EF, 0x8CD7, 0xE6EC, 0xE6ED, 0x9848, 0x92B5, 0x9148, 0xE6F0, 0xE6F3,
+    0xE6F1, 0xE6F2, 0x9778, 0x93A5, 0xE6F6, 0xE6F4, 0xE6F5, 0xE6F7, 0xE748, 0xE6FA, 0xE6FB, 0xE6F9,
+    0xE6F8, 0x92FB, 0xE740, 0xE744, 0xE741, 0xE6FC, 0xE742, 0xE743, 0xE74A, 0xE745, 0x90D6, 0xE747,
+    0xE749, 0xE746, 0xE74C, 0x8F52, 0xE74B, 0xE74D, 0xE74E, 0xE751, 0xE750, 0xE74F, 0xE753, 0xE752,
+    0x96F4, 0xE755, 0xE754, 0xE756, 0xE757, 0xE759, 0xE758, 0x9067, 0xE75A, 0x8BEB, 0xE75B, 0xE75D,
+    0xE75E, 0xE75F, 0xE75C, 0xE760, 0x8ED4, 0xE761, 0x8B4F, 0x8C52, 0x8CAC, 0xE762, 0x93EE, 0x935D,
+    0xE763, 0xE766, 0x8EB2, 0xE765, 0xE764, 0x8C79, 0xE767, 0x8A72, 0xE769, 0x8DDA, 0xE768, 0xE771,
+    0xE76B, 0xE76D, 0x95E3, 0xE76A, 0xE76C, 0xE770, 0xE76E, 0x8B50, 0xE76F, 0xE772, 0x9479, 0x97D6,
+    0x8F53, 0xE773, 0x9741, 0xE775, 0xE774, 0xE778, 0x9760, 0xE777, 0x8A8D, 0xE776, 0xE77B, 0xE77A,
+    0xE779, 0x9351, 0xE77C, 0xE77D, 0xE77E, 0x8D8C, 0x8C44, 0xE780, 0xE781, 0xE782, 0x9068, 0xE783,
+    0x8EAB, 0xE784, 0xE785, 0x999F, 0x999E, 0xE786, 0xE390, 0xE787, 0x9243, 0x904A, 0x945F, 0xE788,
+    0x95D3, 0x92D2, 0x8D9E, 0x9248, 0x8949, 0x9698, 0x9076, 0x8C7D, 0x8BDF, 0x95D4, 0xE789, 0xE78B,
+    0xE78A, 0x89DE, 0x93F4, 0xE78C, 0x9497, 0x9352, 0xE78D, 0x8F71, 0xE78F, 0x96C0, 0xE79E, 0xE791,
+    0xE792, 0x92C7, 0x91DE, 0x9197, 0x93A6, 0xE790, 0x8B74, 0xE799, 0xE796, 0xE7A3, 0x93A7, 0x9280,
+    0xE793, 0x92FC, 0x9372, 0xE794, 0xE798, 0x9080, 0x9487, 0x92CA, 0x90C0, 0xE797, 0x91AC, 0x91A2,
+    0xE795, 0x88A7, 0x9841, 0xE79A, 0x91DF, 0x8F54, 0x9069, 0xE79C, 0xE79B, 0x88ED, 0xE79D, 0x954E,
+    0xE7A5, 0x93D9, 0x908B, 0x9278, 0x8BF6, 0xE7A4, 0x9756, 0x895E, 0x95D5, 0x89DF, 0xE79F, 0xE7A0,
+    0xE7A1, 0xE7A2, 0x93B9, 0x9242, 0x88E1, 0xE7A6, 0xE7A7, 0xEAA1, 0x91BB, 0xE7A8, 0x8993, 0x916B,
+    0x8CAD, 0x9779, 0xE7A9, 0x934B, 0x9198, 0x8ED5, 0xE7AA, 0xE7AD, 0x8F85, 0xE7AB, 0x914A, 0x9149,
+    0x88E2, 0x97C9, 0xE7AF, 0x94F0, 0xE7B1, 0xE7B0, 0xE7AE, 0xE284, 0x8AD2, 0xE78E, 0xE7B3, 0xE7B2,
+    0xE7B4, 0x9757, 0x93DF, 0x964D, 0xE7B5, 0x8ED7, 0xE7B6, 0xE7B7, 0xE7B8, 0x9340, 0x88E8, 0x8D78,
+    0x9859, 0xE7BC, 0x8C53, 0xE7B9, 0xE7BA, 0x9594, 0x8A73, 0x9758, 0x8BBD, 0x9373, 0xE7BD, 0xE7BE,
+    0xE7BF, 0x9341, 0xE7C1, 0xE7C0, 0x93D1, 0xE7C2, 0x8F55, 0x8EDE, 0x947A, 0x9291, 0x8EF0, 0x908C,
+    0xE7C3, 0xE7C4, 0x907C, 0xE7C5, 0xE7C6, 0xE7C7, 0x978F, 0x8F56, 0xE7C9, 0xE7C8, 0x8D79, 0x8D93,
+    0x8E5F, 0xE7CC, 0x8F86, 0xE7CB, 0xE7CA, 0x91E7, 0x8CED, 0x90C1, 0x94AE, 0x8F58, 0xE7CD, 0x8FDD,
+    0xE7D0, 0xE7CE, 0xE7CF, 0xE7D2, 0xE7D1, 0x8FF8, 0xE7D3, 0xE7D4, 0xE7D5, 0x94CE, 0x8DD1, 0x8EDF,
+    0xE7D6, 0xE7D7, 0x97A2, 0x8F64, 0x96EC, 0x97CA, 0xE7D8, 0x8BE0, 0xE7D9, 0x9342, 0xE7DC, 0x8A98,
+    0x906A, 0xE7DA, 0xE7DB, 0x92DE, 0x9674, 0x8BFA, 0xE7DE, 0xE7DF, 0xE7DD, 0xE7E1, 0x93DD, 0x8A62,
+    0xE7E5, 0xE7E2, 0xE7E4, 0xE7E0, 0xE86E, 0xE7E3, 0x97E9, 0x8CD8, 0xE7ED, 0x9353, 0xE7E8, 0xE7EB,
+    0xE7E9, 0xE7EE, 0xE7EF, 0xE7E7, 0xE7F4, 0x8994, 0xE7E6, 0x94AB, 0xE7EA, 0x8FDE, 0x8D7A, 0x9667,
+    0x8BE2, 0x8F65, 0x93BA, 0x914C, 0xE7F2, 0xE7EC, 0xE7F1, 0x96C1, 0x92B6, 0xE7F3, 0xE7F0, 0x914B,
+    0xE7F7, 0xE7F6, 0xE7F5, 0x964E, 0x8F9B, 0xE7F8, 0x95DD, 0x8973, 0x9565, 0x9292, 0x8B98, 0xE7FA,
+    0x8D7C, 0x8E4B, 0xE7F9, 0x908D, 0x908E, 0xE840, 0xE842, 0x8FF9, 0xE841, 0xE843, 0x8BD1, 0x9564,
+    0x8EE0, 0x9842, 0xE7FC, 0x8DF6, 0x985E, 0xE845, 0xE844, 0xE846, 0xE7FB, 0x93E7, 0x9374, 0x92D5,
+    0xE84B, 0x9262, 0xE847, 0xE848, 0x8C4C, 0xE84A, 0x8CAE, 0xE849, 0x8FDF, 0x8A99, 0xE84F, 0x8DBD,
+    0x9199, 0x92C8, 0x8A5A, 0xE84D, 0xE84E, 0x92C1, 0xE84C, 0xE850, 0xE856, 0xE859, 0xE858, 0x934C,
+    0xE851, 0xE852, 0xE855, 0xE857, 0x8BBE, 0xE85A, 0xE854, 0xE853, 0xE85E, 0xE85F, 0xE860, 0xE85D,
+    0xE85C, 0x8FE0, 0x93A8, 0xE85B, 0xE864, 0xE862, 0xE863, 0xE861, 0x91F6, 0xE865, 0xE866, 0xE868,
+    0x8AD3, 0xE867, 0x96F8, 0xE873, 0xE869, 0xE86C, 0xE86A, 0xE86B, 0xE86D, 0xE86F, 0xE870, 0xE871,
+    0xE874, 0xE872, 0xE875, 0xE877, 0xE876, 0x92B7, 0x96E5, 0xE878, 0x914D, 0xE879, 0x95C2, 0xE87A,
+    0x8A4A, 0x895B, 0x8AD5, 0x8AD4, 0xE87B, 0xE87C, 0xE87D, 0xE87E, 0xE880, 0x8AD6, 0x8A74, 0x8D7D,
+    0x94B4, 0xE882, 0xE881, 0xE883, 0x897B, 0xE886, 0xE885, 0xE884, 0xE887, 0xE88A, 0x88C5, 0xE888,
+    0xE88C, 0xE88B, 0xE88E, 0xE88D, 0xE88F, 0x93AC, 0xE890, 0xE891, 0xE893, 0xE892, 0x958C, 0xE894,
+    0xE895, 0x8DE3, 0xE896, 0xE897, 0x9668, 0x916A, 0x88A2, 0x91C9, 0xE898, 0x958D, 0xE89B, 0xE899,
+    0x8D7E, 0xE89A, 0x8CC0, 0x95C3, 0xE89D, 0xE89F, 0xE89E, 0xE8A0, 0x8940, 0x9077, 0x8F9C, 0x8AD7,
+    0xE8A1, 0x9486, 0xE8A3, 0x8941, 0xE8A2, 0x92C2, 0x97CB, 0x93A9, 0xE89C, 0x97A4, 0x8CAF, 0x977A,
+    0x8BF7, 0x97B2, 0x8C47, 0x91E0, 0xE440, 0xE8A4, 0x8A4B, 0x908F, 0x8A75, 0xE8A6, 0xE8A7, 0xE8A5,
+    0x8C84, 0x8DDB, 0x8FE1, 0x8942, 0x97D7, 0xE8A9, 0xE7AC, 0xE8A8, 0xE8AC, 0xE8AA, 0xE8AB, 0xE8AD,
+    0xE8AE, 0x97EA, 0xE8AF, 0xE8B0, 0x90C7, 0x94B9, 0x909D, 0x8AE5, 0x9759, 0x89EB, 0x8F57, 0x8CD9,
+    0xE8B3, 0xE8B2, 0x8E93, 0xE8B4, 0xE8B1, 0x8E47, 0xE8B8, 0xE5AB, 0x99D4, 0x9097, 0xE8B6, 0x97A3,
+    0x93EF, 0x894A, 0x90E1, 0x8EB4, 0x95B5, 0x895F, 0x97EB, 0x978B, 0xE8B9, 0x9364, 0x8EF9, 0xE8BA,
+    0xE8BB, 0x906B, 0xE8BC, 0x97EC, 0xE8B7, 0xE8BE, 0xE8C0, 0xE8BF, 0xE8BD, 0xE8C1, 0xE8C2, 0x919A,
+    0x89E0, 0xE8C3, 0x96B6, 0xE8C4, 0xE8C5, 0x9849, 0x9E50, 0xE8C6, 0xE8C7, 0xE8C8, 0xE8CC, 0xE8C9,
+    0xE8CA, 0xE8CB, 0xE8CD, 0x90C2, 0x96F5, 0x90C3, 0xE8CE, 0x94F1, 0xE8CF, 0xEA72, 0x96CA, 0xE8D0,
+    0xE8D1, 0xE8D2, 0x8A76, 0xE8D4, 0x9078, 0xE8D5, 0x8C43, 0xE8D6, 0xE8DA, 0xE8D8, 0xE8D9, 0x8A93,
+    0xE8D7, 0xE8DB, 0xE8DC, 0x88C6, 0xE8DD, 0xE8DE, 0x8FE2, 0xE8DF, 0x8B66, 0xE8E2, 0xE8E1, 0xE8E0,
+    0xE691, 0x95DA, 0xE8E3, 0xE8E4, 0xE8E5, 0xE8E6, 0xE8E7, 0xE8E8, 0x8AD8, 0xE8E9, 0xE8EA, 0x9442,
+    0xE8EC, 0x89B9, 0xE8EF, 0xE8EE, 0x8943, 0x8BBF, 0x95C5, 0x92B8, 0x8DA0, 0x8D80, 0x8F87, 0x907B,
+    0xE8F1, 0xE8F0, 0x9761, 0x8AE6, 0x94D0, 0x93DA, 0x909C, 0x97CC, 0x8C7A, 0xE8F4, 0xE8F3, 0x966A,
+    0x93AA, 0x896F, 0xE8F5, 0xE8F2, 0x9570, 0x978A, 0xE8F6, 0xE8F7, 0xE8F9, 0x91E8, 0x8A7A, 0x8A7B,
+    0xE8F8, 0x8AE7, 0x8CB0, 0x8AE8, 0x935E, 0x97DE, 0x8CDA, 0xE8FA, 0xE8FB, 0xE8FC, 0xE940, 0xE942,
+    0xE941, 0x9597, 0xE943, 0xE944, 0xE945, 0xE946, 0xE948, 0xE947, 0xE949, 0x94F2, 0xE3CA, 0x9048,
+    0x8B51, 0xE94A, 0xE94B, 0x99AA, 0x9F5A, 0x94D1, 0x88F9, 0x88B9, 0x8E94, 0x964F, 0x8FFC, 0xE94C,
+    0x96DD, 0xE94D, 0x977B, 0x8961, 0x8E60, 0xE94E, 0x89EC, 0xE94F, 0xE950, 0xE952, 0xE953, 0xE955,
+    0xE951, 0xE954, 0x8AD9, 0xE956, 0xE957, 0xE958, 0xE959, 0xE95A, 0xE95C, 0xE95B, 0xE95E, 0xE961,
+    0xE95D, 0xE95F, 0xE960, 0xE962, 0x8BC0, 0x8EF1, 0xE963, 0xE964, 0x8D81, 0xE965, 0x8A5D, 0x946E,
+    0xE966, 0xE967, 0x9279, 0x93E9, 0xE968, 0x949D, 0x91CA, 0x8977, 0x8BEC, 0x8BED, 0x9293, 0xE96D,
+    0x8BEE, 0x89ED, 0xE96C, 0xE96A, 0xE96B, 0xE969, 0xE977, 0xE96E, 0xE96F, 0xE970, 0xE971, 0xE973,
+    0xE972, 0x8F78, 0xE974, 0xE976, 0x8B52, 0xE975, 0x919B, 0x8CB1, 0xE978, 0x91CB, 0xE979, 0x93AB,
+    0xE97A, 0xE980, 0xE97D, 0xE97C, 0xE97E, 0xE97B, 0xE982, 0xE981, 0xE984, 0x8BC1, 0xE983, 0xE985,
+    0xE986, 0xE988, 0xE987, 0xE989, 0xE98B, 0xE98A, 0x8D9C, 0xE98C, 0xE98D, 0x8A5B, 0xE98E, 0xE98F,
+    0x9091, 0xE990, 0xE991, 0xE992, 0xE993, 0x8D82, 0xE994, 0xE995, 0xE996, 0xE997, 0xE998, 0x94AF,
+    0xE99A, 0x9545, 0xE99B, 0xE999, 0xE99D, 0xE99C, 0xE99E, 0xE99F, 0xE9A0, 0xE9A1, 0xE9A2, 0xE9A3,
+    0xE9A4, 0xE9A5, 0xE9A6, 0xE9A7, 0xE9A8, 0xE9A9, 0xE9AA, 0xE9AB, 0xE9AC, 0x9F54, 0xE9AD, 0xE2F6,
+    0x8B53, 0x8A40, 0x8DB0, 0xE9AF, 0xE9AE, 0x96A3, 0xE9B1, 0xE9B2, 0xE9B0, 0xE9B3, 0x9682, 0xE9B4,
+    0x8B9B, 0x9844, 0xE9B5, 0xE9B7, 0x88BC, 0xE9B8, 0x95A9, 0xE9B6, 0xE9B9, 0xE9BA, 0xE9BB, 0xE9BC,
+    0xE9BD, 0x968E, 0x8E4C, 0x8DF8, 0x914E, 0xE9BE, 0xE9C1, 0xE9BF, 0xE9C2, 0x8CEF, 0xE9C0, 0xE9C3,
+    0xE9C4, 0xE9C5, 0xE9C9, 0x8E49, 0x91E2, 0xE9CA, 0xE9C7, 0xE9C6, 0xE9C8, 0x8C7E, 0xE9CE, 0xE9CD,
+    0xE9CC, 0x88B1, 0xE9D8, 0xE9D4, 0xE9D5, 0xE9D1, 0xE9D7, 0xE9D3, 0x8A82, 0x986B, 0xE9D6, 0xE9D2,
+    0xE9D0, 0xE9CF, 0xE9DA, 0xE9DD, 0xE9DC, 0xE9DB, 0x9568, 0xE9D9, 0x88F1, 0xE9DE, 0xE9E0, 0x8A8F,
+    0xE9CB, 0x8956, 0xE9E2, 0xE9E1, 0xE9DF, 0x924C, 0x9690, 0x97D8, 0xE9E3, 0xE9E4, 0xE9E5, 0xE9E6,
+    0xE9E7, 0x92B9, 0xE9E8, 0x94B5, 0xE9ED, 0xE9E9, 0xE9EA, 0x9650, 0x96C2, 0x93CE, 0xE9EE, 0xE9EF,
+    0x93BC, 0xE9EC, 0xE9EB, 0x89A8, 0xE9F7, 0xE9F6, 0x8995, 0xE9F4, 0xE9F3, 0xE9F1, 0x8A9B, 0xE9F0,
+    0x8EB0, 0x89A7, 0x8D83, 0xE9FA, 0xE9F9, 0xE9F8, 0xE9F5, 0xE9FB, 0xE9FC, 0xEA44, 0xEA43, 0xEA45,
+    0x894C, 0xEA40, 0xEA41, 0x8D94, 0x96B7, 0xEA42, 0x9651, 0xEA4A, 0xEA46, 0xEA4B, 0xEA48, 0xEA47,
+    0x8C7B, 0xEA4C, 0xEA4D, 0xEA4E, 0xEA49, 0xE9F2, 0xEA4F, 0x92DF, 0xEA53, 0xEA54, 0xEA52, 0xEA51,
+    0xEA57, 0xEA50, 0xEA55, 0xEA56, 0xEA59, 0xEA58, 0xEA5B, 0xEA5C, 0xEA5D, 0x9868, 0xEA5A, 0x91E9,
+    0x8DEB, 0xEA5E, 0xEA5F, 0xEA60, 0xEA61, 0xEA62, 0x8CB2, 0xEA63, 0xEA64, 0x8EAD, 0xEA65, 0xEA66,
+    0xEA67, 0xEA68, 0xEA6B, 0xEA69, 0x985B, 0xEA6A, 0x97ED, 0xEA6C, 0x97D9, 0xEA6D, 0x949E, 0xEA6E,
+    0xEA70, 0xEA71, 0xEA6F, 0x8D8D, 0x96CB, 0x9683, 0x9BF5, 0x9F80, 0x969B, 0x89A9, 0xEA73, 0x8B6F,
+    0xEA74, 0xEA75, 0xEA76, 0x8D95, 0xEA77, 0xE0D2, 0x96D9, 0x91E1, 0xEA78, 0xEA7A, 0xEA79, 0xEA7B,
+    0xEA7C, 0xEA7D, 0xEA7E, 0xEA80, 0xEA81, 0xEA82, 0xEA83, 0xEA84, 0xEA85, 0xEA86, 0xEA87, 0xEA88,
+    0x9343, 0x8CDB, 0xEA8A, 0x916C, 0xEA8B, 0xEA8C, 0x9540, 0xEA8D, 0xEA8E, 0xE256, 0xE6D8, 0xE8EB,
+    0xEA8F, 0xEA90, 0xEA92, 0xEA93, 0xEA94, 0x97EE, 0xEA91, 0xEA95, 0xEA96, 0xEA98, 0xEA97, 0xEA9A,
+    0xEA9B, 0xEA99, 0x97B4, 0xEA9C, 0xEA9D, 0xE273, 0xEA9E, 0x8149, 0x8194, 0x8190, 0x8193, 0x8195,
+    0x8169, 0x816A, 0x8196, 0x817B, 0x8143, 0x8144, 0x815E, 0x824F, 0x8250, 0x8251, 0x8252, 0x8253,
+    0x8254, 0x8255, 0x8256, 0x8257, 0x8258, 0x8146, 0x8147, 0x8183, 0x8181, 0x8184, 0x8148, 0x8197,
+    0x8260, 0x8261, 0x8262, 0x8263, 0x8264, 0x8265, 0x8266, 0x8267, 0x8268, 0x8269, 0x826A, 0x826B,
+    0x826C, 0x826D, 0x826E, 0x826F, 0x8270, 0x8271, 0x8272, 0x8273, 0x8274, 0x8275, 0x8276, 0x8277,
+    0x8278, 0x8279, 0x816D, 0x815F, 0x816E, 0x814F, 0x8151, 0x814D, 0x8281, 0x8282, 0x8283, 0x8284,
+    0x8285, 0x8286, 0x8287, 0x8288, 0x8289, 0x828A, 0x828B, 0x828C, 0x828D, 0x828E, 0x828F, 0x8290,
+    0x8291, 0x8292, 0x8293, 0x8294, 0x8295, 0x8296, 0x8297, 0x8298, 0x8299, 0x829A, 0x816F, 0x8162,
+    0x8170, 0x8150, 0x818F,
+];
+
+/// The indices into [`CHARS`] & [`SHIFT_JIS`], in ascending order of
+/// Shift JIS code
+pub(super) static BY_SHIFT_JIS: [u16; 6879] = [
+    232, 233, 234, 6796, 6797, 427, 6809, 6810, 6814, 6787, 337, 338, 7, 6847, 3, 6845, 6877, 6846,
+    429, 430, 339, 340, 235, 533, 236, 237, 238, 428, 126, 125, 6798, 6843, 253, 127, 6875, 135,
+    134, 128, 129, 130, 131, 6792, 6793, 251, 252, 6842, 6844, 6874, 6876, 239, 240, 241, 242, 243,
+    244, 245, 246, 247, 248, 6795, 154, 6, 9, 10, 6812, 169, 6811, 6813, 171, 172, 157, 165, 228,
+    227, 5, 137, 138, 140, 6878, 6789, 0, 1, 6790, 6788, 6791, 6794, 6815, 2, 226, 225, 221, 223,
+    222, 220, 219, 214, 213, 216, 215, 218, 217, 139, 249, 144, 142, 143, 145, 250, 152, 153, 177,
+    178, 175, 176, 162, 161, 159, 160, 4, 146, 147, 148, 150, 158, 179, 180, 149, 151, 170, 168,
+    173, 174, 155, 167, 156, 166, 163, 164, 141, 136, 231, 230, 229, 132, 133, 8, 224, 6799, 6800,
+    6801, 6802, 6803, 6804, 6805, 6806, 6807, 6808, 6816, 6817, 6818, 6819, 6820, 6821, 6822, 6823,
+    6824, 6825, 6826, 6827, 6828, 6829, 6830, 6831, 6832, 6833, 6834, 6835, 6836, 6837, 6838, 6839,
+    6840, 6841, 6848, 6849, 6850, 6851, 6852, 6853, 6854, 6855, 6856, 6857, 6858, 6859, 6860, 6861,
+    6862, 6863, 6864, 6865, 6866, 6867, 6868, 6869, 6870, 6871, 6872, 6873, 254, 255, 256, 257,
+    258, 259, 260, 261, 262, 263, 264, 265, 266, 267, 268, 269, 270, 271, 272, 273, 274, 275, 276,
+    277, 278, 279, 280, 281, 282, 283, 284, 285, 286, 287, 288, 289, 290, 291, 292, 293, 294, 295,
+    296, 297, 298, 299, 300, 301, 302, 303, 304, 305, 306, 307, 308, 309, 310, 311, 312, 313, 314,
+    315, 316, 317, 318, 319, 320, 321, 322, 323, 324, 325, 326, 327, 328, 329, 330, 331, 332, 333,
+    334, 335, 336, 341, 342, 343, 344, 345, 346, 347, 348, 349, 350, 351, 352, 353, 354, 355, 356,
+    357, 358, 359, 360, 361, 362, 363, 364, 365, 366, 367, 368, 369, 370, 371, 372, 373, 374, 375,
+    376, 377, 378, 379, 380, 381, 382, 383, 384, 385, 386, 387, 388, 389, 390, 391, 392, 393, 394,
+    395, 396, 397, 398, 399, 400, 401, 402, 403, 404, 405, 406, 407, 408, 409, 410, 411, 412, 413,
+    414, 415, 416, 417, 418, 419, 420, 421, 422, 423, 424, 425, 426, 11, 12, 13, 14, 15, 16, 17,
+    18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41,
+    42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 60, 61, 62, 63, 64, 65, 59,
+    66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89,
+    90, 91, 92, 93, 94, 95, 96, 97, 124, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109,
+    110, 111, 112, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 123, 181, 183, 185, 187, 191,
+    189, 193, 201, 197, 205, 209, 182, 184, 186, 188, 192, 190, 196, 204, 200, 208, 212, 195, 202,
+    199, 206, 210, 194, 203, 198, 207, 211, 498, 1144, 1505, 6174, 1119, 2130, 2336, 1501, 5833,
+    4980, 4882, 4135, 2087, 2400, 3286, 2563, 4972, 4838, 6589, 2817, 1281, 2529, 2261, 1601, 1489,
+    5119, 6403, 4415, 4451, 6556, 2226, 4332, 5276, 1591, 1872, 2330, 2622, 2794, 6154, 6315, 2693,
+    538, 548, 568, 601, 666, 1260, 1438, 1492, 1504, 1660, 2104, 2127, 2165, 2575, 2863, 3465,
+    3755, 3773, 4104, 4439, 4471, 4675, 4952, 5248, 5478, 5860, 5880, 959, 494, 505, 1324, 4672,
+    5902, 4029, 431, 1407, 3323, 5841, 4120, 4884, 4831, 6608, 736, 985, 1117, 1127, 1256, 1502,
+    1922, 6402, 3259, 4687, 5030, 6188, 6195, 6219, 6340, 1053, 1042, 1589, 3468, 4587, 5788, 6253,
+    984, 6660, 4166, 442, 4004, 4778, 3287, 1208, 1138, 3028, 5025, 6613, 1496, 1004, 3219, 3705,
+    6133, 1217, 491, 5851, 6257, 4898, 6411, 1026, 1189, 1556, 1961, 2579, 2648, 2773, 3119, 3190,
+    3200, 3681, 3890, 4133, 6361, 4866, 5243, 5407, 6031, 3238, 3794, 3891, 6451, 2084, 5477, 5638,
+    6148, 2917, 1005, 771, 1274, 1345, 1441, 1613, 1901, 2033, 2380, 2404, 3168, 3359, 3455, 3476,
+    3489, 3519, 3625, 4475, 4827, 4850, 5060, 5866, 6005, 6642, 1364, 2540, 3128, 3734, 827, 1435,
+    1456, 1967, 2006, 2290, 2565, 2972, 3033, 3085, 3643, 4588, 5322, 6649, 6639, 6729, 1713, 3151,
+    4905, 718, 1690, 2190, 4757, 2808, 3564, 473, 635, 991, 2059, 3288, 4134, 6337, 438, 944, 540,
+    574, 564, 603, 585, 894, 1038, 1204, 1418, 1535, 1615, 1635, 4091, 2616, 2729, 2739, 3044,
+    3160, 3447, 3650, 4072, 4085, 4123, 4245, 4843, 4856, 4874, 4904, 4938, 4930, 5185, 5444, 1210,
+    5568, 5797, 5853, 6276, 5120, 613, 1731, 2223, 3560, 3746, 4769, 4848, 5154, 5587, 6237, 6414,
+    6457, 523, 554, 5366, 1255, 1353, 1389, 1905, 2010, 2035, 2076, 2054, 2202, 2224, 2302, 2489,
+    6541, 2603, 2830, 3225, 3449, 3753, 3869, 4423, 4837, 5215, 6132, 6210, 5562, 823, 906, 1421,
+    1114, 1612, 1747, 2161, 2915, 3237, 4001, 5005, 5241, 5418, 6074, 6501, 3222, 6442, 5141, 1309,
+    2770, 5139, 5987, 884, 1232, 1049, 1884, 2314, 2446, 2787, 2785, 3089, 3635, 4014, 4141, 5348,
+    5362, 5629, 5731, 5910, 6142, 6212, 6302, 1577, 1719, 2912, 6370, 6371, 2371, 4212, 2973, 2993,
+    2834, 6596, 3377, 878, 1178, 2065, 2315, 3208, 3270, 3338, 4968, 5306, 5756, 443, 6611, 1043,
+    2873, 2979, 6311, 2781, 752, 4168, 4994, 5975, 6069, 1221, 6646, 2779, 4875, 4959, 4336, 836,
+    4849, 3709, 480, 591, 785, 1628, 837, 916, 926, 1804, 1176, 1343, 1497, 1593, 1598, 1631, 1843,
+    1849, 2083, 2131, 2158, 2192, 2399, 2505, 2750, 2799, 2859, 3040, 3047, 3127, 3362, 3395, 3372,
+    3700, 3728, 3901, 3917, 4199, 4259, 4293, 4469, 4538, 4601, 4662, 4819, 4915, 5357, 5452, 5571,
+    5888, 6108, 6135, 6134, 6141, 6191, 6332, 6422, 4794, 457, 1066, 1722, 1782, 3645, 3845, 3930,
+    1715, 4600, 5619, 6235, 6351, 6373, 6375, 546, 550, 986, 1177, 1223, 1330, 1442, 1549, 1621,
+    1710, 1811, 1854, 2000, 2403, 2686, 2551, 2556, 2672, 2842, 2838, 2987, 1822, 3093, 3113, 3140,
+    3779, 4048, 1574, 4105, 4370, 1997, 5343, 5382, 5579, 5635, 5718, 5743, 6396, 6472, 6540, 481,
+    680, 715, 1473, 1602, 2236, 2267, 2464, 3038, 3579, 3788, 4047, 4580, 5216, 5446, 5515, 2383,
+    4927, 6320, 1051, 1048, 1185, 2800, 2985, 5416, 3984, 2715, 6731, 988, 1605, 4702, 5108, 5814,
+    447, 464, 521, 553, 1015, 1071, 1610, 1920, 2030, 2497, 2687, 3123, 3137, 3183, 3450, 3664,
+    4144, 4162, 4203, 4384, 4369, 4417, 2559, 3561, 1010, 1686, 1795, 2304, 2313, 2333, 3282, 5111,
+    5390, 5652, 6034, 3352, 4076, 6552, 507, 508, 509, 600, 602, 702, 741, 4195, 762, 825, 974,
+    950, 993, 1034, 1186, 1371, 1730, 1936, 1942, 2037, 2047, 2063, 2334, 2502, 2984, 3169, 3587,
+    3602, 3973, 4692, 4696, 4784, 5040, 5912, 6088, 6341, 6436, 6489, 541, 814, 1674, 2613, 2902,
+    1684, 2647, 2907, 3642, 2797, 4320, 697, 924, 1290, 1806, 6046, 2530, 3032, 3039, 3672, 4068,
+    4084, 4225, 4454, 4846, 4928, 5257, 5324, 5498, 5792, 5971, 1062, 6012, 474, 661, 1030, 958,
+    3594, 3644, 3970, 4863, 5709, 6452, 6453, 6456, 765, 2129, 5113, 1188, 4146, 678, 1629, 5848,
+    6204, 455, 3016, 5981, 1695, 1688, 2370, 4159, 3150, 6306, 5766, 4161, 3501, 6206, 4321, 2776,
+    4518, 2798, 6064, 927, 1060, 5069, 5379, 4578, 5719, 5906, 981, 5260, 4046, 611, 695, 840, 738,
+    1158, 1283, 3658, 1305, 1448, 1953, 1971, 2066, 2170, 2160, 2185, 2384, 2420, 2508, 2608, 2791,
+    3275, 3767, 4124, 4368, 4407, 4430, 4504, 4556, 4878, 4897, 5138, 5374, 5409, 5511, 5729, 6356,
+    6672, 4845, 5791, 6585, 885, 2231, 2431, 3403, 6216, 2790, 684, 3030, 3139, 3373, 4143, 4410,
+    5233, 5386, 2660, 543, 662, 654, 673, 767, 854, 870, 1181, 1273, 1334, 1539, 1904, 2189, 2209,
+    2320, 2356, 2874, 2971, 3571, 3582, 3619, 3979, 3993, 4425, 3918, 4666, 5342, 5487, 5604, 5720,
+    5868, 6066, 6202, 6374, 6475, 6702, 737, 999, 1008, 1851, 1929, 3279, 3317, 3640, 3663, 4402,
+    4807, 5371, 5474, 6182, 467, 640, 1029, 1095, 1263, 1490, 1575, 1798, 1870, 1930, 2241, 2493,
+    2736, 3301, 3592, 4351, 5274, 4663, 4686, 4939, 5107, 5424, 5656, 5995, 6239, 6378, 6757, 493,
+    492, 549, 967, 1081, 1076, 1515, 1977, 1987, 2081, 2826, 2997, 3680, 3997, 5433, 5437, 5519,
+    5946, 475, 6573, 504, 589, 607, 647, 646, 743, 759, 893, 904, 933, 998, 1028, 1059, 1056, 1171,
+    1295, 1308, 1466, 1565, 1572, 1595, 1792, 1794, 1803, 1848, 1857, 1865, 1874, 1925, 2048, 2147,
+    2279, 2308, 2378, 2490, 2567, 2592, 2649, 2709, 2778, 2819, 2936, 3131, 3201, 3220, 3293, 3321,
+    3741, 3870, 3992, 4126, 4359, 4373, 4382, 4413, 4443, 4616, 4608, 4670, 4671, 4717, 4736, 4800,
+    4900, 5237, 5246, 5489, 5566, 5612, 5903, 5938, 6010, 3988, 6036, 6143, 6180, 6345, 6440, 6509,
+    6650, 868, 898, 1044, 1050, 1393, 2323, 3413, 5546, 5765, 6723, 744, 857, 1082, 1264, 4127,
+    5939, 6663, 6735, 3626, 3355, 4724, 3725, 2016, 2102, 6498, 3596, 5786, 3055, 6344, 522, 1259,
+    1297, 1385, 1523, 2058, 2195, 2574, 2569, 2786, 2831, 3265, 3812, 4399, 4823, 6542, 497, 571,
+    1014, 1139, 1764, 1793, 1797, 2768, 3152, 3692, 3976, 5400, 6071, 5290, 1294, 1869, 2337, 693,
+    688, 775, 2658, 1125, 1362, 1484, 1611, 1955, 2250, 2374, 2788, 3060, 3271, 3452, 5962, 3573,
+    3980, 3983, 4063, 2521, 4394, 4933, 5278, 5733, 6217, 871, 1282, 2694, 4555, 5565, 799, 1289,
+    6169, 1349, 2916, 4673, 1113, 1743, 1331, 4005, 6696, 579, 861, 1100, 2421, 2582, 2665, 2767,
+    4151, 4233, 4389, 6051, 2802, 6567, 4220, 946, 773, 853, 1634, 2322, 2443, 2462, 2679, 3088,
+    5067, 6245, 3874, 6579, 2344, 6037, 6566, 3886, 2597, 436, 685, 1011, 1706, 2109, 2433, 2506,
+    2804, 3524, 3652, 3733, 4252, 4524, 5124, 5522, 5600, 5940, 6412, 2534, 2627, 3072, 528, 527,
+    562, 590, 856, 1045, 1041, 1197, 1254, 1402, 1488, 1487, 1503, 1563, 1691, 1807, 1819, 2003,
+    2027, 2328, 2483, 1571, 2536, 2541, 2560, 2730, 3053, 3064, 3109, 3627, 4049, 4088, 4366, 4383,
+    4390, 4664, 4695, 4774, 5345, 5406, 5411, 5410, 5426, 5469, 5592, 5601, 6242, 6404, 3059, 488,
+    563, 595, 748, 1567, 1650, 2144, 2326, 2591, 3031, 3336, 3164, 3550, 3701, 3811, 4017, 4042,
+    4613, 4623, 4772, 4988, 5772, 3125, 6705, 1917, 5506, 6648, 4198, 5726, 1594, 6255, 433, 1040,
+    1328, 1436, 1537, 1607, 2071, 3312, 3354, 3803, 5608, 1604, 5017, 4275, 675, 2766, 4835, 1700,
+    5038, 4483, 4789, 784, 1655, 2352, 5627, 2527, 3497, 4044, 4381, 4610, 5490, 5716, 5873, 5135,
+    5897, 649, 932, 1678, 2696, 3451, 3544, 5923, 5963, 6048, 4862, 1619, 1934, 2116, 459, 1021,
+    1590, 2249, 2681, 3071, 3600, 3656, 4118, 4722, 5640, 5926, 6437, 720, 1022, 1087, 1652, 2363,
+    2978, 4438, 6262, 1253, 1019, 1086, 1597, 1675, 1789, 629, 2120, 2324, 3203, 4087, 4090, 4401,
+    4505, 4593, 4773, 4798, 4987, 5236, 5331, 5526, 5691, 5748, 5837, 5922, 5935, 6238, 5949, 516,
+    570, 739, 962, 1981, 2221, 2752, 3122, 3274, 3631, 4488, 5967, 6013, 1020, 1423, 1618, 3248,
+    4056, 4490, 4331, 1370, 3506, 828, 5240, 5803, 614, 1739, 2580, 3950, 4188, 4796, 6469, 806,
+    1990, 2562, 2904, 3070, 3263, 3318, 3378, 3911, 4379, 1790, 5876, 5942, 6346, 817, 843, 2244,
+    2620, 2639, 3278, 1873, 4458, 4559, 2652, 5072, 5087, 5473, 896, 1023, 1463, 1861, 1978, 2049,
+    6028, 6190, 694, 726, 918, 949, 966, 1035, 1131, 1155, 1147, 1207, 1458, 1485, 1518, 1614,
+    1657, 1665, 1666, 1669, 1858, 1860, 1890, 1960, 2266, 2270, 2310, 2365, 2358, 2570, 2572, 2583,
+    2611, 2718, 2824, 2967, 2975, 3165, 3229, 3272, 3302, 3483, 3481, 3494, 3807, 3912, 3990, 4032,
+    4061, 4103, 4185, 4207, 4337, 4398, 4658, 4931, 4986, 5037, 5244, 5294, 5385, 5396, 5403, 5419,
+    5544, 5602, 5951, 6009, 6068, 6097, 6218, 6318, 437, 435, 449, 471, 783, 876, 1318, 1347, 1390,
+    1553, 1826, 2096, 2472, 2703, 2697, 3212, 3585, 3774, 4139, 4995, 5517, 5957, 6043, 1212, 1327,
+    6406, 2316, 2867, 3075, 3527, 4506, 4645, 4826, 5367, 6395, 5179, 5781, 1679, 561, 625, 608,
+    1140, 1512, 1632, 1642, 1998, 2149, 2338, 2537, 2595, 2855, 2921, 3226, 3262, 3742, 3800, 3921,
+    4057, 4099, 4396, 4768, 4842, 5068, 5353, 5394, 5707, 5770, 5838, 5976, 6265, 515, 517, 832,
+    1368, 1403, 1662, 3729, 1681, 4713, 5375, 5790, 6189, 6304, 4213, 5454, 6347, 5930, 1261, 1003,
+    5825, 1072, 1302, 1818, 2379, 3117, 3454, 3935, 4324, 4596, 5251, 5846, 5927, 6039, 6040, 6211,
+    3683, 6504, 1741, 1762, 2509, 2733, 5641, 6249, 2355, 2691, 2871, 4926, 6354, 6234, 5300, 3391,
+    2430, 1649, 445, 3439, 3762, 2584, 804, 852, 922, 1491, 1968, 2032, 2222, 2492, 2511, 2578,
+    2610, 2857, 2775, 3054, 3269, 3568, 3732, 3896, 4347, 4631, 1406, 5299, 5335, 5434, 5429, 5451,
+    5828, 5947, 6291, 6293, 2519, 4109, 4697, 6232, 1820, 2103, 2227, 2531, 2576, 2725, 3974, 4132,
+    4306, 4499, 4701, 5572, 5626, 5654, 5686, 4010, 835, 2309, 2377, 2422, 2280, 5389, 4150, 4265,
+    5441, 6254, 4424, 4787, 5174, 532, 742, 963, 980, 1606, 1654, 1668, 1788, 2233, 2246, 2444,
+    2774, 2784, 3172, 3213, 3194, 2751, 3376, 3486, 3498, 2547, 4148, 4261, 4461, 4503, 4579, 4729,
+    4795, 4809, 5065, 5415, 5596, 5661, 5879, 5878, 6023, 6015, 6128, 6568, 864, 1169, 3368, 3482,
+    755, 4070, 4507, 4748, 4352, 1218, 1355, 1714, 2381, 2656, 2655, 2893, 3595, 3787, 3786, 4034,
+    4053, 4095, 4329, 4387, 4403, 5094, 5392, 6173, 5867, 6759, 709, 881, 1017, 1027, 639, 1184,
+    1404, 1446, 3549, 1592, 1701, 948, 2107, 2112, 2350, 2362, 2342, 2387, 2453, 2561, 2653, 1791,
+    2938, 2953, 3360, 3523, 486, 3819, 3909, 4154, 4358, 4456, 4433, 4636, 4896, 4901, 4975, 4998,
+    5090, 5282, 5631, 5811, 5872, 6072, 6275, 6474, 701, 1374, 2176, 4762, 5031, 5617, 5831, 612,
+    676, 860, 987, 2064, 2343, 2701, 3290, 5642, 5830, 619, 1698, 5594, 2549, 4431, 972, 5264, 764,
+    2390, 1568, 1579, 1661, 2408, 2695, 5864, 529, 1424, 1431, 3136, 5401, 1151, 1338, 1479, 2110,
+    2252, 2743, 4805, 2891, 6175, 6450, 6477, 573, 1335, 1651, 4614, 1718, 1821, 1972, 2028, 2146,
+    2240, 2657, 3188, 3343, 4679, 4730, 4853, 5261, 5582, 5810, 5836, 6207, 6739, 6580, 536, 1039,
+    1429, 4216, 5945, 6369, 6695, 3342, 3438, 973, 1153, 1588, 2254, 2283, 2305, 3156, 3419, 3668,
+    5381, 6104, 3404, 5475, 4892, 818, 5151, 1033, 1032, 566, 5859, 5780, 1460, 4707, 1805, 4191,
+    5787, 2849, 5532, 3604, 6617, 2980, 5443, 458, 976, 1203, 1298, 2293, 2375, 2558, 3045, 3254,
+    3303, 3459, 3971, 4193, 4260, 4449, 4626, 4677, 5137, 5430, 6061, 1257, 1388, 1939, 2535, 2621,
+    2994, 3086, 3743, 5450, 652, 3968, 1284, 1926, 2057, 2612, 3132, 3823, 4114, 4557, 4775, 5165,
+    5847, 6446, 4270, 3761, 4197, 4230, 5001, 5819, 4101, 4153, 4891, 1543, 3931, 453, 542, 1599,
+    2008, 2291, 2588, 2764, 3185, 5116, 5254, 5395, 5925, 6033, 6454, 2963, 3437, 3617, 4864, 4967,
+    5575, 432, 740, 808, 1173, 1647, 1813, 1823, 1856, 1921, 1935, 1957, 1994, 2205, 2332, 2625,
+    2670, 3381, 3558, 3745, 3929, 4643, 4709, 4727, 5190, 5447, 5459, 5637, 5660, 6020, 6125, 6343,
+    6625, 909, 2349, 3908, 2666, 3145, 3653, 5590, 6077, 6197, 3199, 1375, 2868, 2937, 5809, 6073,
+    3815, 5827, 1361, 2771, 2385, 2952, 565, 3365, 2753, 5785, 5028, 4445, 6059, 2887, 3383, 1300,
+    1409, 1555, 4391, 3539, 1052, 5979, 6679, 510, 569, 672, 677, 858, 5563, 1080, 1342, 1600,
+    1817, 1862, 1871, 1902, 1927, 2073, 2287, 2339, 2395, 2829, 3121, 3999, 4073, 4107, 4465, 4811,
+    5372, 5465, 5677, 5821, 5901, 5917, 5973, 6756, 3184, 2424, 2460, 2512, 3347, 3868, 4210, 5871,
+    6083, 3330, 1135, 1995, 2438, 5761, 5801, 5997, 766, 1365, 1430, 1697, 1864, 3268, 4530, 3730,
+    5584, 5723, 6376, 3464, 556, 3091, 3400, 3739, 6261, 747, 1058, 1348, 1358, 1481, 1699, 1980,
+    2524, 2699, 3283, 3862, 4934, 5609, 5822, 5913, 6058, 3982, 3987, 897, 1868, 1279, 1464, 2021,
+    644, 751, 794, 810, 831, 1142, 1357, 1359, 1451, 1596, 1737, 1770, 2094, 2277, 2417, 2712,
+    2792, 2837, 2850, 3895, 3251, 3308, 3235, 3448, 3513, 1946, 3813, 4064, 4224, 4232, 4231, 4354,
+    4420, 850, 4971, 5047, 5083, 5377, 5479, 5536, 5667, 5812, 5818, 6098, 6199, 6360, 6479, 6161,
+    700, 914, 1054, 1333, 1664, 2184, 2437, 3197, 3953, 4189, 4691, 4947, 5858, 6014, 1729, 6636,
+    960, 1982, 1993, 3236, 3570, 3936, 4086, 4276, 3098, 3601, 5442, 2772, 2988, 826, 4149, 2883,
+    1689, 6633, 4865, 1622, 5920, 3435, 1228, 1705, 2098, 2507, 3146, 5543, 5845, 6353, 1084, 2636,
+    5986, 1443, 5894, 770, 466, 820, 5061, 5482, 3445, 2359, 6057, 2899, 6447, 4476, 3777, 975,
+    2897, 5722, 6252, 3129, 489, 1680, 1918, 5798, 936, 5597, 4654, 5118, 1908, 2557, 479, 754,
+    1468, 1683, 6335, 545, 1471, 2001, 5427, 3414, 4080, 4060, 1640, 4979, 3618, 3509, 1845, 2013,
+    2360, 2436, 3512, 4330, 463, 1906, 465, 1323, 1237, 2086, 3406, 4377, 4694, 4708, 4754, 5782,
+    5346, 5126, 1801, 2272, 2442, 5339, 2716, 3182, 3210, 3674, 3986, 1521, 4560, 4841, 6443, 631,
+    1878, 2312, 2368, 2500, 2710, 3888, 3557, 4678, 4674, 5746, 5924, 641, 1329, 1529, 2814, 2905,
+    3491, 3607, 5581, 1408, 5603, 6193, 5826, 5192, 4098, 3969, 4956, 557, 872, 977, 2300, 2748,
+    3173, 3864, 4250, 4328, 4806, 5053, 5800, 2642, 3361, 3534, 4482, 4921, 6449, 6718, 829, 4262,
+    3994, 4264, 4653, 4223, 3019, 1839, 4656, 3756, 3763, 758, 6007, 3315, 3861, 5948, 6515, 552,
+    4558, 2282, 4228, 6144, 6627, 1229, 1360, 5145, 6233, 559, 844, 969, 1018, 1024, 1809, 2416,
+    2523, 2719, 3120, 3124, 3555, 3583, 3659, 3757, 4500, 4802, 5085, 5569, 4267, 5961, 3495, 6352,
+    6401, 2340, 2606, 3770, 3902, 4022, 5035, 5149, 952, 971, 1064, 1469, 1859, 1965, 2088, 2248,
+    2263, 2284, 2522, 3100, 3174, 3796, 3879, 4003, 4094, 4455, 4561, 4665, 5270, 5445, 5583, 5883,
+    6295, 6393, 2958, 4299, 686, 1682, 1992, 2721, 3101, 3673, 3916, 4572, 6762, 2746, 4111, 957,
+    3785, 6517, 1954, 4741, 4940, 4659, 1938, 1999, 3764, 4222, 5843, 2806, 1500, 1531, 4378, 3865,
+    5496, 632, 1956, 2964, 3118, 3353, 3707, 4062, 5249, 5404, 5550, 1889, 2394, 3806, 4092, 4854,
+    6047, 6032, 4990, 5148, 6606, 1120, 1958, 2520, 3216, 3430, 5567, 5598, 6364, 2496, 3716, 439,
+    531, 1325, 1432, 1525, 1626, 790, 1808, 1866, 2023, 2262, 2513, 2532, 2607, 3223, 3545, 4214,
+    4714, 4739, 4834, 5509, 5564, 5607, 5634, 6166, 6177, 606, 2441, 3056, 4797, 4969, 5048, 5909,
+    1653, 2889, 6385, 4983, 5042, 551, 875, 1989, 1831, 2663, 4074, 4728, 5303, 5338, 3264, 1924,
+    2253, 3161, 526, 3567, 6558, 834, 1073, 1227, 1380, 2183, 2260, 3478, 1462, 4323, 4357, 4385,
+    6256, 2517, 4634, 448, 586, 763, 1351, 1841, 1844, 1913, 2744, 451, 5032, 6130, 6183, 4317,
+    6342, 714, 1386, 3847, 4009, 845, 3949, 5019, 4244, 667, 1416, 3554, 4269, 4468, 5784, 5793,
+    5852, 610, 911, 1514, 1909, 6325, 623, 4793, 6030, 1267, 2348, 3057, 3737, 5288, 5738, 4128,
+    920, 1373, 2151, 2218, 2628, 3096, 4302, 4936, 651, 634, 937, 1079, 1346, 1444, 1603, 1736,
+    1735, 1755, 1863, 2286, 2351, 2491, 2539, 2662, 3176, 3181, 3473, 3985, 4489, 4685, 4844, 4950,
+    5012, 5156, 5307, 5388, 5538, 5895, 6027, 6405, 6631, 6666, 468, 502, 683, 867, 1291, 1480,
+    1827, 2004, 2005, 2243, 2629, 2669, 2749, 2844, 779, 4388, 4667, 4745, 5476, 5557, 5586, 6011,
+    6172, 1063, 6359, 945, 703, 978, 1377, 2445, 2682, 3566, 3938, 4130, 5980, 908, 3155, 3069,
+    1332, 1833, 1449, 2678, 4604, 816, 3889, 2426, 4027, 6550, 6725, 1317, 1483, 2581, 2728, 3097,
+    1132, 2943, 1836, 4740, 2726, 6565, 2769, 6618, 2803, 506, 626, 1013, 2288, 2677, 3157, 5789,
+    605, 4517, 6728, 434, 2157, 3313, 3364, 5020, 1090, 2676, 6545, 1800, 4251, 1717, 1623, 5167,
+    3298, 5008, 4110, 4699, 1475, 4325, 3110, 3922, 917, 1427, 3480, 3563, 3964, 6278, 6664, 2865,
+    1528, 1508, 788, 1055, 1096, 2573, 3899, 5805, 6019, 6632, 1499, 3562, 3334, 746, 2840, 4452,
+    4470, 6298, 6724, 2429, 2968, 4872, 1470, 1573, 3102, 3611, 3907, 4444, 4617, 4989, 729, 2675,
+    6738, 3906, 2704, 934, 6408, 1672, 2242, 4319, 5576, 1157, 2092, 4376, 6126, 935, 476, 801,
+    1426, 3546, 4624, 5968, 1928, 3966, 995, 1964, 4372, 5070, 5391, 5700, 6292, 2765, 5071, 6110,
+    2124, 2123, 3162, 3846, 5468, 5750, 1146, 572, 728, 910, 1016, 1608, 1853, 2082, 2173, 2397,
+    2661, 2754, 3305, 3230, 3622, 3623, 3740, 4050, 5285, 5431, 5850, 5893, 5911, 6236, 5194, 1420,
+    485, 576, 440, 5425, 5754, 6350, 689, 1852, 1474, 1617, 1875, 2398, 2406, 2449, 2641, 2888,
+    2942, 3191, 3328, 3503, 3736, 4163, 4570, 4606, 4965, 5003, 5336, 5492, 5666, 5869, 6203, 6410,
+    2172, 2273, 3034, 3144, 3224, 4592, 4605, 3242, 4566, 5200, 5296, 2707, 4922, 6365, 6259, 3196,
+    4414, 4963, 5934, 478, 989, 1759, 3023, 3416, 5077, 5102, 5351, 847, 1057, 1702, 2692, 2827,
+    3666, 3695, 3817, 5283, 5291, 5966, 6251, 6201, 1976, 3641, 4174, 4966, 2373, 3766, 887, 3211,
+    3320, 3667, 3758, 3991, 4327, 6205, 4182, 6782, 609, 2163, 2545, 5112, 484, 511, 705, 450, 809,
+    1644, 2525, 2812, 3241, 3614, 3841, 3951, 4115, 4361, 4824, 5455, 5881, 5969, 6198, 6355, 892,
+    4457, 658, 997, 2727, 3246, 3516, 3671, 4771, 5747, 6220, 6619, 6716, 3685, 1352, 3234, 4393,
+    6377, 537, 560, 594, 802, 901, 1778, 2026, 3646, 4043, 4852, 5994, 6229, 6258, 6267, 6714,
+    6773, 2626, 3061, 842, 895, 3466, 5279, 1880, 2045, 2177, 3363, 3484, 4301, 4473, 4639, 5013,
+    5834, 6049, 1078, 6553, 3014, 3453, 5589, 5659, 6281, 902, 1520, 1881, 1911, 2668, 2911, 2919,
+    3221, 3356, 3565, 3606, 4280, 4607, 4648, 5175, 5904, 760, 6712, 4069, 4655, 6052, 5456, 660,
+    1101, 5417, 3058, 5591, 4698, 2099, 2731, 6693, 496, 495, 6597, 5412, 5075, 5046, 2860, 3311,
+    4006, 4718, 1915, 441, 444, 452, 454, 456, 460, 461, 462, 470, 472, 482, 483, 5547, 487, 4791,
+    1916, 490, 499, 500, 501, 503, 512, 513, 514, 525, 524, 519, 520, 518, 530, 534, 539, 535, 544,
+    547, 577, 558, 578, 580, 575, 567, 587, 593, 596, 599, 588, 582, 584, 597, 583, 592, 598, 724,
+    618, 624, 615, 620, 622, 617, 621, 616, 627, 628, 648, 655, 645, 657, 653, 637, 555, 633, 650,
+    656, 659, 636, 630, 643, 638, 663, 664, 2659, 669, 668, 665, 671, 670, 674, 679, 681, 687, 682,
+    692, 690, 698, 699, 691, 696, 704, 706, 708, 710, 707, 711, 713, 712, 719, 716, 717, 723, 722,
+    721, 725, 727, 731, 730, 733, 732, 734, 735, 749, 745, 750, 753, 4196, 756, 757, 761, 768, 769,
+    1252, 774, 772, 776, 778, 780, 781, 782, 787, 789, 786, 791, 792, 793, 798, 796, 797, 795, 800,
+    803, 805, 807, 812, 815, 5110, 819, 821, 822, 824, 830, 833, 838, 841, 839, 846, 848, 849, 851,
+    855, 865, 859, 862, 863, 869, 866, 874, 880, 873, 879, 883, 882, 888, 891, 890, 877, 886, 889,
+    5776, 5775, 899, 900, 905, 903, 907, 912, 915, 919, 923, 925, 6399, 921, 928, 929, 930, 931,
+    938, 939, 3744, 940, 942, 941, 943, 947, 951, 953, 954, 955, 956, 961, 965, 964, 446, 968, 970,
+    811, 979, 982, 983, 1422, 992, 990, 994, 996, 1000, 1002, 1001, 1006, 1007, 1009, 1012, 4288,
+    6248, 1025, 2654, 3528, 1037, 1031, 1036, 1046, 1047, 1075, 1077, 1067, 1068, 1074, 1069, 1070,
+    1065, 1061, 1083, 1103, 1091, 1102, 1085, 1089, 1093, 1088, 1105, 1094, 1097, 1092, 1098, 1104,
+    1099, 1123, 1106, 1115, 1108, 1111, 1122, 1124, 1109, 1110, 1121, 1107, 1118, 1116, 1126, 1129,
+    1130, 1141, 1143, 1137, 1134, 1133, 1136, 1128, 1150, 1152, 1163, 1156, 1145, 1161, 1154, 1159,
+    1160, 1149, 1148, 1162, 1175, 1167, 1112, 1172, 1180, 1164, 1166, 1174, 1179, 1187, 1165, 1168,
+    1183, 1170, 1182, 1194, 1191, 1196, 1190, 1195, 1198, 1193, 1205, 1199, 1206, 1202, 1201, 1209,
+    1200, 1219, 1220, 3521, 1214, 1215, 1213, 1216, 1225, 1222, 1211, 1226, 1224, 1231, 1230, 1233,
+    1236, 1235, 1234, 1238, 1239, 1241, 1240, 1245, 1242, 1244, 1246, 1243, 1247, 1248, 1249, 1250,
+    1251, 1258, 1262, 1266, 1265, 1268, 1270, 1269, 1271, 1272, 1275, 1277, 1276, 1192, 1278, 1280,
+    1285, 1286, 1292, 1287, 1288, 1293, 1299, 1314, 1303, 1296, 1301, 1304, 1306, 1307, 1313, 1310,
+    1311, 1312, 1315, 1316, 1321, 1319, 1320, 1336, 1322, 1326, 1337, 1339, 1340, 1367, 1341, 1363,
+    1354, 1366, 3092, 1356, 1350, 1369, 1372, 1382, 1376, 1378, 1383, 1398, 1384, 1381, 1379, 1387,
+    1392, 1391, 1394, 1396, 1395, 1401, 1397, 1400, 1399, 1405, 1411, 1410, 1412, 1413, 1414, 1415,
+    1417, 1419, 1425, 2825, 1428, 1433, 1434, 1437, 1439, 1440, 4177, 1450, 1447, 1445, 1453, 1452,
+    1455, 1454, 1457, 1461, 1459, 1465, 1467, 1477, 581, 604, 1478, 1482, 1486, 1498, 1495, 1472,
+    1493, 1494, 1513, 1511, 1507, 1510, 1506, 1509, 1519, 1527, 1522, 1516, 1517, 1524, 1526, 1530,
+    1532, 1534, 1538, 1536, 1533, 1544, 1542, 1545, 1546, 1541, 1547, 1548, 1551, 1550, 1552, 1557,
+    1540, 1554, 1558, 1559, 1561, 1562, 1560, 1564, 1566, 1569, 1570, 1576, 1578, 1580, 1582, 1583,
+    1584, 2518, 1585, 1586, 1587, 1609, 1616, 1620, 1624, 1625, 1630, 1627, 1637, 1639, 1636, 1633,
+    1638, 1643, 1645, 1648, 1646, 1656, 1658, 1659, 1663, 1667, 1670, 1671, 1673, 1676, 1677, 1685,
+    1687, 1692, 1696, 1694, 1693, 1581, 1703, 1704, 477, 1707, 1708, 1709, 1711, 1712, 1476, 1716,
+    1723, 1720, 1724, 1721, 1726, 1725, 1727, 1728, 1732, 1740, 1738, 1734, 1771, 1733, 1742, 1746,
+    1748, 1761, 1753, 1752, 1744, 1745, 1754, 1751, 1750, 1749, 1757, 1760, 1758, 1756, 1763, 1765,
+    1766, 1769, 1768, 1767, 1774, 1773, 1775, 1776, 1780, 1772, 1777, 1779, 1781, 1783, 1785, 1784,
+    1786, 1787, 1796, 1799, 1802, 1810, 1815, 1814, 1812, 1816, 1824, 1825, 1830, 1829, 1828, 1834,
+    1837, 1835, 1838, 1840, 1842, 1832, 1846, 1847, 1850, 6726, 1855, 1867, 1876, 1877, 1879, 1883,
+    1882, 1885, 1893, 1888, 1886, 1887, 1892, 1891, 1894, 1895, 1896, 1898, 1899, 1897, 1900, 1903,
+    1907, 1910, 1912, 1951, 1950, 1914, 1919, 1923, 1931, 1932, 1937, 1940, 1941, 1943, 1944, 1933,
+    1945, 1947, 1948, 1949, 1952, 1959, 1962, 1963, 1970, 1969, 1966, 1975, 1974, 1979, 1973, 1985,
+    1984, 1983, 1986, 1988, 1991, 1996, 2002, 2015, 2009, 2014, 2012, 2007, 2089, 2017, 2029, 2053,
+    2024, 2020, 2034, 2018, 2038, 2025, 2022, 2036, 2031, 2019, 2039, 2051, 2040, 2060, 2067, 2052,
+    2044, 2043, 2046, 2055, 2042, 2056, 2041, 2062, 2061, 2050, 2068, 2074, 2108, 2069, 2079, 2070,
+    2080, 2077, 2078, 2075, 2085, 2072, 2106, 2093, 2105, 2100, 2090, 2011, 2095, 2097, 2091, 2101,
+    2148, 2128, 2122, 2114, 2115, 2119, 2113, 2117, 2121, 2132, 2118, 2111, 2125, 2126, 2143, 2139,
+    2134, 2133, 2145, 2140, 2137, 2135, 2136, 2138, 2141, 2142, 2167, 2171, 2152, 2153, 2154, 2162,
+    2168, 2164, 2159, 2166, 2156, 2155, 2150, 2169, 2181, 2180, 2174, 2187, 2179, 2182, 2175, 2178,
+    2186, 2188, 2200, 2198, 2197, 2208, 2196, 2193, 2194, 2191, 2199, 4563, 2201, 2204, 2203, 2207,
+    2210, 2206, 2214, 2212, 2211, 2213, 2215, 2216, 2217, 2220, 2219, 2225, 2228, 2230, 2232, 2234,
+    2235, 2237, 2238, 2239, 2245, 2251, 2256, 2258, 2255, 2257, 2259, 2264, 2268, 2271, 2265, 2274,
+    2275, 2278, 2306, 2269, 2276, 2307, 2303, 2289, 2301, 2325, 2294, 2455, 2296, 2311, 2299, 2298,
+    2292, 2295, 2281, 2297, 2331, 2317, 2319, 2335, 2327, 2329, 2318, 2321, 2347, 2341, 2345, 2413,
+    2346, 2369, 2366, 2361, 2382, 2357, 2376, 2367, 2364, 2372, 2386, 2354, 2353, 2388, 2402, 2389,
+    2392, 2401, 2393, 2396, 2405, 2391, 2411, 2418, 2407, 2410, 2414, 2419, 2477, 2412, 2415, 2409,
+    2425, 2427, 2428, 2423, 2480, 2435, 2434, 2439, 2440, 2432, 2448, 2457, 2454, 2451, 2452, 2447,
+    2456, 2450, 2466, 2463, 4785, 2458, 2459, 2285, 2461, 2465, 2482, 2469, 2468, 2467, 2470, 2473,
+    2471, 2475, 2476, 2474, 2479, 2478, 2481, 2484, 2485, 2487, 2486, 2488, 3752, 2494, 2499, 2498,
+    2495, 2501, 2504, 2503, 2510, 2514, 2515, 2516, 5523, 2526, 2528, 2533, 2538, 2543, 2546, 2542,
+    2544, 2548, 2550, 2553, 2552, 2554, 2555, 2564, 2713, 2571, 2568, 2566, 2714, 2586, 2587, 2585,
+    2577, 2596, 2593, 2594, 2590, 2599, 2598, 2602, 2604, 2605, 2600, 2601, 2609, 2614, 2617, 2619,
+    2618, 2615, 2623, 2624, 2634, 2631, 2637, 2633, 2632, 2635, 2630, 2638, 2640, 2643, 2589, 2644,
+    2645, 2646, 2650, 2651, 2664, 2667, 2671, 2673, 2674, 6282, 2680, 2688, 2683, 2689, 2685, 2684,
+    2690, 2700, 2702, 2698, 2705, 2706, 2722, 2711, 2735, 2717, 2708, 2724, 2723, 2734, 2732, 2720,
+    2740, 2763, 2738, 2761, 2737, 2760, 2741, 2758, 2756, 2755, 2757, 2762, 2742, 2747, 2745, 2759,
+    3000, 2777, 2793, 2780, 2789, 2795, 2783, 2796, 2832, 2782, 2801, 2805, 2809, 2811, 2822, 2816,
+    2828, 2818, 2821, 2820, 2813, 3006, 2835, 2807, 2833, 2823, 2836, 2869, 2815, 2810, 2861, 2841,
+    2864, 2848, 2876, 2879, 2852, 2866, 2843, 2845, 2854, 2846, 2884, 2870, 2862, 2847, 2853, 2878,
+    2858, 2851, 2856, 2880, 2881, 2872, 2877, 2875, 2839, 2910, 2908, 2894, 2909, 2901, 2890, 2913,
+    2903, 2885, 2906, 2886, 2892, 2882, 2898, 2896, 2895, 2914, 2900, 2927, 2925, 2940, 2931, 2932,
+    2941, 2930, 2939, 1641, 2935, 2944, 2929, 2933, 2924, 2974, 2918, 2923, 2922, 2920, 2928, 2945,
+    2947, 2955, 2965, 2954, 3025, 2951, 2950, 2946, 2956, 2926, 2966, 2949, 2962, 2948, 2957, 2960,
+    3010, 2970, 2961, 2981, 2959, 2991, 2976, 2992, 2982, 2989, 2986, 2990, 2983, 2977, 2969, 2998,
+    2996, 3001, 2995, 3002, 3003, 2999, 5099, 3009, 3012, 3011, 3008, 3007, 3005, 3017, 3013, 3018,
+    3004, 3015, 3020, 3022, 3024, 5098, 3021, 3026, 3027, 6537, 3029, 3036, 3035, 3897, 3037, 6400,
+    3042, 3041, 3043, 3046, 3049, 3048, 3050, 3051, 3052, 3062, 3063, 3065, 3066, 3068, 3067, 3073,
+    3076, 3074, 3077, 3078, 3079, 3080, 3081, 3083, 3082, 3084, 3087, 3090, 3094, 3095, 3099, 3103,
+    3105, 3104, 3107, 3106, 6727, 3108, 3111, 3112, 3114, 3116, 3115, 3130, 3126, 3133, 3135, 3143,
+    3147, 3153, 3142, 3154, 3141, 3134, 3138, 3149, 3148, 3170, 3189, 3175, 3166, 3177, 3171, 3180,
+    3158, 3159, 3167, 3163, 3179, 3187, 3178, 3186, 3198, 5238, 3206, 3202, 3209, 3207, 3195, 3205,
+    3204, 3193, 3192, 3217, 3232, 3218, 3215, 3227, 3214, 3231, 3233, 3415, 3228, 3266, 3276, 3273,
+    3239, 3245, 3256, 3240, 3244, 3260, 3253, 3247, 3257, 3249, 3243, 3267, 3252, 3255, 3250, 3258,
+    3261, 3291, 3307, 3292, 3277, 3309, 3304, 3296, 3284, 3306, 3289, 3310, 3299, 3281, 3297, 3295,
+    3300, 3285, 3351, 3280, 3294, 3314, 3325, 3319, 3335, 3329, 3339, 3331, 3326, 3333, 3327, 3340,
+    3341, 3316, 3324, 3332, 3322, 3371, 3357, 3443, 3344, 3349, 3350, 3370, 3346, 3366, 3345, 3367,
+    3337, 3369, 3358, 3348, 3392, 3386, 3385, 3389, 3388, 3382, 3375, 3421, 3380, 3390, 3387, 3374,
+    3393, 3394, 3405, 3379, 3401, 3397, 3396, 3398, 3402, 3407, 3399, 3412, 3409, 3417, 3408, 3410,
+    3420, 3418, 3411, 3426, 3427, 3423, 3429, 3425, 3428, 3424, 3433, 3432, 3384, 3434, 3431, 3436,
+    3440, 3442, 3441, 3444, 3446, 3457, 3456, 3461, 3472, 3458, 3463, 3462, 3460, 3471, 3467, 3470,
+    3469, 3475, 3474, 3479, 3477, 3492, 3487, 3500, 3493, 3490, 3485, 3488, 3496, 3502, 3531, 3499,
+    3504, 3507, 3508, 3520, 3510, 3511, 3517, 3514, 3518, 3515, 3522, 3526, 3525, 3529, 3532, 3530,
+    3533, 3535, 3536, 3537, 3538, 3541, 3540, 3542, 3543, 3547, 3548, 3551, 3552, 3553, 3556, 3559,
+    3569, 3572, 3575, 3574, 3576, 3577, 3578, 3580, 3581, 3586, 3584, 3588, 3590, 3589, 3591, 3593,
+    3599, 3597, 3598, 3605, 3603, 642, 3610, 3608, 3612, 3609, 3613, 3621, 3620, 3616, 3615, 3624,
+    3628, 3629, 6737, 3630, 3633, 3632, 3634, 3637, 3636, 3639, 3638, 3651, 3647, 3654, 3648, 3649,
+    3657, 3660, 3655, 3698, 3665, 3690, 3669, 3662, 3670, 3675, 3678, 3676, 3684, 3679, 3677, 3682,
+    3688, 3691, 3686, 3689, 3693, 3694, 3696, 3697, 3699, 3702, 3703, 3704, 3661, 3706, 3708, 3710,
+    3711, 3712, 3715, 3713, 3714, 3718, 3717, 3720, 3719, 3721, 3722, 3724, 3723, 3727, 3726, 3731,
+    3735, 3738, 3747, 3748, 3754, 3751, 3750, 3760, 3749, 3759, 3769, 3765, 3768, 3771, 3772, 3778,
+    3776, 3781, 3782, 3775, 3784, 3783, 3780, 3789, 3790, 3791, 3793, 3792, 3804, 3797, 3805, 3798,
+    3802, 3799, 3801, 3795, 3809, 3808, 3810, 3814, 3818, 3816, 3826, 3827, 3825, 3828, 3820, 3824,
+    3821, 3822, 3830, 3831, 3829, 3832, 3837, 3833, 3834, 3835, 3836, 3839, 3838, 3840, 3843, 3844,
+    3842, 3849, 3848, 3850, 3851, 3853, 3854, 3855, 3852, 3856, 3857, 3858, 3859, 3860, 3863, 3866,
+    3867, 3871, 3872, 3873, 3876, 3875, 3877, 3878, 3880, 3881, 3883, 3884, 3885, 3887, 3892, 3894,
+    3893, 3898, 3900, 3903, 3904, 3905, 5103, 3910, 3915, 3914, 3913, 3926, 3923, 3920, 3924, 3925,
+    3919, 3927, 3928, 3932, 3933, 3939, 3940, 3934, 3937, 3943, 3942, 3941, 3945, 3944, 3946, 3948,
+    3947, 3952, 3954, 3955, 3959, 3957, 3958, 3956, 3960, 3961, 3962, 3963, 3965, 3967, 3972, 3975,
+    3977, 3978, 4038, 3981, 4039, 3989, 4002, 3995, 3998, 3996, 4007, 4000, 4008, 4013, 4011, 4012,
+    4023, 4019, 4021, 4024, 4016, 4015, 4018, 4020, 4028, 4026, 4025, 4031, 4030, 4033, 4036, 4035,
+    4037, 4041, 4040, 4045, 4059, 4054, 4058, 4055, 4052, 4051, 4065, 4066, 4071, 4075, 4077, 6765,
+    4078, 4079, 4081, 4082, 4083, 4089, 4093, 4100, 4102, 4096, 4097, 4106, 4108, 4112, 4113, 4117,
+    4116, 4067, 4119, 4122, 4125, 4121, 4129, 4136, 4131, 4137, 4138, 4140, 6785, 4142, 4145, 4147,
+    4152, 4157, 4155, 4158, 4156, 4160, 4172, 4164, 4165, 4170, 4169, 4167, 5887, 4171, 4173, 4175,
+    4176, 4179, 4178, 4180, 4181, 4183, 4186, 4187, 4190, 4192, 4194, 4200, 4206, 4204, 4202, 4217,
+    4208, 4209, 4211, 4218, 4215, 4219, 4229, 4243, 4201, 4227, 4205, 4226, 4221, 4242, 4235, 4241,
+    4236, 4239, 4240, 4237, 4238, 4257, 4253, 4258, 4247, 4256, 4255, 4246, 4249, 4248, 4234, 4254,
+    4271, 4266, 4272, 4273, 4263, 4268, 4274, 4279, 4287, 4290, 4278, 4277, 4313, 4284, 4285, 4289,
+    4281, 4283, 4291, 4286, 4282, 4294, 4295, 4296, 4292, 4298, 4297, 4300, 4305, 4304, 4309, 4307,
+    4303, 4308, 4311, 4312, 4314, 4310, 4315, 4316, 4318, 4322, 4326, 4335, 4340, 4334, 4339, 4333,
+    4338, 4344, 4343, 4342, 4341, 4345, 4346, 4348, 4350, 4349, 4355, 4353, 4356, 4360, 6539, 4362,
+    4363, 4364, 4365, 4367, 4374, 4371, 4386, 4380, 4375, 4404, 4406, 4392, 4395, 4400, 4397, 4405,
+    4422, 4411, 4408, 4421, 4418, 4419, 4409, 4416, 4429, 4427, 4412, 4428, 4426, 4432, 4448, 4440,
+    4436, 4446, 4453, 4450, 4437, 4498, 4435, 4441, 4462, 4447, 4434, 4442, 4460, 4463, 4467, 4464,
+    4474, 4472, 4466, 4477, 4479, 4487, 4485, 4481, 4491, 4484, 4478, 4480, 4486, 4502, 4513, 4497,
+    4493, 4495, 4501, 4494, 4492, 4496, 4514, 4511, 4508, 4512, 4509, 4510, 4519, 4516, 4515, 4521,
+    4520, 4525, 4459, 4522, 5778, 4523, 4526, 4527, 4528, 4532, 4531, 4533, 4534, 4535, 4529, 4536,
+    4537, 4539, 4540, 4541, 4542, 4543, 4544, 4545, 4546, 4548, 4547, 4549, 4550, 4551, 4553, 4554,
+    4552, 4562, 4564, 4567, 4565, 4569, 4568, 4571, 4573, 4576, 4575, 4574, 4577, 4582, 4583, 4586,
+    4581, 4584, 4585, 5516, 4589, 4590, 4591, 4595, 4594, 4597, 4598, 4599, 4602, 4603, 6394, 4611,
+    4609, 4612, 4615, 4618, 4619, 4620, 4621, 4622, 4627, 4625, 4629, 4628, 4630, 4632, 4633, 4635,
+    4637, 4638, 4642, 4641, 4640, 4644, 4646, 4647, 4649, 4650, 4652, 4651, 4661, 4657, 4660, 4669,
+    777, 4668, 4683, 4688, 4681, 4684, 4676, 4682, 4680, 4700, 4689, 4690, 4703, 4705, 4704, 4706,
+    4712, 6208, 4711, 4710, 4716, 4715, 4693, 4725, 4723, 4720, 4721, 4726, 4733, 4734, 4735, 4731,
+    4732, 4742, 4738, 4744, 4743, 4719, 4737, 4746, 4747, 4749, 4753, 4750, 4752, 4755, 4756, 4751,
+    4759, 4760, 4761, 4764, 4763, 4758, 4765, 4766, 4767, 4770, 4776, 4777, 4779, 4780, 4781, 4782,
+    4783, 4786, 4788, 4790, 4792, 4799, 4801, 4808, 4804, 4810, 4813, 4812, 4815, 4814, 4816, 4818,
+    4817, 4820, 4821, 4822, 4803, 4825, 4828, 4829, 4830, 4832, 4833, 4839, 4836, 4847, 4840, 4860,
+    4861, 4859, 4851, 4868, 4867, 4870, 4912, 4873, 4871, 4869, 4858, 4876, 4857, 4877, 4855, 4890,
+    4889, 4879, 4888, 4887, 4894, 4893, 4899, 4895, 4886, 4885, 4880, 4881, 4907, 4914, 4920, 4916,
+    4918, 4913, 4883, 4911, 4908, 4910, 4906, 4923, 4902, 4903, 4917, 4909, 4919, 4942, 4953, 4937,
+    4929, 4945, 4946, 4932, 4949, 4925, 4943, 4948, 4935, 4941, 4951, 4955, 4954, 4924, 4961, 5018,
+    4944, 4976, 4957, 4962, 5044, 4985, 4981, 4974, 4993, 4977, 4984, 4973, 4964, 4958, 4978, 4982,
+    4960, 5004, 4970, 4996, 4999, 4991, 5010, 5006, 4997, 5011, 5007, 5000, 5002, 5009, 4992, 5027,
+    5016, 5014, 5023, 5024, 5029, 5026, 5022, 5021, 5015, 5033, 5045, 5043, 5036, 5034, 5092, 5039,
+    5041, 5052, 5064, 5055, 5058, 5056, 5066, 5049, 5059, 5062, 5086, 5054, 5063, 5050, 5051, 5057,
+    5076, 5074, 5078, 5073, 5079, 5080, 5082, 5084, 5081, 5088, 5095, 5097, 5096, 5091, 5089, 5093,
+    5101, 5100, 5104, 5105, 5106, 469, 5109, 5114, 5115, 5117, 5123, 5125, 5127, 5128, 5121, 5122,
+    5132, 5130, 5133, 5134, 5131, 5136, 5227, 5129, 5140, 5143, 5146, 5147, 5144, 5142, 5150, 5164,
+    5158, 5159, 5155, 5157, 5153, 5163, 5160, 5162, 5152, 5161, 5170, 5173, 5171, 5172, 5168, 5169,
+    5166, 5183, 5182, 5191, 5176, 5177, 5189, 5180, 5186, 5188, 5181, 5178, 5184, 5187, 5218, 5196,
+    5195, 5193, 5198, 5207, 5202, 5203, 5208, 6247, 5197, 5204, 5199, 5206, 5205, 5201, 5211, 5212,
+    5210, 5221, 5219, 5217, 5213, 5214, 5220, 5209, 5222, 5224, 5223, 5226, 5225, 5229, 5230, 5231,
+    5228, 5232, 5235, 5234, 5239, 5242, 5245, 5247, 5250, 5258, 5256, 5267, 5253, 5255, 5275, 5252,
+    5259, 5265, 5263, 5271, 5266, 5268, 5262, 5269, 5272, 5277, 5273, 5280, 5281, 5284, 5286, 5287,
+    5289, 5297, 5301, 5298, 5295, 5292, 5293, 5302, 5305, 5304, 5308, 5318, 5310, 5311, 5312, 5313,
+    5317, 5319, 5316, 5314, 5315, 5320, 5309, 5325, 5323, 5327, 5326, 5329, 5328, 5330, 5332, 5333,
+    5334, 5337, 5340, 5341, 5344, 5347, 5349, 5352, 5350, 5354, 5355, 5356, 5358, 5359, 5360, 5361,
+    5363, 5364, 5365, 5368, 5369, 5370, 5373, 5380, 5378, 5376, 5383, 5384, 5387, 5393, 5397, 5405,
+    5402, 5398, 5399, 5420, 5414, 5413, 5408, 5423, 5421, 5422, 5440, 5435, 5428, 5438, 5439, 5432,
+    5436, 5449, 5453, 5448, 5457, 5467, 5471, 5466, 5464, 5470, 5484, 5461, 5463, 5472, 5460, 5458,
+    5481, 5480, 5488, 5462, 5485, 5483, 5486, 5491, 5497, 6324, 5493, 5495, 5499, 5494, 5500, 5502,
+    5504, 5503, 5501, 5505, 5508, 5507, 5512, 5510, 5513, 5514, 5518, 5520, 5521, 5524, 5525, 5527,
+    5528, 5529, 5530, 5531, 5533, 5535, 5534, 5537, 5539, 5540, 5541, 5542, 5545, 5548, 5549, 5551,
+    5553, 5555, 5554, 5556, 5558, 5559, 5560, 5552, 5561, 2229, 5573, 5570, 5585, 5577, 5578, 5574,
+    5580, 5593, 5588, 5606, 5605, 5599, 5613, 5610, 5611, 5614, 5615, 5618, 5616, 5621, 5620, 5622,
+    6766, 5623, 5595, 5624, 5625, 5628, 5630, 5632, 5633, 5636, 5639, 5645, 5644, 5643, 5648, 5650,
+    5649, 5647, 5651, 5646, 5657, 5658, 5653, 5655, 5662, 5664, 5665, 5663, 5669, 5670, 5668, 5671,
+    5676, 5675, 5673, 5674, 5681, 5678, 5680, 5682, 5683, 5679, 5685, 5689, 5687, 5672, 5688, 5684,
+    5692, 5690, 5693, 5694, 5697, 5696, 5695, 5699, 5698, 5702, 5701, 5703, 5704, 5706, 5705, 5708,
+    5710, 5714, 5711, 5712, 5713, 5715, 5717, 5721, 5724, 5728, 5727, 5725, 5730, 5734, 5732, 5739,
+    5736, 5740, 5737, 5742, 5744, 5741, 5735, 5745, 5749, 5752, 5751, 5757, 5755, 5753, 5760, 5759,
+    5758, 5762, 5763, 5764, 5767, 5768, 5769, 5771, 5773, 5774, 5777, 5779, 5783, 5794, 5796, 5795,
+    5799, 5802, 5889, 5804, 5813, 5807, 5808, 5820, 5823, 5832, 5816, 5829, 5824, 5815, 5835, 5840,
+    5839, 5842, 5806, 5854, 5855, 5856, 5857, 5817, 5849, 5844, 5861, 5862, 5865, 5870, 5874, 5877,
+    6222, 5875, 5886, 5882, 5885, 5884, 5891, 5890, 5892, 5896, 5898, 5899, 5900, 5907, 5908, 2247,
+    5905, 5914, 5915, 5916, 5919, 5918, 5921, 5928, 5929, 5931, 5932, 5933, 5937, 5936, 5944, 5943,
+    5941, 5950, 5953, 5954, 5952, 5956, 5955, 5958, 5959, 5960, 5964, 5965, 5970, 5972, 5977, 5978,
+    5974, 5984, 5982, 5983, 5991, 5985, 5989, 5993, 5990, 5988, 6006, 6003, 5998, 6000, 6008, 5999,
+    6017, 5996, 6001, 6002, 6022, 6018, 6016, 6021, 6004, 6026, 6025, 6024, 6029, 6038, 6035, 6056,
+    6050, 6041, 6044, 6042, 6045, 6054, 6053, 6055, 6062, 6063, 6067, 6065, 6060, 6078, 6075, 6076,
+    6070, 6079, 6084, 6085, 6091, 6090, 6086, 6080, 6087, 6082, 6081, 6089, 6099, 6096, 6095, 6092,
+    6093, 6094, 6103, 6101, 6102, 6100, 6105, 6106, 6109, 6107, 6112, 6114, 6115, 6113, 6116, 5992,
+    6117, 6118, 6119, 6121, 6111, 6120, 6122, 6124, 6123, 6127, 6129, 6131, 6136, 6137, 6138, 6139,
+    6140, 6146, 6145, 6147, 6151, 6150, 6149, 6152, 6155, 3422, 6153, 6157, 6156, 6159, 6158, 6160,
+    6162, 6163, 6165, 6164, 6167, 6168, 6170, 6171, 6176, 6179, 6181, 6178, 6200, 6184, 6186, 6185,
+    6187, 6192, 6196, 6194, 6209, 6215, 6213, 6214, 6223, 6221, 6225, 6226, 6224, 6227, 6228, 6230,
+    6231, 6244, 6241, 6240, 6243, 5321, 6250, 6268, 6246, 6260, 6263, 6264, 6266, 6272, 6269, 6271,
+    6270, 6273, 6274, 6277, 6279, 6280, 6283, 6284, 6285, 6287, 6288, 6289, 6286, 6290, 6294, 6296,
+    6299, 6300, 6301, 913, 6303, 6305, 6307, 6312, 6309, 6310, 6308, 6313, 6314, 6316, 6317, 6319,
+    6323, 6322, 6321, 6326, 6327, 6328, 6329, 6330, 6331, 6333, 6334, 6767, 6336, 4184, 6339, 6338,
+    6349, 6348, 6363, 6358, 6357, 6362, 6366, 6367, 6372, 6368, 6379, 6380, 6381, 6382, 6384, 6383,
+    6386, 6387, 6388, 6389, 6391, 6390, 6392, 6397, 6398, 6407, 6409, 6413, 6415, 6416, 6420, 6417,
+    6418, 6421, 6419, 6423, 6424, 6425, 6426, 6427, 6429, 6428, 6432, 6430, 6433, 6434, 6431, 6435,
+    6438, 6439, 6441, 6444, 6445, 6448, 6461, 6459, 6460, 6458, 6455, 6463, 6464, 6465, 6466, 6468,
+    6467, 6470, 6473, 6471, 6462, 6476, 6478, 6480, 6485, 6483, 6482, 6484, 6481, 6487, 6486, 6490,
+    6488, 6491, 6492, 6494, 6493, 6495, 6497, 6496, 6499, 6500, 6502, 6503, 6505, 6506, 6507, 6508,
+    6510, 6511, 6512, 6513, 6514, 6519, 6516, 6518, 6521, 6520, 6522, 6523, 6524, 6525, 6526, 6527,
+    6528, 6529, 6530, 6531, 6532, 6533, 6534, 6535, 6536, 6538, 6544, 6543, 6548, 6546, 6547, 6549,
+    6551, 6554, 6559, 6555, 6557, 6560, 6561, 6562, 6563, 6564, 6569, 6571, 6574, 6570, 6572, 6575,
+    6576, 6577, 6583, 6582, 6584, 6578, 6581, 6612, 6588, 6587, 6586, 6601, 6600, 6593, 6599, 6595,
+    6591, 6592, 6598, 6594, 6590, 6607, 6602, 6605, 6604, 6603, 6609, 6616, 6610, 6615, 6614, 6620,
+    6621, 6622, 6623, 6624, 6626, 6629, 6630, 6638, 6637, 6628, 6634, 6635, 6647, 6645, 6677, 6644,
+    6643, 6654, 6641, 6640, 6653, 6652, 6651, 6655, 6656, 6661, 6662, 6665, 6658, 6657, 6659, 6668,
+    6671, 6670, 6676, 6667, 6669, 6673, 6674, 6675, 6678, 6685, 6683, 6682, 6680, 6681, 6686, 6687,
+    6684, 6689, 6688, 6694, 6690, 6691, 6692, 6697, 6698, 6699, 6700, 6701, 6703, 6704, 6706, 6707,
+    6708, 6709, 6711, 6713, 6710, 6715, 6717, 6719, 6722, 6720, 6721, 6297, 6730, 6732, 6733, 6734,
+    6736, 6740, 6742, 6741, 6743, 6744, 6745, 6746, 6747, 6748, 6749, 6750, 6751, 6752, 6753, 6754,
+    6755, 3882, 6758, 6760, 6761, 6763, 6764, 6768, 6769, 6774, 6770, 6771, 6772, 6775, 6776, 6778,
+    6777, 6781, 6779, 6780, 6783, 6784, 6786, 1344, 2934, 5863, 3687, 813, 3505,
+];
@@ -14,44 +14,129 @@
 #![allow(clippy::module_name_repetitions)]
 
 pub mod byte_stream;
+pub mod decode;
+pub mod detect;
 pub mod encoding;
+pub mod error;
+pub mod format;
+pub mod galois;
+pub mod gs1;
+pub mod kanji;
+pub mod mask;
+pub mod matrix;
+pub mod micro;
+pub mod qr_code;
+pub mod reed_solomon;
+pub mod render;
+pub mod rmqr;
+pub mod segment;
+pub mod structured_append;
 
-#[derive(Debug, Clone, Copy)]
+pub use error::{DecodeError, EncodeError};
+pub use qr_code::QrCode;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ErrorCorrection {
     L,
     M,
     Q,
     H,
 }
+
+impl ErrorCorrection {
+    /// Every level, from the least to the most error correction
+    pub const ALL: [Self; 4] = [Self::L, Self::M, Self::Q, Self::H];
+    /// Returns the two bits used to denote this level in the format information
+    #[inline]
+    #[must_use]
+    pub const fn bits(self) -> u16 {
+        use ErrorCorrection::*;
+        match self {
+            L => 0b01,
+            M => 0b00,
+            Q => 0b11,
+            H => 0b10,
+        }
+    }
+}
 /// Denotes the alphabet used to encode
-///
-/// Kanji currently not added.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum EncodingMode {
     Numeric = 0b0001,
     Alphanumeric = 0b0010,
     Byte = 0b0100,
+    /// Double byte Shift JIS characters, see [`kanji`]
+    Kanji = 0b1000,
 }
 
 impl EncodingMode {
+    /// Returns the most compact mode able to encode the whole string
+    ///
+    /// Kanji mode is only chosen when every character is a Kanji,
+    /// mixing them with any other character falls back to byte mode.
     #[inline]
     #[must_use]
-    pub const fn analyze_string(s: &str) -> Self {
+    pub fn analyze_string(s: &str) -> Self {
         use EncodingMode::*;
         let mut mode = Numeric;
-        let mut i = 0;
-        while i < s.as_bytes().len() {
-            match s.as_bytes()[i] {
-                b'0'..=b'9' => (),
-                b'A'..=b'Z' | b' ' | b'$' | b'%' | b'*' | b'+' | b'-' | b'.' | b'/' | b':' => {
-                    mode = Alphanumeric;
-                }
+        for (i, c) in s.chars().enumerate() {
+            let next = match c {
+                '0'..='9' => Numeric,
+                'A'..='Z' | ' ' | '$' | '%' | '*' | '+' | '-' | '.' | '/' | ':' => Alphanumeric,
+                c if kanji::is_kanji(c) => Kanji,
                 _ => return Byte,
-            }
-            i += 1;
+            };
+            mode = match (mode, next) {
+                _ if i == 0 => next,
+                (Kanji, Kanji) => Kanji,
+                (Kanji, _) | (_, Kanji) => return Byte,
+                (Alphanumeric, _) | (_, Alphanumeric) => Alphanumeric,
+                _ => Numeric,
+            };
         }
         mode
     }
+    /// Returns the number of characters in the string as counted by this
+    /// mode, which is the number of bytes for [`EncodingMode::Byte`]
+    #[inline]
+    #[must_use]
+    pub fn character_count(self, s: &str) -> usize {
+        match self {
+            Self::Kanji => s.chars().count(),
+            Self::Numeric | Self::Alphanumeric | Self::Byte => s.len(),
+        }
+    }
+    /// Returns whether the character can be encoded in this mode
+    #[inline]
+    #[must_use]
+    pub fn can_encode(self, c: char) -> bool {
+        use EncodingMode::*;
+        match self {
+            Numeric => c.is_ascii_digit(),
+            Alphanumeric => matches!(
+                c,
+                '0'..='9' | 'A'..='Z' | ' ' | '$' | '%' | '*' | '+' | '-' | '.' | '/' | ':'
+            ),
+            Byte => true,
+            Kanji => kanji::is_kanji(c),
+        }
+    }
+    /// Checks that every character of the string can be encoded in this mode
+    ///
+    /// # Errors
+    ///
+    /// [`EncodeError::InvalidCharacterForMode`] for the first character
+    /// which cannot be encoded
+    pub fn check(self, s: &str) -> Result<(), EncodeError> {
+        match s.char_indices().find(|&(_, c)| !self.can_encode(c)) {
+            Some((index, character)) => Err(EncodeError::InvalidCharacterForMode {
+                mode: self,
+                character,
+                index,
+            }),
+            None => Ok(()),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -134,7 +219,7 @@ pub enum QRCodeVersion {
     V38,
     /// Total bytes: 3532
     V39,
-    /// Total bytes: 4706
+    /// Total bytes: 3706
     V40,
 }
 /// Designates the way a block is split up
@@ -163,6 +248,46 @@ pub enum GroupSplit {
     },
 }
 
+impl GroupSplit {
+    /// Returns the number of error correction bytes for every block
+    #[inline]
+    #[must_use]
+    pub const fn ec_bytes(&self) -> usize {
+        match self {
+            Self::One { ec_bytes, .. } | Self::Two { ec_bytes, .. } => *ec_bytes,
+        }
+    }
+    /// Returns the total number of blocks across all groups
+    #[inline]
+    #[must_use]
+    pub const fn blocks(&self) -> usize {
+        match self {
+            Self::One { block_split, .. } => block_split.blocks,
+            Self::Two {
+                block_split_one,
+                block_split_two,
+                ..
+            } => block_split_one.blocks + block_split_two.blocks,
+        }
+    }
+    /// Returns an iterator over the number of data bytes in each block,
+    /// in the order the blocks appear in the data
+    pub fn block_sizes(&self) -> impl Iterator<Item = usize> {
+        let (one, two) = match self {
+            Self::One { block_split, .. } => ((block_split.blocks, block_split.data_bytes), (0, 0)),
+            Self::Two {
+                block_split_one,
+                block_split_two,
+                ..
+            } => (
+                (block_split_one.blocks, block_split_one.data_bytes),
+                (block_split_two.blocks, block_split_two.data_bytes),
+            ),
+        };
+        std::iter::repeat_n(one.1, one.0).chain(std::iter::repeat_n(two.1, two.0))
+    }
+}
+
 macro_rules! impl_qr {
     (
         $(
@@ -252,8 +377,8 @@ macro_rules! impl_qr {
         }
     };
     (@impl group $ec_bytes: literal,
-        $data_bytes_one: literal, $blocks_one: literal,
-        $data_bytes_two: literal, $blocks_two: literal
+        $blocks_one: literal, $data_bytes_one: literal,
+        $blocks_two: literal, $data_bytes_two: literal
     ) => {
         Two {
             ec_bytes: $ec_bytes,
@@ -268,8 +393,8 @@ macro_rules! impl_qr {
         }
     };
     (@impl group $ec_bytes: literal,
-        $data_bytes: literal,
-        $blocks: literal
+        $blocks: literal,
+        $data_bytes: literal
     ) => {
         One {
             ec_bytes: $ec_bytes,
@@ -288,6 +413,10 @@ macro_rules! impl_qr {
             $h: literal
         ),+
     ) => {
+        /// Every version, from smallest to largest
+        pub const ALL: &'static [Self] = &[$( QRCodeVersion::$version ),+];
+        /// Returns the number of data codewords for the given
+        /// [error correction](ErrorCorrection)
         #[inline]
         #[must_use]
         pub const fn data_size(self, ec: ErrorCorrection) -> usize {
@@ -325,6 +454,95 @@ macro_rules! impl_qr {
     };
 }
 impl QRCodeVersion {
+    /// Returns the number of zero bits appended after the final codeword
+    #[inline]
+    #[must_use]
+    pub const fn remainder_bits(self) -> u16 {
+        use QRCodeVersion::*;
+        match self {
+            V2 | V3 | V4 | V5 | V6 => 7,
+            V14 | V15 | V16 | V17 | V18 | V19 | V20 | V28 | V29 | V30 | V31 | V32 | V33 | V34 => 3,
+            V21 | V22 | V23 | V24 | V25 | V26 | V27 => 4,
+            V1 | V7 | V8 | V9 | V10 | V11 | V12 | V13 | V35 | V36 | V37 | V38 | V39 | V40 => 0,
+        }
+    }
+    /// Returns the version number, from `1` to `40`
+    #[inline]
+    #[must_use]
+    pub const fn number(self) -> usize {
+        self as usize + 1
+    }
+    /// Returns the version with the given number, from `1` to `40`
+    #[inline]
+    #[must_use]
+    pub const fn from_number(number: usize) -> Option<Self> {
+        if number >= 1 && number <= Self::ALL.len() {
+            Some(Self::ALL[number - 1])
+        } else {
+            None
+        }
+    }
+    /// Returns the width & height of the symbol in modules
+    #[inline]
+    #[must_use]
+    pub const fn size(self) -> usize {
+        17 + 4 * self.number()
+    }
+    /// Returns the row & column coordinates of the alignment pattern centers
+    #[inline]
+    #[must_use]
+    pub const fn alignment_positions(self) -> &'static [usize] {
+        use QRCodeVersion::*;
+        match self {
+            V1 => &[],
+            V2 => &[6, 18],
+            V3 => &[6, 22],
+            V4 => &[6, 26],
+            V5 => &[6, 30],
+            V6 => &[6, 34],
+            V7 => &[6, 22, 38],
+            V8 => &[6, 24, 42],
+            V9 => &[6, 26, 46],
+            V10 => &[6, 28, 50],
+            V11 => &[6, 30, 54],
+            V12 => &[6, 32, 58],
+            V13 => &[6, 34, 62],
+            V14 => &[6, 26, 46, 66],
+            V15 => &[6, 26, 48, 70],
+            V16 => &[6, 26, 50, 74],
+            V17 => &[6, 30, 54, 78],
+            V18 => &[6, 30, 56, 82],
+            V19 => &[6, 30, 58, 86],
+            V20 => &[6, 34, 62, 90],
+            V21 => &[6, 28, 50, 72, 94],
+            V22 => &[6, 26, 50, 74, 98],
+            V23 => &[6, 30, 54, 78, 102],
+            V24 => &[6, 28, 54, 80, 106],
+            V25 => &[6, 32, 58, 84, 110],
+            V26 => &[6, 30, 58, 86, 114],
+            V27 => &[6, 34, 62, 90, 118],
+            V28 => &[6, 26, 50, 74, 98, 122],
+            V29 => &[6, 30, 54, 78, 102, 126],
+            V30 => &[6, 26, 52, 78, 104, 130],
+            V31 => &[6, 30, 56, 82, 108, 134],
+            V32 => &[6, 34, 60, 86, 112, 138],
+            V33 => &[6, 30, 58, 86, 114, 142],
+            V34 => &[6, 34, 62, 90, 118, 146],
+            V35 => &[6, 30, 54, 78, 102, 126, 150],
+            V36 => &[6, 24, 50, 76, 102, 128, 154],
+            V37 => &[6, 28, 54, 80, 106, 132, 158],
+            V38 => &[6, 32, 58, 84, 110, 136, 162],
+            V39 => &[6, 26, 54, 82, 110, 138, 166],
+            V40 => &[6, 30, 58, 86, 114, 142, 170],
+        }
+    }
+    /// Returns the total number of codewords, data and error correction
+    #[inline]
+    #[must_use]
+    pub const fn total_codewords(self) -> usize {
+        let split = self.split(ErrorCorrection::L);
+        self.data_size(ErrorCorrection::L) + split.ec_bytes() * split.blocks()
+    }
     // Could make this an enum instead?
     impl_qr![
         (
@@ -609,4 +827,3 @@ impl QRCodeVersion {
         )
     ];
 }
- 
\ No newline at end of file
@@ -0,0 +1,194 @@
+//! Data mask patterns and the penalty scoring used to choose between them
+use crate::{matrix::QrMatrix, ErrorCorrection};
+
+#[cfg(test)]
+mod test;
+
+/// The eight data mask patterns
+///
+/// A mask flips every data module at `(x, y)` for which its condition holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Mask {
+    /// `(x + y) % 2 == 0`
+    M0 = 0b000,
+    /// `y % 2 == 0`
+    M1 = 0b001,
+    /// `x % 3 == 0`
+    M2 = 0b010,
+    /// `(x + y) % 3 == 0`
+    M3 = 0b011,
+    /// `(y / 2 + x / 3) % 2 == 0`
+    M4 = 0b100,
+    /// `(x * y) % 2 + (x * y) % 3 == 0`
+    M5 = 0b101,
+    /// `((x * y) % 2 + (x * y) % 3) % 2 == 0`
+    M6 = 0b110,
+    /// `((x + y) % 2 + (x * y) % 3) % 2 == 0`
+    M7 = 0b111,
+}
+
+impl Mask {
+    /// Every mask, ordered by their reference
+    pub const ALL: [Self; 8] = [
+        Self::M0,
+        Self::M1,
+        Self::M2,
+        Self::M3,
+        Self::M4,
+        Self::M5,
+        Self::M6,
+        Self::M7,
+    ];
+    /// Returns whether the module at `(x, y)` should be flipped
+    #[inline]
+    #[must_use]
+    pub const fn applies(self, x: usize, y: usize) -> bool {
+        use Mask::*;
+        match self {
+            M0 => (x + y).is_multiple_of(2),
+            M1 => y.is_multiple_of(2),
+            M2 => x.is_multiple_of(3),
+            M3 => (x + y).is_multiple_of(3),
+            M4 => (y / 2 + x / 3).is_multiple_of(2),
+            M5 => (x * y) % 2 + (x * y) % 3 == 0,
+            M6 => ((x * y) % 2 + (x * y) % 3).is_multiple_of(2),
+            M7 => ((x + y) % 2 + (x * y) % 3).is_multiple_of(2),
+        }
+    }
+}
+
+/// The penalty points of a symbol, split up by rule
+///
+/// A lower [total](Penalty::total) is better.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Penalty {
+    /// Rows & columns of five or more same colored modules,
+    /// `3 + (length - 5)` points each
+    pub runs: u32,
+    /// 2x2 blocks of same colored modules, `3` points each
+    pub blocks: u32,
+    /// `1:1:3:1:1` patterns preceded or followed by four light modules,
+    /// `40` points each
+    pub finder_like: u32,
+    /// `10` points for every 5% the proportion of dark modules
+    /// deviates from 50%
+    pub balance: u32,
+}
+
+impl Penalty {
+    /// Scores the given matrix according to the four penalty rules
+    #[must_use]
+    pub fn of(matrix: &QrMatrix) -> Self {
+        let size = matrix.size();
+        let mut penalty = Self::default();
+        let mut column = Vec::with_capacity(size);
+
+        for line in matrix.rows() {
+            penalty.score_line(line);
+        }
+        for x in 0..size {
+            column.clear();
+            column.extend((0..size).map(|y| matrix.get(x, y)));
+            penalty.score_line(&column);
+        }
+
+        let modules = matrix.modules();
+        for y in 0..size - 1 {
+            let (top, bottom) = (&modules[y * size..], &modules[(y + 1) * size..]);
+            let same = (0..size - 1)
+                .filter(|&x| top[x] == top[x + 1] && top[x] == bottom[x] && top[x] == bottom[x + 1])
+                .count();
+            penalty.blocks += 3 * count(same);
+        }
+
+        let total = modules.len();
+        let dark = modules.iter().filter(|&&m| m).count();
+        penalty.balance = 10 * count((dark * 20).abs_diff(total * 10) / total);
+        penalty
+    }
+    /// Returns the sum of every rule's points
+    #[inline]
+    #[must_use]
+    pub const fn total(&self) -> u32 {
+        self.runs + self.blocks + self.finder_like + self.balance
+    }
+    fn score_line(&mut self, line: &[bool]) {
+        const FINDER: [bool; 7] = [true, false, true, true, true, false, true];
+
+        let mut run = 0;
+        for (i, &module) in line.iter().enumerate() {
+            if i > 0 && module == line[i - 1] {
+                run += 1;
+            } else {
+                run = 1;
+            }
+            match run {
+                5 => self.runs += 3,
+                6.. => self.runs += 1,
+                _ => (),
+            }
+        }
+        // modules outside of the symbol belong to the light quiet zone
+        let light = |range: std::ops::Range<usize>| range.filter_map(|i| line.get(i)).all(|&m| !m);
+        for start in 0..line.len().saturating_sub(6) {
+            if line[start..start + 7] != FINDER {
+                continue;
+            }
+            if light(start.saturating_sub(4)..start) {
+                self.finder_like += 40;
+            }
+            if light(start + 7..start + 11) {
+                self.finder_like += 40;
+            }
+        }
+    }
+}
+
+/// Returns the [`Penalty`] of the matrix under every mask, ordered by
+/// their reference
+///
+/// The given matrix should hold unmasked data. Each candidate is scored
+/// with its [format information](QrMatrix::place_format) in place.
+#[must_use]
+pub fn penalties(matrix: &QrMatrix, ec: ErrorCorrection) -> [Penalty; 8] {
+    Mask::ALL.map(|mask| {
+        let mut masked = matrix.clone();
+        masked.apply_mask(mask);
+        masked.place_format(ec, mask);
+        Penalty::of(&masked)
+    })
+}
+
+/// Returns the mask with the lowest total [`Penalty`],
+/// preferring the lower reference on ties
+///
+/// The given matrix should hold unmasked data.
+#[must_use]
+pub fn best_mask(matrix: &QrMatrix, ec: ErrorCorrection) -> Mask {
+    let penalties = penalties(matrix, ec);
+    Mask::ALL
+        .into_iter()
+        .min_by_key(|&mask| penalties[mask as usize].total())
+        .unwrap_or(Mask::M0)
+}
+
+#[inline]
+fn count(n: usize) -> u32 {
+    u32::try_from(n).unwrap_or(u32::MAX)
+}
+
+impl QrMatrix {
+    /// Flips every data module the mask applies to
+    ///
+    /// Applying the same mask twice undoes it.
+    pub fn apply_mask(&mut self, mask: Mask) {
+        let size = self.size();
+        for y in 0..size {
+            for x in 0..size {
+                if !self.is_reserved(x, y) && mask.applies(x, y) {
+                    self.toggle(x, y);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{
+    encoding::{interleave, Encodable},
+    mask::{best_mask, penalties, Mask, Penalty},
+    matrix::QrMatrix,
+    ErrorCorrection, QRCodeVersion,
+};
+
+fn line(s: &str) -> Vec<bool> {
+    s.chars().map(|c| c == '#').collect()
+}
+
+fn hello_world() -> QrMatrix {
+    let (data, _) = "HELLO WORLD"
+        .create_bits(QRCodeVersion::V1, ErrorCorrection::Q)
+        .unwrap();
+    let mut matrix = QrMatrix::new(QRCodeVersion::V1);
+    matrix.place_data(&interleave(&data, QRCodeVersion::V1, ErrorCorrection::Q));
+    matrix
+}
+
+#[test]
+fn runs() {
+    for (s, points) in [
+        ("#.#.#.#.", 0),
+        ("....#", 0),
+        (".....#", 3),
+        ("#######.", 5),
+        ("#####.....", 6),
+    ] {
+        let mut penalty = Penalty::default();
+        penalty.score_line(&line(s));
+        assert_eq!(penalty.runs, points, "{s}");
+    }
+}
+
+#[test]
+fn finder_like() {
+    for (s, points) in [
+        // the edges of the symbol count as light
+        ("#.###.#", 80),
+        ("....#.###.#....", 80),
+        ("#...#.###.#....", 40),
+        ("#...#.###.#...#", 0),
+        ("..#.#.###.#..#", 0),
+    ] {
+        let mut penalty = Penalty::default();
+        penalty.score_line(&line(s));
+        assert_eq!(penalty.finder_like, points, "{s}");
+    }
+}
+
+#[test]
+fn masks() {
+    assert!(Mask::M0.applies(0, 0));
+    assert!(!Mask::M0.applies(1, 0));
+    assert!(Mask::M1.applies(5, 2));
+    assert!(Mask::M2.applies(3, 1));
+    assert!(!Mask::M2.applies(1, 3));
+    assert!(Mask::M4.applies(3, 2));
+    assert!(!Mask::M4.applies(2, 3));
+
+    let matrix = hello_world();
+    for mask in Mask::ALL {
+        let mut masked = matrix.clone();
+        masked.apply_mask(mask);
+        assert_ne!(masked, matrix);
+        let size = matrix.size();
+        for (x, y) in (0..size).flat_map(|y| (0..size).map(move |x| (x, y))) {
+            if matrix.is_reserved(x, y) {
+                assert_eq!(masked.get(x, y), matrix.get(x, y));
+            }
+        }
+        masked.apply_mask(mask);
+        assert_eq!(masked, matrix);
+    }
+}
+
+#[test]
+fn selection() {
+    let matrix = hello_world();
+    let penalties = penalties(&matrix, ErrorCorrection::Q);
+    let best = best_mask(&matrix, ErrorCorrection::Q);
+    assert!(penalties
+        .iter()
+        .all(|p| p.total() >= penalties[best as usize].total()));
+
+    let mut masked = matrix;
+    masked.apply_mask(best);
+    masked.place_format(ErrorCorrection::Q, best);
+    assert_eq!(Penalty::of(&masked), penalties[best as usize]);
+}
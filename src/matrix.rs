@@ -0,0 +1,215 @@
+//! The grid of modules making up a QR Code symbol
+use crate::{byte_stream::Bytes, QRCodeVersion};
+
+#[cfg(test)]
+mod test;
+
+/// A square grid of modules, along with which of them are reserved
+/// for function patterns and format/version information
+///
+/// Coordinates are given as `(x, y)`, i.e. `(column, row)`, with the
+/// origin in the top left corner. A dark module is `true`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QrMatrix {
+    version: QRCodeVersion,
+    size: usize,
+    modules: Vec<bool>,
+    reserved: Vec<bool>,
+}
+
+impl QrMatrix {
+    /// Creates a matrix for the given version with every function pattern
+    /// placed, and the format & version information areas reserved
+    #[must_use]
+    pub fn new(version: QRCodeVersion) -> Self {
+        let size = version.size();
+        let mut matrix = Self {
+            version,
+            size,
+            modules: vec![false; size * size],
+            reserved: vec![false; size * size],
+        };
+        matrix.place_timing();
+        matrix.place_finder(3, 3);
+        matrix.place_finder(size - 4, 3);
+        matrix.place_finder(3, size - 4);
+        matrix.place_alignment();
+        matrix.reserve_format();
+        matrix.place_version();
+        // the dark module
+        matrix.set_function(8, size - 8, true);
+        matrix
+    }
+    /// Returns the version this matrix was created for
+    #[inline]
+    #[must_use]
+    pub const fn version(&self) -> QRCodeVersion {
+        self.version
+    }
+    /// Returns the width & height in modules
+    #[inline]
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+    /// Returns whether the module at `(x, y)` is dark
+    ///
+    /// # Panics
+    ///
+    /// If the coordinates are out of bounds
+    #[inline]
+    #[must_use]
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        self.modules[self.index(x, y)]
+    }
+    /// Sets the module at `(x, y)`
+    ///
+    /// # Panics
+    ///
+    /// If the coordinates are out of bounds
+    #[inline]
+    pub fn set(&mut self, x: usize, y: usize, dark: bool) {
+        let i = self.index(x, y);
+        self.modules[i] = dark;
+    }
+    /// Flips the module at `(x, y)`
+    ///
+    /// # Panics
+    ///
+    /// If the coordinates are out of bounds
+    #[inline]
+    pub fn toggle(&mut self, x: usize, y: usize) {
+        let i = self.index(x, y);
+        self.modules[i] = !self.modules[i];
+    }
+    /// Returns whether the module at `(x, y)` is part of a function pattern
+    /// or the format/version information, meaning it may not hold data
+    ///
+    /// # Panics
+    ///
+    /// If the coordinates are out of bounds
+    #[inline]
+    #[must_use]
+    pub fn is_reserved(&self, x: usize, y: usize) -> bool {
+        self.reserved[self.index(x, y)]
+    }
+    /// Returns the modules row by row
+    #[inline]
+    #[must_use]
+    pub fn modules(&self) -> &[bool] {
+        &self.modules
+    }
+    /// Returns an iterator over the rows of modules
+    pub fn rows(&self) -> impl Iterator<Item = &[bool]> {
+        self.modules.chunks(self.size)
+    }
+    /// Returns the coordinates of every data module in placement order
+    ///
+    /// Starting in the bottom right corner, columns are walked in pairs,
+    /// alternating between upwards and downwards, right module first.
+    /// The vertical timing pattern is skipped over entirely, as is every
+    /// [reserved](QrMatrix::is_reserved) module.
+    #[must_use]
+    pub fn data_positions(&self) -> Vec<(usize, usize)> {
+        let mut positions = Vec::with_capacity(self.size * self.size);
+        let mut right = self.size - 1;
+        let mut upward = true;
+        loop {
+            if right == 6 {
+                right = 5;
+            }
+            for vertical in 0..self.size {
+                let y = if upward {
+                    self.size - 1 - vertical
+                } else {
+                    vertical
+                };
+                for x in [right, right - 1] {
+                    if !self.is_reserved(x, y) {
+                        positions.push((x, y));
+                    }
+                }
+            }
+            upward = !upward;
+            let Some(next) = right.checked_sub(2) else {
+                break;
+            };
+            right = next;
+        }
+        positions
+    }
+    /// Writes the codewords into the data modules, most significant bit
+    /// first, in the order given by [`QrMatrix::data_positions`]
+    ///
+    /// `codewords` is expected to be the output of
+    /// [`interleave`](crate::encoding::interleave), remainder bits included.
+    /// Any data modules left over are set to light.
+    pub fn place_data(&mut self, codewords: &Bytes) {
+        let bits = codewords.bit_len();
+        for (i, (x, y)) in self.data_positions().into_iter().enumerate() {
+            let dark = i < bits && codewords[i / 8] >> (7 - i % 8) & 1 == 1;
+            self.set(x, y, dark);
+        }
+    }
+    /// Sets a module, marking it as reserved
+    pub(crate) fn set_function(&mut self, x: usize, y: usize, dark: bool) {
+        let i = self.index(x, y);
+        self.modules[i] = dark;
+        self.reserved[i] = true;
+    }
+    /// Marks a module as reserved without changing it
+    fn reserve(&mut self, x: usize, y: usize) {
+        let i = self.index(x, y);
+        self.reserved[i] = true;
+    }
+    #[inline]
+    const fn index(&self, x: usize, y: usize) -> usize {
+        assert!(x < self.size && y < self.size, "module out of bounds");
+        y * self.size + x
+    }
+    /// Places the finder pattern centered on `(cx, cy)`, along with its separator
+    fn place_finder(&mut self, cx: usize, cy: usize) {
+        for y in cy.saturating_sub(4)..=(cy + 4).min(self.size - 1) {
+            for x in cx.saturating_sub(4)..=(cx + 4).min(self.size - 1) {
+                let ring = x.abs_diff(cx).max(y.abs_diff(cy));
+                self.set_function(x, y, ring != 2 && ring != 4);
+            }
+        }
+    }
+    fn place_timing(&mut self) {
+        for i in 8..self.size - 8 {
+            self.set_function(i, 6, i % 2 == 0);
+            self.set_function(6, i, i % 2 == 0);
+        }
+    }
+    fn place_alignment(&mut self) {
+        let positions = self.version.alignment_positions();
+        let Some(&last) = positions.last() else {
+            return;
+        };
+        for &cy in positions {
+            for &cx in positions {
+                // skip those overlapping the finder patterns
+                if (cx, cy) == (6, 6) || (cx, cy) == (last, 6) || (cx, cy) == (6, last) {
+                    continue;
+                }
+                for y in cy - 2..=cy + 2 {
+                    for x in cx - 2..=cx + 2 {
+                        let ring = x.abs_diff(cx).max(y.abs_diff(cy));
+                        self.set_function(x, y, ring != 1);
+                    }
+                }
+            }
+        }
+    }
+    fn reserve_format(&mut self) {
+        for i in 0..9 {
+            self.reserve(i, 8);
+            self.reserve(8, i);
+        }
+        for i in 0..8 {
+            self.reserve(self.size - 1 - i, 8);
+            self.reserve(8, self.size - 1 - i);
+        }
+    }
+}
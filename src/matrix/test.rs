@@ -0,0 +1,107 @@
+use crate::{matrix::QrMatrix, QRCodeVersion};
+
+#[test]
+fn data_capacity() {
+    for &version in QRCodeVersion::ALL {
+        let matrix = QrMatrix::new(version);
+        let size = matrix.size();
+        let free = (0..size)
+            .flat_map(|y| (0..size).map(move |x| (x, y)))
+            .filter(|&(x, y)| !matrix.is_reserved(x, y))
+            .count();
+        assert_eq!(
+            free,
+            version.total_codewords() * 8 + version.remainder_bits() as usize,
+            "{version:?}"
+        );
+    }
+}
+
+#[test]
+fn function_patterns() {
+    let matrix = QrMatrix::new(QRCodeVersion::V2);
+    assert_eq!(matrix.size(), 25);
+
+    let finder = [
+        "#######.", "#.....#.", "#.###.#.", "#.###.#.", "#.###.#.", "#.....#.", "#######.",
+        "........",
+    ];
+    for (y, row) in finder.iter().enumerate() {
+        for (x, c) in row.chars().enumerate() {
+            let dark = c == '#';
+            assert_eq!(matrix.get(x, y), dark, "top left ({x}, {y})");
+            assert_eq!(matrix.get(24 - x, y), dark, "top right ({x}, {y})");
+            assert_eq!(matrix.get(x, 24 - y), dark, "bottom left ({x}, {y})");
+        }
+    }
+    // timing patterns
+    for i in 8..17 {
+        assert_eq!(matrix.get(i, 6), i % 2 == 0);
+        assert_eq!(matrix.get(6, i), i % 2 == 0);
+    }
+    // alignment pattern centered on (18, 18)
+    assert!(matrix.get(18, 18));
+    assert!(!matrix.get(17, 18));
+    assert!(matrix.get(16, 16));
+    assert!(matrix.is_reserved(20, 20));
+    assert!(!matrix.is_reserved(21, 21));
+    // dark module
+    assert!(matrix.get(8, 17));
+}
+
+#[test]
+fn zig_zag() {
+    let matrix = QrMatrix::new(QRCodeVersion::V1);
+    let positions = matrix.data_positions();
+    assert_eq!(positions.len(), 26 * 8);
+    // first codeword, upwards in the rightmost column pair
+    assert_eq!(
+        positions[..8],
+        [
+            (20, 20),
+            (19, 20),
+            (20, 19),
+            (19, 19),
+            (20, 18),
+            (19, 18),
+            (20, 17),
+            (19, 17)
+        ]
+    );
+    // turning at the format information, then heading downwards
+    assert_eq!(positions[23], (19, 9));
+    assert_eq!(positions[24], (18, 9));
+    assert_eq!(positions[25], (17, 9));
+    // the vertical timing pattern is skipped
+    assert!(positions.iter().all(|&(x, _)| x != 6));
+    let last = positions.last().copied();
+    assert_eq!(last, Some((0, 12)));
+}
+
+#[test]
+fn place_data() {
+    use crate::{encoding::interleave, encoding::Encodable, ErrorCorrection};
+
+    let (data, _) = "01234567"
+        .create_bits(QRCodeVersion::V1, ErrorCorrection::M)
+        .unwrap();
+    let codewords = interleave(&data, QRCodeVersion::V1, ErrorCorrection::M);
+    let mut matrix = QrMatrix::new(QRCodeVersion::V1);
+    matrix.place_data(&codewords);
+
+    let bits: Vec<bool> = matrix
+        .data_positions()
+        .into_iter()
+        .map(|(x, y)| matrix.get(x, y))
+        .collect();
+    let expected: Vec<bool> = codewords
+        .iter()
+        .flat_map(|&b| (0..8).rev().map(move |i| b >> i & 1 == 1))
+        .collect();
+    assert_eq!(bits, expected);
+    // 0x10
+    assert_eq!(
+        bits[..8],
+        [false, false, false, true, false, false, false, false]
+    );
+}
@@ -0,0 +1,716 @@
+//! Micro QR Code, the M1 to M4 symbols with a single finder pattern
+use crate::{
+    byte_stream::Bytes,
+    encoding::data_bit_length,
+    format::{bch_remainder, FORMAT_GENERATOR},
+    mask::Mask,
+    reed_solomon,
+    segment::{optimize_with, Segment},
+    EncodeError, EncodingMode, ErrorCorrection,
+};
+
+#[cfg(test)]
+mod test;
+
+/// Applied to the format information so that it is never all zero
+const FORMAT_MASK: u16 = 0b100_0100_0100_0101;
+
+/// The four Micro QR Code versions
+///
+/// [`MicroVersion::M1`] only detects errors, it is used with
+/// [`ErrorCorrection::L`]. No version supports [`ErrorCorrection::H`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MicroVersion {
+    /// Total bytes: 5, numeric only
+    M1,
+    /// Total bytes: 10, numeric & alphanumeric only
+    M2,
+    /// Total bytes: 17
+    M3,
+    /// Total bytes: 24
+    M4,
+}
+
+impl MicroVersion {
+    /// Every version, from smallest to largest
+    pub const ALL: [Self; 4] = [Self::M1, Self::M2, Self::M3, Self::M4];
+
+    /// Returns the version number, from `1` to `4`
+    #[inline]
+    #[must_use]
+    pub const fn number(self) -> usize {
+        self as usize + 1
+    }
+    /// Returns the width & height of the symbol in modules
+    #[inline]
+    #[must_use]
+    pub const fn size(self) -> usize {
+        9 + 2 * self.number()
+    }
+    /// Returns the total number of codewords, data & error correction
+    ///
+    /// The final data codeword of [`MicroVersion::M1`] and
+    /// [`MicroVersion::M3`] is only four bits long.
+    #[inline]
+    #[must_use]
+    pub const fn total_codewords(self) -> usize {
+        use MicroVersion::*;
+        match self {
+            M1 => 5,
+            M2 => 10,
+            M3 => 17,
+            M4 => 24,
+        }
+    }
+    /// Returns the number of data bits and error correction codewords for
+    /// the given [error correction](ErrorCorrection), if supported
+    #[inline]
+    #[must_use]
+    const fn capacity_table(self, ec: ErrorCorrection) -> Option<(usize, usize)> {
+        use ErrorCorrection::*;
+        use MicroVersion::*;
+        Some(match (self, ec) {
+            (M1, L) => (20, 2),
+            (M2, L) => (40, 5),
+            (M2, M) => (32, 6),
+            (M3, L) => (84, 6),
+            (M3, M) => (68, 8),
+            (M4, L) => (128, 8),
+            (M4, M) => (112, 10),
+            (M4, Q) => (80, 14),
+            _ => return None,
+        })
+    }
+    /// Returns whether the version supports the given
+    /// [error correction](ErrorCorrection)
+    #[inline]
+    #[must_use]
+    pub const fn supports(self, ec: ErrorCorrection) -> bool {
+        self.capacity_table(ec).is_some()
+    }
+    /// Returns the number of data bits for the given
+    /// [error correction](ErrorCorrection)
+    #[inline]
+    #[must_use]
+    pub const fn data_bits(self, ec: ErrorCorrection) -> Option<usize> {
+        match self.capacity_table(ec) {
+            Some((bits, _)) => Some(bits),
+            None => None,
+        }
+    }
+    /// Returns the number of data codewords for the given
+    /// [error correction](ErrorCorrection), counting a final four bit
+    /// codeword as a whole one
+    #[inline]
+    #[must_use]
+    pub const fn data_size(self, ec: ErrorCorrection) -> Option<usize> {
+        match self.capacity_table(ec) {
+            Some((bits, _)) => Some(bits.div_ceil(8)),
+            None => None,
+        }
+    }
+    /// Returns the number of error correction codewords for the given
+    /// [error correction](ErrorCorrection)
+    #[inline]
+    #[must_use]
+    pub const fn ec_bytes(self, ec: ErrorCorrection) -> Option<usize> {
+        match self.capacity_table(ec) {
+            Some((_, ec_bytes)) => Some(ec_bytes),
+            None => None,
+        }
+    }
+    /// Returns the width of the mode indicator, which is absent in
+    /// [`MicroVersion::M1`]
+    #[inline]
+    #[must_use]
+    pub const fn mode_bits(self) -> u16 {
+        self as u16
+    }
+    /// Returns the value of the mode indicator, if the mode is supported
+    #[inline]
+    #[must_use]
+    pub const fn mode_indicator(self, mode: EncodingMode) -> Option<u16> {
+        if self.count_bits(mode).is_none() {
+            return None;
+        }
+        Some(match mode {
+            EncodingMode::Numeric => 0,
+            EncodingMode::Alphanumeric => 1,
+            EncodingMode::Byte => 0b10,
+            EncodingMode::Kanji => 0b11,
+        })
+    }
+    /// Returns the width of the character count, if the mode is supported
+    #[inline]
+    #[must_use]
+    pub const fn count_bits(self, mode: EncodingMode) -> Option<u16> {
+        use EncodingMode::*;
+        let number = self as u16 + 1;
+        // M1 is numeric only, M2 adds alphanumeric and M3 every other mode
+        Some(match mode {
+            Numeric => number + 2,
+            Alphanumeric if number >= 2 => number + 1,
+            Byte if number >= 3 => number + 1,
+            Kanji if number >= 3 => number,
+            _ => return None,
+        })
+    }
+    /// Returns the width of the terminator, which is shortened when the
+    /// data leaves less room
+    #[inline]
+    #[must_use]
+    pub const fn terminator_bits(self) -> u16 {
+        3 + 2 * self.mode_bits()
+    }
+    /// Returns the number of characters of the given mode the version can
+    /// hold in a single segment
+    #[must_use]
+    pub const fn capacity(self, ec: ErrorCorrection, mode: EncodingMode) -> Option<usize> {
+        let (Some(bits), Some(count_bits)) = (self.data_bits(ec), self.count_bits(mode)) else {
+            return None;
+        };
+        let available = bits - self.mode_bits() as usize - count_bits as usize;
+        let mut count = 0;
+        while data_bit_length(mode, count + 1) <= available {
+            count += 1;
+        }
+        Some(count)
+    }
+    /// Returns the three bit symbol number written in the format
+    /// information, if the [error correction](ErrorCorrection) is supported
+    #[inline]
+    #[must_use]
+    pub const fn symbol_number(self, ec: ErrorCorrection) -> Option<u16> {
+        use ErrorCorrection::*;
+        use MicroVersion::*;
+        Some(match (self, ec) {
+            (M1, L) => 0,
+            (M2, L) => 1,
+            (M2, M) => 2,
+            (M3, L) => 3,
+            (M3, M) => 4,
+            (M4, L) => 5,
+            (M4, M) => 6,
+            (M4, Q) => 7,
+            _ => return None,
+        })
+    }
+}
+
+/// The four data mask patterns of Micro QR Code, a subset of the [`Mask`]s
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MicroMask {
+    /// [`Mask::M1`], `y % 2 == 0`
+    M0 = 0b00,
+    /// [`Mask::M4`], `(y / 2 + x / 3) % 2 == 0`
+    M1 = 0b01,
+    /// [`Mask::M6`], `((x * y) % 2 + (x * y) % 3) % 2 == 0`
+    M2 = 0b10,
+    /// [`Mask::M7`], `((x + y) % 2 + (x * y) % 3) % 2 == 0`
+    M3 = 0b11,
+}
+
+impl MicroMask {
+    /// Every mask, ordered by their reference
+    pub const ALL: [Self; 4] = [Self::M0, Self::M1, Self::M2, Self::M3];
+
+    /// Returns the QR Code mask with the same pattern
+    #[inline]
+    #[must_use]
+    pub const fn pattern(self) -> Mask {
+        match self {
+            Self::M0 => Mask::M1,
+            Self::M1 => Mask::M4,
+            Self::M2 => Mask::M6,
+            Self::M3 => Mask::M7,
+        }
+    }
+    /// Returns whether the module at `(x, y)` should be flipped
+    #[inline]
+    #[must_use]
+    pub const fn applies(self, x: usize, y: usize) -> bool {
+        self.pattern().applies(x, y)
+    }
+}
+
+/// Returns the 15 bit format information for the given version,
+/// [error correction](ErrorCorrection) and [mask](MicroMask), if the
+/// version supports the error correction
+///
+/// The three bit symbol number and two bit mask are followed by ten BCH
+/// error correction bits, and the result is masked with
+/// `0b100010001000101`.
+#[inline]
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn format_bits(
+    version: MicroVersion,
+    ec: ErrorCorrection,
+    mask: MicroMask,
+) -> Option<u16> {
+    let Some(symbol) = version.symbol_number(ec) else {
+        return None;
+    };
+    let data = (symbol << 2 | mask as u16) as u32;
+    Some(((data << 10 | bch_remainder(data, FORMAT_GENERATOR, 10)) as u16) ^ FORMAT_MASK)
+}
+
+/// Returns the score of a masked symbol, higher being better
+///
+/// With `sum1` and `sum2` the number of dark modules along the right and
+/// bottom edges, timing patterns excluded, the score is
+/// `min * 16 + max` of the two.
+#[must_use]
+pub fn score(matrix: &MicroMatrix) -> usize {
+    let last = matrix.size() - 1;
+    let right = (1..=last).filter(|&y| matrix.get(last, y)).count();
+    let bottom = (1..=last).filter(|&x| matrix.get(x, last)).count();
+    right.min(bottom) * 16 + right.max(bottom)
+}
+
+/// Returns the mask with the highest [score], preferring the lower
+/// reference on ties
+///
+/// The given matrix should hold unmasked data.
+#[must_use]
+pub fn best_mask(matrix: &MicroMatrix) -> MicroMask {
+    let mut best = (MicroMask::M0, 0);
+    for mask in MicroMask::ALL {
+        let mut masked = matrix.clone();
+        masked.apply_mask(mask);
+        let score = score(&masked);
+        if score > best.1 {
+            best = (mask, score);
+        }
+    }
+    best.0
+}
+
+/// Splits the string into the segments which produce the shortest
+/// bitstream for the given version, like [`segment::optimize`](crate::segment::optimize)
+///
+/// Returns `None` if a character cannot be encoded in any mode the
+/// version supports.
+#[must_use]
+pub fn optimize(s: &str, version: MicroVersion) -> Option<Vec<Segment>> {
+    let head_bits = [
+        EncodingMode::Numeric,
+        EncodingMode::Alphanumeric,
+        EncodingMode::Byte,
+        EncodingMode::Kanji,
+    ]
+    .map(|mode| {
+        version
+            .count_bits(mode)
+            .map(|bits| (version.mode_bits() + bits) as usize)
+    });
+    optimize_with(s, &head_bits, false)
+}
+
+/// Returns the number of bits needed to encode the segments in the
+/// given version, if it supports every mode
+#[must_use]
+pub fn bit_length(segments: &[Segment], version: MicroVersion) -> Option<usize> {
+    segments
+        .iter()
+        .map(|s| {
+            version.count_bits(s.mode).map(|bits| {
+                (version.mode_bits() + bits) as usize + data_bit_length(s.mode, s.character_count())
+            })
+        })
+        .sum()
+}
+
+/// Creates the data codewords for the given segments
+///
+/// The terminator is shortened or left out if the data nearly fills the
+/// symbol, and the final four bit codeword of [`MicroVersion::M1`] and
+/// [`MicroVersion::M3`] is padded with zeros rather than a pad codeword.
+/// That codeword is returned in the high nibble of the last byte.
+///
+/// # Errors
+///
+/// - [`EncodeError::UnsupportedMicroErrorCorrection`] if the version does
+///   not support the error correction
+/// - [`EncodeError::UnsupportedMicroMode`] if a segment's mode is not
+///   supported by the version
+/// - [`EncodeError::EciInMicro`] if a segment has an ECI, which Micro QR
+///   Code cannot express
+/// - [`EncodeError::InvalidCharacterForMode`] if a segment holds data its
+///   mode cannot encode
+/// - [`EncodeError::MicroDataTooLong`] if the segments do not fit
+#[allow(clippy::cast_possible_truncation)]
+pub fn create_bits(
+    segments: &[Segment],
+    version: MicroVersion,
+    ec: ErrorCorrection,
+) -> Result<Vec<u8>, EncodeError> {
+    let Some(capacity_bits) = version.data_bits(ec) else {
+        return Err(EncodeError::UnsupportedMicroErrorCorrection { version, ec });
+    };
+    for segment in segments {
+        if let Some(designator) = segment.eci {
+            return Err(EncodeError::EciInMicro { designator });
+        }
+        if version.count_bits(segment.mode).is_none() {
+            return Err(EncodeError::UnsupportedMicroMode {
+                version,
+                mode: segment.mode,
+            });
+        }
+        if segment.mode != EncodingMode::Byte {
+            segment
+                .mode
+                .check(&String::from_utf8_lossy(&segment.data))?;
+        }
+    }
+    // every mode is supported by now
+    let needed_bits = bit_length(segments, version).unwrap_or(usize::MAX);
+    if needed_bits > capacity_bits {
+        return Err(EncodeError::MicroDataTooLong {
+            needed_bits,
+            capacity_bits,
+            version,
+            ec,
+        });
+    }
+    // a count too large for its field never fits, so it needs no check
+
+    let mut bytes = Bytes::with_capacity(capacity_bits.div_ceil(8));
+    for segment in segments {
+        if let (Some(indicator), Some(count_bits)) = (
+            version.mode_indicator(segment.mode),
+            version.count_bits(segment.mode),
+        ) {
+            if version.mode_bits() > 0 {
+                bytes.push(indicator, version.mode_bits());
+            }
+            bytes.push(segment.character_count() as u16, count_bits);
+        }
+        segment.encode_data(&mut bytes)?;
+    }
+
+    let remaining = |bytes: &Bytes| capacity_bits - bytes.bit_len();
+    let terminator = remaining(&bytes).min(version.terminator_bits() as usize);
+    if terminator > 0 {
+        bytes.push(0, terminator as u16);
+    }
+    let fill = ((8 - bytes.bit_len() % 8) % 8).min(remaining(&bytes));
+    if fill > 0 {
+        bytes.push(0, fill as u16);
+    }
+    let mut pad = [0xEC, 0x11].into_iter().cycle();
+    while remaining(&bytes) > 0 {
+        match remaining(&bytes) {
+            8.. => bytes.push(pad.next().unwrap_or(0), 8),
+            bits => bytes.push(0, bits as u16),
+        }
+    }
+    Ok(bytes.into_parts().0)
+}
+
+/// Appends the error correction codewords to the data codewords, ready
+/// to be [placed](MicroMatrix::place_data)
+///
+/// Micro QR Code symbols only have a single block, so nothing is
+/// interleaved. A final four bit data codeword takes up four bits.
+///
+/// # Panics
+///
+/// If the version does not support the error correction, or `data` is
+/// shorter than [`MicroVersion::data_size`]
+#[must_use]
+pub fn codewords(data: &[u8], version: MicroVersion, ec: ErrorCorrection) -> Bytes {
+    let (Some(data_bits), Some(ec_bytes)) = (version.data_bits(ec), version.ec_bytes(ec)) else {
+        panic!("{version:?} does not support {ec:?}");
+    };
+    let data = &data[..data_bits.div_ceil(8)];
+    let mut bytes = Bytes::with_capacity(version.total_codewords());
+    for (i, &byte) in data.iter().enumerate() {
+        if i == data.len() - 1 && data_bits % 8 == 4 {
+            bytes.push(u16::from(byte >> 4), 4);
+        } else {
+            bytes.push(u16::from(byte), 8);
+        }
+    }
+    for byte in reed_solomon::encode(data, ec_bytes) {
+        bytes.push(u16::from(byte), 8);
+    }
+    bytes
+}
+
+/// The grid of modules making up a Micro QR Code symbol
+///
+/// Coordinates are given as `(x, y)`, i.e. `(column, row)`, with the
+/// origin in the top left corner, next to the finder pattern.
+/// A dark module is `true`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MicroMatrix {
+    version: MicroVersion,
+    size: usize,
+    modules: Vec<bool>,
+    reserved: Vec<bool>,
+}
+
+impl MicroMatrix {
+    /// Creates a matrix for the given version with the finder & timing
+    /// patterns placed, and the format information area reserved
+    #[must_use]
+    pub fn new(version: MicroVersion) -> Self {
+        let size = version.size();
+        let mut matrix = Self {
+            version,
+            size,
+            modules: vec![false; size * size],
+            reserved: vec![false; size * size],
+        };
+        for i in 8..size {
+            matrix.set_function(i, 0, i % 2 == 0);
+            matrix.set_function(0, i, i % 2 == 0);
+        }
+        for y in 0..8_usize {
+            for x in 0..8_usize {
+                let ring = x.abs_diff(3).max(y.abs_diff(3));
+                matrix.set_function(x, y, ring != 2 && ring != 4);
+            }
+        }
+        for i in 1..=8 {
+            matrix.set_function(8, i, false);
+            matrix.set_function(i, 8, false);
+        }
+        matrix
+    }
+    /// Returns the version this matrix was created for
+    #[inline]
+    #[must_use]
+    pub const fn version(&self) -> MicroVersion {
+        self.version
+    }
+    /// Returns the width & height in modules
+    #[inline]
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+    /// Returns whether the module at `(x, y)` is dark
+    ///
+    /// # Panics
+    ///
+    /// If the coordinates are out of bounds
+    #[inline]
+    #[must_use]
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        self.modules[self.index(x, y)]
+    }
+    /// Returns whether the module at `(x, y)` is part of a function pattern
+    /// or the format information, meaning it may not hold data
+    ///
+    /// # Panics
+    ///
+    /// If the coordinates are out of bounds
+    #[inline]
+    #[must_use]
+    pub fn is_reserved(&self, x: usize, y: usize) -> bool {
+        self.reserved[self.index(x, y)]
+    }
+    /// Returns the modules row by row
+    #[inline]
+    #[must_use]
+    pub fn modules(&self) -> &[bool] {
+        &self.modules
+    }
+    /// Returns an iterator over the rows of modules
+    pub fn rows(&self) -> impl Iterator<Item = &[bool]> {
+        self.modules.chunks(self.size)
+    }
+    /// Returns the coordinates of every data module in placement order
+    ///
+    /// Like [`QrMatrix::data_positions`](crate::matrix::QrMatrix::data_positions),
+    /// but no column is skipped as the vertical timing pattern lies on
+    /// the left edge.
+    #[must_use]
+    pub fn data_positions(&self) -> Vec<(usize, usize)> {
+        let mut positions = Vec::with_capacity(self.size * self.size);
+        let mut upward = true;
+        for right in (1..self.size).rev().step_by(2) {
+            for vertical in 0..self.size {
+                let y = if upward {
+                    self.size - 1 - vertical
+                } else {
+                    vertical
+                };
+                for x in [right, right - 1] {
+                    if !self.is_reserved(x, y) {
+                        positions.push((x, y));
+                    }
+                }
+            }
+            upward = !upward;
+        }
+        positions
+    }
+    /// Writes the [codewords] into the data modules, most significant bit
+    /// first, in the order given by [`MicroMatrix::data_positions`]
+    pub fn place_data(&mut self, codewords: &Bytes) {
+        let bits = codewords.bit_len();
+        for (i, (x, y)) in self.data_positions().into_iter().enumerate() {
+            let dark = i < bits && codewords[i / 8] >> (7 - i % 8) & 1 == 1;
+            let index = self.index(x, y);
+            self.modules[index] = dark;
+        }
+    }
+    /// Flips every data module the mask applies to
+    ///
+    /// Applying the same mask twice undoes it.
+    pub fn apply_mask(&mut self, mask: MicroMask) {
+        for y in 0..self.size {
+            for x in 0..self.size {
+                if !self.is_reserved(x, y) && mask.applies(x, y) {
+                    let i = self.index(x, y);
+                    self.modules[i] ^= true;
+                }
+            }
+        }
+    }
+    /// Writes the format information around the finder pattern, least
+    /// significant bit at the top of the column
+    ///
+    /// # Panics
+    ///
+    /// If the version does not support the error correction
+    pub fn place_format(&mut self, ec: ErrorCorrection, mask: MicroMask) {
+        let Some(bits) = format_bits(self.version, ec, mask) else {
+            panic!("{:?} does not support {ec:?}", self.version);
+        };
+        let bit = |i: usize| bits >> i & 1 == 1;
+        for i in 0..8 {
+            self.set_function(8, i + 1, bit(i));
+            self.set_function(i + 1, 8, bit(14 - i));
+        }
+    }
+    fn set_function(&mut self, x: usize, y: usize, dark: bool) {
+        let i = self.index(x, y);
+        self.modules[i] = dark;
+        self.reserved[i] = true;
+    }
+    #[inline]
+    const fn index(&self, x: usize, y: usize) -> usize {
+        assert!(x < self.size && y < self.size, "module out of bounds");
+        y * self.size + x
+    }
+}
+
+/// A finished Micro QR Code, with its data placed, masked and its format
+/// information written
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MicroCode {
+    ec: ErrorCorrection,
+    mask: MicroMask,
+    matrix: MicroMatrix,
+}
+
+impl MicroCode {
+    /// Encodes the given string using the smallest version it fits in
+    ///
+    /// The string is split into the [segments](optimize) giving the
+    /// shortest bitstream.
+    ///
+    /// # Errors
+    ///
+    /// If no version supports the error correction, or the string does
+    /// not fit in [`MicroVersion::M4`]
+    pub fn new(data: &str, ec: ErrorCorrection) -> Result<Self, EncodeError> {
+        for version in MicroVersion::ALL {
+            let Some(segments) = optimize(data, version) else {
+                continue;
+            };
+            let fits = matches!(
+                (bit_length(&segments, version), version.data_bits(ec)),
+                (Some(needed), Some(capacity)) if needed <= capacity
+            );
+            if fits {
+                return Self::from_segments_with_version(&segments, version, ec);
+            }
+        }
+        Self::with_version(data, MicroVersion::M4, ec)
+    }
+    /// Encodes the given string using the given version
+    ///
+    /// # Errors
+    ///
+    /// If the version does not support the error correction or a
+    /// character, or the string does not fit
+    pub fn with_version(
+        data: &str,
+        version: MicroVersion,
+        ec: ErrorCorrection,
+    ) -> Result<Self, EncodeError> {
+        // only M1 and M2 may be unable to encode a character
+        match version {
+            MicroVersion::M1 => EncodingMode::Numeric.check(data)?,
+            MicroVersion::M2 => EncodingMode::Alphanumeric.check(data)?,
+            MicroVersion::M3 | MicroVersion::M4 => (),
+        }
+        let segments = optimize(data, version).unwrap_or_default();
+        Self::from_segments_with_version(&segments, version, ec)
+    }
+    /// Encodes the given segments, in order, using the given version
+    ///
+    /// # Errors
+    ///
+    /// If the segments are invalid or do not fit, see [`create_bits`]
+    pub fn from_segments_with_version(
+        segments: &[Segment],
+        version: MicroVersion,
+        ec: ErrorCorrection,
+    ) -> Result<Self, EncodeError> {
+        let data = create_bits(segments, version, ec)?;
+        Ok(Self::from_data_codewords(&data, version, ec))
+    }
+    /// Creates a symbol from finished data codewords, such as those
+    /// returned by [`create_bits`]
+    ///
+    /// The mask with the highest [score] is chosen.
+    ///
+    /// # Panics
+    ///
+    /// If the version does not support the error correction, or `data`
+    /// is shorter than [`MicroVersion::data_size`]
+    #[must_use]
+    pub fn from_data_codewords(data: &[u8], version: MicroVersion, ec: ErrorCorrection) -> Self {
+        let mut matrix = MicroMatrix::new(version);
+        matrix.place_data(&codewords(data, version, ec));
+        let mask = best_mask(&matrix);
+        matrix.apply_mask(mask);
+        matrix.place_format(ec, mask);
+        Self { ec, mask, matrix }
+    }
+    /// Replaces the automatically chosen mask
+    pub fn set_mask(&mut self, mask: MicroMask) {
+        self.matrix.apply_mask(self.mask);
+        self.matrix.apply_mask(mask);
+        self.matrix.place_format(self.ec, mask);
+        self.mask = mask;
+    }
+    #[inline]
+    #[must_use]
+    pub const fn version(&self) -> MicroVersion {
+        self.matrix.version()
+    }
+    #[inline]
+    #[must_use]
+    pub const fn error_correction(&self) -> ErrorCorrection {
+        self.ec
+    }
+    #[inline]
+    #[must_use]
+    pub const fn mask(&self) -> MicroMask {
+        self.mask
+    }
+    /// Returns the modules of the symbol, excluding the quiet zone
+    #[inline]
+    #[must_use]
+    pub const fn matrix(&self) -> &MicroMatrix {
+        &self.matrix
+    }
+}
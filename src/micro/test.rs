@@ -0,0 +1,196 @@
+use crate::{
+    format,
+    mask::Mask,
+    micro::{
+        best_mask, codewords, create_bits, format_bits, score, MicroCode, MicroMask, MicroMatrix,
+        MicroVersion,
+    },
+    reed_solomon,
+    segment::Segment,
+    EncodeError, EncodingMode, ErrorCorrection,
+};
+
+#[test]
+fn capacities() {
+    use EncodingMode::*;
+    use ErrorCorrection::*;
+    use MicroVersion::*;
+    // numeric, alphanumeric, byte & kanji characters
+    let table = [
+        (M1, L, [Some(5), None, None, None]),
+        (M2, L, [Some(10), Some(6), None, None]),
+        (M2, M, [Some(8), Some(5), None, None]),
+        (M3, L, [Some(23), Some(14), Some(9), Some(6)]),
+        (M3, M, [Some(18), Some(11), Some(7), Some(4)]),
+        (M4, L, [Some(35), Some(21), Some(15), Some(9)]),
+        (M4, M, [Some(30), Some(18), Some(13), Some(8)]),
+        (M4, Q, [Some(21), Some(13), Some(9), Some(5)]),
+    ];
+    for (version, ec, capacities) in table {
+        let modes = [Numeric, Alphanumeric, Byte, Kanji];
+        assert_eq!(
+            modes.map(|mode| version.capacity(ec, mode)),
+            capacities,
+            "{version:?}-{ec:?}"
+        );
+        let data_bits = version.data_bits(ec).unwrap();
+        let ec_bytes = version.ec_bytes(ec).unwrap();
+        assert_eq!(
+            version.data_size(ec).unwrap() + ec_bytes,
+            version.total_codewords()
+        );
+        // every data module is used
+        assert_eq!(
+            MicroMatrix::new(version).data_positions().len(),
+            data_bits + ec_bytes * 8,
+            "{version:?}-{ec:?}"
+        );
+    }
+    assert!(!M1.supports(M));
+    assert!(MicroVersion::ALL.iter().all(|v| !v.supports(H)));
+}
+
+#[test]
+fn iso_example() {
+    // 01234567 as M2-L, from ISO/IEC 18004
+    let segments = [Segment::numeric("01234567").unwrap()];
+    let data = create_bits(&segments, MicroVersion::M2, ErrorCorrection::L).unwrap();
+    assert_eq!(data, [0x40, 0x18, 0xAC, 0xC3, 0x00]);
+    assert_eq!(
+        reed_solomon::encode(&data, 5),
+        [0x86, 0x0D, 0x22, 0xAE, 0x30]
+    );
+}
+
+#[test]
+fn half_codeword() {
+    // 3 count bits and 17 data bits fill M1 exactly, leaving no terminator
+    let segments = [Segment::numeric("12345").unwrap()];
+    let data = create_bits(&segments, MicroVersion::M1, ErrorCorrection::L).unwrap();
+    assert_eq!(data, [0xA3, 0xDA, 0xD0]);
+    let bits = codewords(&data, MicroVersion::M1, ErrorCorrection::L);
+    assert_eq!(bits.bit_len(), 36);
+    assert_eq!(bits[2] & 0xF0, 0xD0);
+
+    // the final four bits are zero padding rather than a pad codeword
+    let data = create_bits(&[], MicroVersion::M3, ErrorCorrection::M).unwrap();
+    assert_eq!(data, [0, 0xEC, 0x11, 0xEC, 0x11, 0xEC, 0x11, 0xEC, 0]);
+}
+
+#[test]
+fn format_information() {
+    assert_eq!(
+        format_bits(MicroVersion::M1, ErrorCorrection::L, MicroMask::M0),
+        Some(0b100_0100_0100_0101)
+    );
+    assert_eq!(
+        format_bits(MicroVersion::M1, ErrorCorrection::M, MicroMask::M0),
+        None
+    );
+    // the same BCH code as QR Code, under a different mask
+    for version in MicroVersion::ALL {
+        for ec in ErrorCorrection::ALL {
+            for mask in MicroMask::ALL {
+                let Some(bits) = format_bits(version, ec, mask) else {
+                    continue;
+                };
+                let data = version.symbol_number(ec).unwrap() << 2 | mask as u16;
+                let qr = format::format_bits(
+                    ErrorCorrection::ALL
+                        .into_iter()
+                        .find(|ec| ec.bits() == data >> 3)
+                        .unwrap(),
+                    Mask::ALL[usize::from(data & 0b111)],
+                );
+                assert_eq!(bits ^ 0b100_0100_0100_0101, qr ^ 0b101_0100_0001_0010);
+            }
+        }
+    }
+}
+
+#[test]
+fn function_patterns() {
+    let matrix = MicroMatrix::new(MicroVersion::M2);
+    let row = |y: usize| (0..13).map(|x| matrix.get(x, y)).collect::<Vec<_>>();
+    let (t, f) = (true, false);
+    assert_eq!(row(0), [t, t, t, t, t, t, t, f, t, f, t, f, t]);
+    assert_eq!(row(1), [t, f, f, f, f, f, t, f, f, f, f, f, f]);
+    assert_eq!(row(3), [t, f, t, t, t, f, t, f, f, f, f, f, f]);
+    assert_eq!(row(7), [f; 13]);
+    assert!(matrix.is_reserved(8, 8) && matrix.is_reserved(1, 8) && matrix.is_reserved(8, 1));
+    assert!(!matrix.is_reserved(9, 1) && !matrix.is_reserved(1, 9));
+    assert_eq!(matrix.data_positions()[0], (12, 12));
+}
+
+#[test]
+#[should_panic = "module out of bounds"]
+fn out_of_bounds() {
+    // would be the first module of the second row if not checked
+    let _ = MicroMatrix::new(MicroVersion::M2).get(13, 0);
+}
+
+#[test]
+fn masks() {
+    for mask in MicroMask::ALL {
+        for (x, y) in [(0, 0), (3, 5), (7, 2), (10, 9)] {
+            assert_eq!(mask.applies(x, y), mask.pattern().applies(x, y));
+        }
+    }
+    let matrix = MicroMatrix::new(MicroVersion::M1);
+    // the timing patterns are left out of the edges
+    assert_eq!(score(&matrix), 0);
+    let best = best_mask(&matrix);
+    let mut masked = matrix.clone();
+    masked.apply_mask(best);
+    assert!(MicroMask::ALL.iter().all(|&mask| {
+        let mut other = matrix.clone();
+        other.apply_mask(mask);
+        score(&other) <= score(&masked)
+    }));
+}
+
+#[test]
+fn symbols() {
+    use MicroVersion::*;
+    let version = |s: &str, ec| MicroCode::new(s, ec).map(|code| code.version());
+    assert_eq!(version("12345", ErrorCorrection::L), Ok(M1));
+    assert_eq!(version("123456", ErrorCorrection::L), Ok(M2));
+    assert_eq!(version("HELLO", ErrorCorrection::L), Ok(M2));
+    assert_eq!(version("hello", ErrorCorrection::L), Ok(M3));
+    assert_eq!(version("12345", ErrorCorrection::Q), Ok(M4));
+    assert_eq!(
+        version("12345", ErrorCorrection::H),
+        Err(EncodeError::UnsupportedMicroErrorCorrection {
+            version: M4,
+            ec: ErrorCorrection::H
+        })
+    );
+    assert!(matches!(
+        version(&"a".repeat(16), ErrorCorrection::L),
+        Err(EncodeError::MicroDataTooLong { version: M4, .. })
+    ));
+    assert_eq!(
+        MicroCode::with_version("12A", M1, ErrorCorrection::L).map(|_| ()),
+        Err(EncodeError::InvalidCharacterForMode {
+            mode: EncodingMode::Numeric,
+            character: 'A',
+            index: 2
+        })
+    );
+    assert_eq!(
+        create_bits(&[Segment::byte(b"a")], M2, ErrorCorrection::L),
+        Err(EncodeError::UnsupportedMicroMode {
+            version: M2,
+            mode: EncodingMode::Byte
+        })
+    );
+
+    let mut code = MicroCode::new("01234567", ErrorCorrection::L).unwrap();
+    let original = code.clone();
+    for mask in MicroMask::ALL {
+        code.set_mask(mask);
+        assert_eq!(code.mask(), mask);
+    }
+    code.set_mask(original.mask());
+    assert_eq!(code, original);
+}
@@ -0,0 +1,161 @@
+//! A complete QR Code symbol
+use crate::{
+    encoding::interleave,
+    gs1::Fnc1,
+    mask::{best_mask, penalties, Mask, Penalty},
+    matrix::QrMatrix,
+    segment::{self, create_bits_with_header, optimize, optimize_fnc1, Segment},
+    EncodeError, ErrorCorrection, QRCodeVersion,
+};
+
+#[cfg(test)]
+mod test;
+
+/// A finished QR Code, with its data placed, masked and its format
+/// information written
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QrCode {
+    ec: ErrorCorrection,
+    mask: Mask,
+    matrix: QrMatrix,
+}
+
+impl QrCode {
+    /// Encodes the given string using the smallest version it fits in
+    ///
+    /// The string is split into the [segments](optimize) giving the
+    /// shortest bitstream.
+    ///
+    /// # Errors
+    ///
+    /// If the string does not fit in any version
+    pub fn new(data: &str, ec: ErrorCorrection) -> Result<Self, EncodeError> {
+        let (version, segments) = segment::fit_version(ec, 0, |version| optimize(data, version))?;
+        Self::from_segments_with_version(&segments, version, ec)
+    }
+    /// Like [`QrCode::new`], but declares any non-ASCII byte mode data as
+    /// UTF-8 with an ECI, see [`segment::declare_utf8`]
+    ///
+    /// Some scanners assume ISO-8859-1 otherwise.
+    ///
+    /// # Errors
+    ///
+    /// If the string does not fit in any version
+    pub fn new_utf8(data: &str, ec: ErrorCorrection) -> Result<Self, EncodeError> {
+        let (version, segments) = segment::fit_version(ec, 0, |version| {
+            let mut segments = optimize(data, version);
+            segment::declare_utf8(&mut segments);
+            segments
+        })?;
+        Self::from_segments_with_version(&segments, version, ec)
+    }
+    /// Encodes the string after the given [`Fnc1`] mode indicator using
+    /// the smallest version it fits in
+    ///
+    /// The [group separators](crate::gs1::GS) of the string are written
+    /// as `%` in alphanumeric segments, see [`optimize_fnc1`].
+    ///
+    /// # Errors
+    ///
+    /// If the string does not fit in any version
+    pub fn with_fnc1(data: &str, fnc1: Fnc1, ec: ErrorCorrection) -> Result<Self, EncodeError> {
+        let (version, segments) = segment::fit_version(ec, fnc1.bit_length(), |version| {
+            optimize_fnc1(data, version)
+        })?;
+        let data = create_bits_with_header(&fnc1.fields(), &segments, version, ec)?;
+        Ok(Self::from_data_codewords(&data, version, ec))
+    }
+    /// Encodes the given string using the given version
+    ///
+    /// The string is split into the [segments](optimize) giving the
+    /// shortest bitstream.
+    ///
+    /// # Errors
+    ///
+    /// If the string does not fit
+    pub fn with_version(
+        data: &str,
+        version: QRCodeVersion,
+        ec: ErrorCorrection,
+    ) -> Result<Self, EncodeError> {
+        let data = segment::create_bits(&optimize(data, version), version, ec)?;
+        Ok(Self::from_data_codewords(&data, version, ec))
+    }
+    /// Encodes the given segments, in order, using the smallest version
+    /// they fit in
+    ///
+    /// # Errors
+    ///
+    /// If the segments are invalid or do not fit in any version,
+    /// see [`segment::create_bits`]
+    pub fn from_segments(segments: &[Segment], ec: ErrorCorrection) -> Result<Self, EncodeError> {
+        let version = segment::fit_segments(segments, ec)?;
+        Self::from_segments_with_version(segments, version, ec)
+    }
+    /// Encodes the given segments, in order, using the given version
+    ///
+    /// # Errors
+    ///
+    /// If the segments are invalid or do not fit, see [`segment::create_bits`]
+    pub fn from_segments_with_version(
+        segments: &[Segment],
+        version: QRCodeVersion,
+        ec: ErrorCorrection,
+    ) -> Result<Self, EncodeError> {
+        let data = segment::create_bits(segments, version, ec)?;
+        Ok(Self::from_data_codewords(&data, version, ec))
+    }
+    /// Creates a symbol from finished data codewords, such as those
+    /// returned by [`Encodable::create_bits`](crate::encoding::Encodable::create_bits)
+    ///
+    /// The mask with the lowest [`Penalty`] is chosen.
+    ///
+    /// # Panics
+    ///
+    /// If `data` is shorter than [`QRCodeVersion::data_size`]
+    #[must_use]
+    pub fn from_data_codewords(data: &[u8], version: QRCodeVersion, ec: ErrorCorrection) -> Self {
+        let mut matrix = QrMatrix::new(version);
+        matrix.place_data(&interleave(data, version, ec));
+        let mask = best_mask(&matrix, ec);
+        matrix.apply_mask(mask);
+        matrix.place_format(ec, mask);
+        Self { ec, mask, matrix }
+    }
+    /// Replaces the automatically chosen mask
+    pub fn set_mask(&mut self, mask: Mask) {
+        self.matrix.apply_mask(self.mask);
+        self.matrix.apply_mask(mask);
+        self.matrix.place_format(self.ec, mask);
+        self.mask = mask;
+    }
+    /// Returns the [`Penalty`] this symbol would have under each mask,
+    /// ordered by their reference
+    #[must_use]
+    pub fn penalties(&self) -> [Penalty; 8] {
+        let mut unmasked = self.matrix.clone();
+        unmasked.apply_mask(self.mask);
+        penalties(&unmasked, self.ec)
+    }
+    #[inline]
+    #[must_use]
+    pub const fn version(&self) -> QRCodeVersion {
+        self.matrix.version()
+    }
+    #[inline]
+    #[must_use]
+    pub const fn error_correction(&self) -> ErrorCorrection {
+        self.ec
+    }
+    #[inline]
+    #[must_use]
+    pub const fn mask(&self) -> Mask {
+        self.mask
+    }
+    /// Returns the modules of the symbol, excluding the quiet zone
+    #[inline]
+    #[must_use]
+    pub const fn matrix(&self) -> &QrMatrix {
+        &self.matrix
+    }
+}
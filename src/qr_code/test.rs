@@ -0,0 +1,67 @@
+use crate::{mask::Mask, EncodeError, ErrorCorrection, QRCodeVersion, QrCode};
+
+#[test]
+fn smallest_version() {
+    for (data, ec, version) in [
+        ("9".repeat(41), ErrorCorrection::L, QRCodeVersion::V1),
+        ("9".repeat(42), ErrorCorrection::L, QRCodeVersion::V2),
+        ("A".repeat(25), ErrorCorrection::L, QRCodeVersion::V1),
+        ("A".repeat(26), ErrorCorrection::L, QRCodeVersion::V2),
+        ("a".repeat(17), ErrorCorrection::L, QRCodeVersion::V1),
+        ("a".repeat(7), ErrorCorrection::H, QRCodeVersion::V1),
+        ("a".repeat(8), ErrorCorrection::H, QRCodeVersion::V2),
+        // the count field grows at V10
+        ("9".repeat(552), ErrorCorrection::L, QRCodeVersion::V9),
+        ("9".repeat(553), ErrorCorrection::L, QRCodeVersion::V10),
+        ("a".repeat(230), ErrorCorrection::L, QRCodeVersion::V9),
+        ("a".repeat(231), ErrorCorrection::L, QRCodeVersion::V10),
+        // and again at V27
+        ("9".repeat(3283), ErrorCorrection::L, QRCodeVersion::V26),
+        ("9".repeat(3284), ErrorCorrection::L, QRCodeVersion::V27),
+        ("A".repeat(1990), ErrorCorrection::L, QRCodeVersion::V26),
+        ("A".repeat(1991), ErrorCorrection::L, QRCodeVersion::V27),
+        ("9".repeat(7089), ErrorCorrection::L, QRCodeVersion::V40),
+    ] {
+        let code = QrCode::new(&data, ec).unwrap();
+        assert_eq!(code.version(), version, "{} x {:?}", data.len(), ec);
+        assert_eq!(code.matrix().size(), version.size());
+    }
+    assert_eq!(
+        QrCode::new(&"9".repeat(7090), ErrorCorrection::L),
+        Err(EncodeError::DataTooLong {
+            needed_bits: 23_652,
+            capacity_bits: 23_648,
+            version: QRCodeVersion::V40,
+            ec: ErrorCorrection::L,
+        })
+    );
+    assert!(matches!(
+        QrCode::new(&"a".repeat(1274), ErrorCorrection::H),
+        Err(EncodeError::DataTooLong { .. })
+    ));
+    assert!(matches!(
+        QrCode::new(&"a".repeat(70_000), ErrorCorrection::L),
+        Err(EncodeError::CountFieldOverflow { .. })
+    ));
+}
+
+#[test]
+fn forced_mask() {
+    let mut code = QrCode::new("HELLO WORLD", ErrorCorrection::Q).unwrap();
+    let chosen = code.mask();
+    let penalties = code.penalties();
+    assert!(penalties
+        .iter()
+        .all(|p| p.total() >= penalties[chosen as usize].total()));
+
+    for mask in Mask::ALL {
+        let mut other = code.clone();
+        other.set_mask(mask);
+        assert_eq!(other.mask(), mask);
+        assert_eq!(other.penalties(), penalties);
+        other.set_mask(chosen);
+        assert_eq!(other, code);
+    }
+    code.set_mask(Mask::M3);
+    assert_eq!(code.mask(), Mask::M3);
+}
@@ -0,0 +1,238 @@
+//! Reed-Solomon error correction codeword generation & error correction
+use crate::{galois, ErrorCorrection, GroupSplit, QRCodeVersion};
+
+#[cfg(test)]
+mod test;
+
+/// A single block of data codewords alongside its error correction codewords
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block<'a> {
+    /// The data codewords of this block
+    pub data: &'a [u8],
+    /// The error correction codewords calculated from [`Block::data`]
+    pub ec: Vec<u8>,
+}
+
+/// Returns the generator polynomial of the given degree,
+/// `(x - α^0)(x - α^1)...(x - α^(degree - 1))`
+///
+/// The coefficients are ordered from the highest power to the lowest,
+/// the leading coefficient is always `1`.
+#[must_use]
+pub fn generator(degree: usize) -> Vec<u8> {
+    let mut poly = Vec::with_capacity(degree + 1);
+    poly.push(1);
+    for i in 0..degree {
+        let root = galois::exp(i);
+        poly.push(0);
+        for j in (1..poly.len()).rev() {
+            poly[j] ^= galois::mul(poly[j - 1], root);
+        }
+    }
+    poly
+}
+
+/// Calculates `ec_bytes` error correction codewords for the given data
+///
+/// This is the remainder of dividing the data polynomial, multiplied by
+/// `x^ec_bytes`, by the [generator] polynomial.
+#[must_use]
+pub fn encode(data: &[u8], ec_bytes: usize) -> Vec<u8> {
+    let generator = generator(ec_bytes);
+    let mut remainder = vec![0; ec_bytes];
+    for &byte in data {
+        let factor = byte ^ remainder.first().copied().unwrap_or(0);
+        remainder.rotate_left(1);
+        if let Some(last) = remainder.last_mut() {
+            *last = 0;
+        }
+        remainder
+            .iter_mut()
+            .zip(&generator[1..])
+            .for_each(|(r, &g)| *r ^= galois::mul(g, factor));
+    }
+    remainder
+}
+
+/// Splits the data codewords into the blocks described by
+/// [`QRCodeVersion::split`], calculating the error correction
+/// codewords for each one
+///
+/// # Panics
+///
+/// If `data` is shorter than [`QRCodeVersion::data_size`]
+#[must_use]
+pub fn create_blocks(data: &[u8], version: QRCodeVersion, ec: ErrorCorrection) -> Vec<Block<'_>> {
+    split_blocks(data, &version.split(ec))
+}
+
+/// Splits the data codewords into the blocks described by the
+/// [`GroupSplit`], calculating the error correction codewords for each one
+///
+/// # Panics
+///
+/// If `data` is shorter than the blocks of the split
+#[must_use]
+pub fn split_blocks<'a>(data: &'a [u8], split: &GroupSplit) -> Vec<Block<'a>> {
+    let ec_bytes = split.ec_bytes();
+    let mut rest = data;
+    split
+        .block_sizes()
+        .map(|size| {
+            let (data, tail) = rest.split_at(size);
+            rest = tail;
+            Block {
+                data,
+                ec: encode(data, ec_bytes),
+            }
+        })
+        .collect()
+}
+
+/// Returns `ec_bytes` syndromes of a block, its data codewords followed
+/// by its error correction codewords
+///
+/// The `i`th syndrome is the block evaluated at `α^i`, the roots of the
+/// [generator]. Every syndrome is zero if the block is intact.
+#[must_use]
+pub fn syndromes(block: &[u8], ec_bytes: usize) -> Vec<u8> {
+    (0..ec_bytes)
+        .map(|i| {
+            let x = galois::exp(i);
+            block
+                .iter()
+                .fold(0, |acc, &coefficient| galois::mul(acc, x) ^ coefficient)
+        })
+        .collect()
+}
+
+/// Evaluates a polynomial whose coefficients are ordered from the lowest
+/// power to the highest
+fn evaluate(poly: &[u8], x: u8) -> u8 {
+    poly.iter()
+        .rev()
+        .fold(0, |acc, &coefficient| galois::mul(acc, x) ^ coefficient)
+}
+
+/// Corrects a block in place, its data codewords followed by its
+/// `ec_bytes` error correction codewords, returning the number of
+/// codewords which were changed
+///
+/// `erasures` are the indices of codewords known to be unreliable. Twice
+/// the number of errors plus the number of erasures may be at most
+/// `ec_bytes`.
+///
+/// The errata locator is found by the Berlekamp-Massey algorithm, seeded
+/// with the erasure locator, its roots by a Chien search and the error
+/// values by Forney's algorithm.
+///
+/// # Returns
+///
+/// [`None`] if the block has too many errors, in which case it is left
+/// untouched
+#[must_use]
+pub fn correct(block: &mut [u8], ec_bytes: usize, erasures: &[usize]) -> Option<usize> {
+    let syndromes = syndromes(block, ec_bytes);
+    if syndromes.iter().all(|&s| s == 0) {
+        return Some(0);
+    }
+    if erasures.len() > ec_bytes || erasures.iter().any(|&i| i >= block.len()) {
+        return None;
+    }
+    // the locator of the codeword at index i is α to the power of its
+    // degree in the block polynomial
+    let locator = |i: usize| galois::exp(block.len() - 1 - i);
+
+    // the erasure locator, the product of (1 - X x) for every erasure
+    let mut errata = vec![1];
+    for &i in erasures {
+        let x = locator(i);
+        errata.push(0);
+        for j in (1..errata.len()).rev() {
+            errata[j] ^= galois::mul(errata[j - 1], x);
+        }
+    }
+
+    // Berlekamp-Massey, starting after the erasures
+    let rho = erasures.len();
+    let mut previous = errata.clone();
+    let (mut length, mut shift, mut discrepancy) = (rho, 1, 1);
+    for k in rho..ec_bytes {
+        let delta = errata
+            .iter()
+            .enumerate()
+            .take_while(|&(j, _)| j <= k)
+            .fold(0, |acc, (j, &c)| acc ^ galois::mul(c, syndromes[k - j]));
+        if delta == 0 {
+            shift += 1;
+            continue;
+        }
+        let factor = galois::div(delta, discrepancy);
+        let mut next = errata.clone();
+        next.resize(next.len().max(previous.len() + shift), 0);
+        for (j, &b) in previous.iter().enumerate() {
+            next[j + shift] ^= galois::mul(factor, b);
+        }
+        if 2 * length <= k + rho {
+            length = k + 1 + rho - length;
+            previous = std::mem::replace(&mut errata, next);
+            discrepancy = delta;
+            shift = 1;
+        } else {
+            errata = next;
+            shift += 1;
+        }
+    }
+    while errata.len() > 1 && errata.last() == Some(&0) {
+        errata.pop();
+    }
+    if errata.len() - 1 != length || 2 * length > ec_bytes + rho {
+        return None;
+    }
+
+    // Chien search for the codewords whose inverse locator is a root
+    let positions = (0..block.len())
+        .filter(|&i| evaluate(&errata, galois::inv(locator(i))) == 0)
+        .collect::<Vec<_>>();
+    if positions.len() != length {
+        return None;
+    }
+
+    // Forney, with the evaluator Ω = S Λ mod x^ec_bytes and the formal
+    // derivative of Λ, which only keeps its odd powers
+    let mut evaluator = vec![0; ec_bytes];
+    for (i, &s) in syndromes.iter().enumerate() {
+        for (j, &c) in errata.iter().enumerate().take(ec_bytes - i) {
+            evaluator[i + j] ^= galois::mul(s, c);
+        }
+    }
+    let derivative = errata
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(j, &c)| if j % 2 == 1 { c } else { 0 })
+        .collect::<Vec<_>>();
+    let mut corrected = block.to_vec();
+    let mut changed = 0;
+    for &i in &positions {
+        let x = locator(i);
+        let x_inverse = galois::inv(x);
+        let denominator = evaluate(&derivative, x_inverse);
+        if denominator == 0 {
+            return None;
+        }
+        let magnitude = galois::div(galois::mul(x, evaluate(&evaluator, x_inverse)), denominator);
+        if magnitude != 0 {
+            corrected[i] ^= magnitude;
+            changed += 1;
+        }
+    }
+    if self::syndromes(&corrected, ec_bytes)
+        .iter()
+        .any(|&s| s != 0)
+    {
+        return None;
+    }
+    block.copy_from_slice(&corrected);
+    Some(changed)
+}
@@ -0,0 +1,155 @@
+use crate::{
+    encoding::Encodable,
+    reed_solomon::{correct, create_blocks, encode, generator, syndromes},
+    ErrorCorrection, QRCodeVersion,
+};
+
+#[test]
+fn generator_polynomial() {
+    // α exponents of the degree 7 generator, from ISO/IEC 18004 Annex A
+    let exponents = [0, 87, 229, 146, 149, 238, 102, 21];
+    let expected: Vec<u8> = exponents.iter().map(|&e| crate::galois::exp(e)).collect();
+    assert_eq!(generator(7), expected);
+}
+
+#[test]
+fn iso_example() {
+    // "01234567" as 1-M, from ISO/IEC 18004 Annex I
+    let data = [
+        0x10, 0x20, 0x0C, 0x56, 0x61, 0x80, 0xEC, 0x11, 0xEC, 0x11, 0xEC, 0x11, 0xEC, 0x11, 0xEC,
+        0x11,
+    ];
+    assert_eq!(
+        encode(&data, 10),
+        [0xA5, 0x24, 0xD4, 0xC1, 0xED, 0x36, 0xC7, 0x87, 0x2C, 0x55]
+    );
+}
+
+#[test]
+fn hello_world() {
+    let (data, _) = "HELLO WORLD"
+        .create_bits(QRCodeVersion::V1, ErrorCorrection::M)
+        .unwrap();
+    let blocks = create_blocks(&data, QRCodeVersion::V1, ErrorCorrection::M);
+    assert_eq!(blocks.len(), 1);
+    assert_eq!(
+        blocks[0].ec,
+        [0xC4, 0x23, 0x27, 0x77, 0xEB, 0xD7, 0xE7, 0xE2, 0x5D, 0x17]
+    );
+}
+
+#[test]
+fn two_groups() {
+    let (data, _) = "HELLO WORLD"
+        .create_bits(QRCodeVersion::V5, ErrorCorrection::Q)
+        .unwrap();
+    let blocks = create_blocks(&data, QRCodeVersion::V5, ErrorCorrection::Q);
+    let sizes: Vec<_> = blocks.iter().map(|b| b.data.len()).collect();
+    assert_eq!(sizes, [15, 15, 16, 16]);
+    assert!(blocks.iter().all(|b| b.ec.len() == 18));
+    assert_eq!(blocks[0].data, &data[..15]);
+    assert_eq!(blocks[3].data, &data[46..]);
+}
+
+#[test]
+fn splits_cover_data() {
+    for &version in QRCodeVersion::ALL {
+        for ec in [
+            ErrorCorrection::L,
+            ErrorCorrection::M,
+            ErrorCorrection::Q,
+            ErrorCorrection::H,
+        ] {
+            let split = version.split(ec);
+            assert_eq!(
+                split.block_sizes().sum::<usize>(),
+                version.data_size(ec),
+                "{version:?}-{ec:?}"
+            );
+        }
+    }
+}
+
+/// A linear congruential generator, for reproducible damage
+struct Lcg(u64);
+
+impl Lcg {
+    fn next(&mut self, bound: usize) -> usize {
+        self.0 = self
+            .0
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (self.0 >> 33) as usize % bound
+    }
+    #[allow(clippy::cast_possible_truncation)]
+    fn byte(&mut self) -> u8 {
+        self.next(256) as u8
+    }
+    /// Returns `count` distinct indices below `bound`
+    fn indices(&mut self, count: usize, bound: usize) -> Vec<usize> {
+        let mut indices = Vec::with_capacity(count);
+        while indices.len() < count {
+            let i = self.next(bound);
+            if !indices.contains(&i) {
+                indices.push(i);
+            }
+        }
+        indices
+    }
+}
+
+fn codeword(data_bytes: usize, ec_bytes: usize, rng: &mut Lcg) -> Vec<u8> {
+    let mut block = (0..data_bytes).map(|_| rng.byte()).collect::<Vec<_>>();
+    let ec = encode(&block, ec_bytes);
+    block.extend(ec);
+    block
+}
+
+#[test]
+fn syndrome_check() {
+    let mut rng = Lcg(1);
+    let mut block = codeword(16, 10, &mut rng);
+    assert!(syndromes(&block, 10).iter().all(|&s| s == 0));
+    block[3] ^= 0x40;
+    assert!(syndromes(&block, 10).iter().any(|&s| s != 0));
+}
+
+#[test]
+fn errors_and_erasures() {
+    let mut rng = Lcg(0x5EED);
+    for (data_bytes, ec_bytes) in [(19, 7), (16, 10), (13, 13), (9, 17), (15, 30), (118, 30)] {
+        let original = codeword(data_bytes, ec_bytes, &mut rng);
+        for erasures in 0..=ec_bytes {
+            let errors = (ec_bytes - erasures) / 2;
+            let mut block = original.clone();
+            let indices = rng.indices(errors + erasures, block.len());
+            for &i in &indices {
+                block[i] ^= rng.byte().max(1);
+            }
+            assert_eq!(
+                correct(&mut block, ec_bytes, &indices[errors..]),
+                Some(errors + erasures),
+                "{data_bytes}+{ec_bytes} with {errors} errors & {erasures} erasures"
+            );
+            assert_eq!(block, original);
+        }
+    }
+}
+
+#[test]
+fn uncorrectable() {
+    let mut rng = Lcg(7);
+    let original = codeword(16, 10, &mut rng);
+    assert_eq!(correct(&mut original.clone(), 10, &[]), Some(0));
+    // an intact codeword marked as erased needs no change
+    assert_eq!(correct(&mut original.clone(), 10, &[0, 5]), Some(0));
+
+    let mut block = original;
+    for i in rng.indices(6, block.len()) {
+        block[i] ^= 0xFF;
+    }
+    let damaged = block.clone();
+    assert_eq!(correct(&mut block, 10, &[]), None);
+    assert_eq!(block, damaged);
+    assert_eq!(correct(&mut block, 10, &[0; 11]), None);
+}
@@ -0,0 +1,151 @@
+//! Turning finished symbols into images & text
+use crate::{
+    matrix::QrMatrix,
+    micro::{MicroCode, MicroMatrix},
+    rmqr::{RmqrCode, RmqrMatrix},
+    QrCode,
+};
+
+pub mod netpbm;
+pub mod png;
+pub mod svg;
+pub mod terminal;
+#[cfg(test)]
+mod test;
+
+/// A grid of modules which can be rendered, excluding the quiet zone
+///
+/// Coordinates are given as `(x, y)`, i.e. `(column, row)`, with the
+/// origin in the top left corner.
+pub trait Symbol {
+    /// Returns the width in modules
+    fn width(&self) -> usize;
+    /// Returns the height in modules
+    fn height(&self) -> usize;
+    /// Returns whether the module at `(x, y)` is dark
+    fn is_dark(&self, x: usize, y: usize) -> bool;
+}
+
+macro_rules! impl_symbol {
+    ($( $matrix: ty => $width: ident, $height: ident; )+) => {
+        $(
+            impl Symbol for $matrix {
+                #[inline]
+                fn width(&self) -> usize {
+                    self.$width()
+                }
+                #[inline]
+                fn height(&self) -> usize {
+                    self.$height()
+                }
+                #[inline]
+                fn is_dark(&self, x: usize, y: usize) -> bool {
+                    self.get(x, y)
+                }
+            }
+        )+
+    };
+    ($( $code: ty ),+) => {
+        $(
+            impl Symbol for $code {
+                #[inline]
+                fn width(&self) -> usize {
+                    self.matrix().width()
+                }
+                #[inline]
+                fn height(&self) -> usize {
+                    self.matrix().height()
+                }
+                #[inline]
+                fn is_dark(&self, x: usize, y: usize) -> bool {
+                    self.matrix().is_dark(x, y)
+                }
+            }
+        )+
+    };
+}
+
+impl_symbol! {
+    QrMatrix => size, size;
+    MicroMatrix => size, size;
+    RmqrMatrix => width, height;
+}
+impl_symbol!(QrCode, MicroCode, RmqrCode);
+
+/// Returns whether the module at `(x, y)` of the symbol surrounded by a
+/// quiet zone of the given width is dark
+///
+/// Coordinates outside of the symbol are light.
+#[inline]
+#[must_use]
+pub fn is_dark_with_quiet_zone(
+    symbol: &impl Symbol,
+    quiet_zone: usize,
+    x: usize,
+    y: usize,
+) -> bool {
+    match (x.checked_sub(quiet_zone), y.checked_sub(quiet_zone)) {
+        (Some(x), Some(y)) if x < symbol.width() && y < symbol.height() => symbol.is_dark(x, y),
+        _ => false,
+    }
+}
+
+/// Returns the width & height in pixels of the image of a symbol
+/// surrounded by a quiet zone, each module being `scale` pixels wide
+///
+/// # Returns
+///
+/// [`None`] if either overflows
+#[must_use]
+pub fn scaled_size(
+    symbol: &impl Symbol,
+    quiet_zone: usize,
+    scale: usize,
+) -> Option<(usize, usize)> {
+    let side = |modules: usize| {
+        quiet_zone
+            .checked_mul(2)?
+            .checked_add(modules)?
+            .checked_mul(scale)
+    };
+    Some((side(symbol.width())?, side(symbol.height())?))
+}
+
+/// An sRGB color with an alpha channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    /// The opacity, `0` being fully transparent
+    pub a: u8,
+}
+
+impl Color {
+    pub const BLACK: Self = Self::rgb(0, 0, 0);
+    pub const WHITE: Self = Self::rgb(0xFF, 0xFF, 0xFF);
+    pub const TRANSPARENT: Self = Self::rgba(0, 0, 0, 0);
+
+    /// Creates an opaque color
+    #[inline]
+    #[must_use]
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self::rgba(r, g, b, 0xFF)
+    }
+    #[inline]
+    #[must_use]
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+    /// Returns whether the color is fully transparent
+    #[inline]
+    #[must_use]
+    pub const fn is_transparent(self) -> bool {
+        self.a == 0
+    }
+    /// Returns the color as `#rrggbb`, ignoring its opacity
+    #[must_use]
+    pub fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
@@ -0,0 +1,454 @@
+//! Netpbm bitmaps, graymaps & pixmaps
+//!
+//! Every format can be written from a [`Symbol`], and read back as a
+//! grayscale [`Image`].
+use std::io::{self, Read, Write};
+
+use super::{is_dark_with_quiet_zone, scaled_size, Color, Symbol};
+
+#[cfg(test)]
+mod test;
+
+/// The longest line allowed in the plain formats
+const LINE_LENGTH: usize = 70;
+
+/// The Netpbm formats, by their magic number
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// `P1`, a bitmap written as ASCII `0` & `1`
+    PlainBitmap,
+    /// `P2`, a graymap written as ASCII decimal numbers
+    PlainGraymap,
+    /// `P3`, a pixmap written as ASCII decimal numbers
+    PlainPixmap,
+    /// `P4`, a bitmap packing eight pixels per byte
+    Bitmap,
+    /// `P5`, a graymap with a byte per pixel
+    Graymap,
+    /// `P6`, a pixmap with three bytes per pixel
+    Pixmap,
+}
+
+impl Format {
+    /// Returns the two byte magic number starting the file
+    #[inline]
+    #[must_use]
+    pub const fn magic(self) -> [u8; 2] {
+        [b'P', b'1' + self as u8]
+    }
+    #[inline]
+    #[must_use]
+    pub const fn from_magic(magic: [u8; 2]) -> Option<Self> {
+        use Format::*;
+        Some(match magic {
+            [b'P', b'1'] => PlainBitmap,
+            [b'P', b'2'] => PlainGraymap,
+            [b'P', b'3'] => PlainPixmap,
+            [b'P', b'4'] => Bitmap,
+            [b'P', b'5'] => Graymap,
+            [b'P', b'6'] => Pixmap,
+            _ => return None,
+        })
+    }
+    /// Returns whether the samples are written as ASCII
+    #[inline]
+    #[must_use]
+    pub const fn is_plain(self) -> bool {
+        matches!(
+            self,
+            Self::PlainBitmap | Self::PlainGraymap | Self::PlainPixmap
+        )
+    }
+    /// Returns whether this is one of the black & white bitmap formats,
+    /// which have no maximum value
+    #[inline]
+    #[must_use]
+    pub const fn is_bitmap(self) -> bool {
+        matches!(self, Self::PlainBitmap | Self::Bitmap)
+    }
+    /// Returns the number of samples per pixel
+    #[inline]
+    #[must_use]
+    pub const fn channels(self) -> usize {
+        match self {
+            Self::PlainPixmap | Self::Pixmap => 3,
+            _ => 1,
+        }
+    }
+}
+
+/// Renders symbols as Netpbm images
+///
+/// Bitmaps are always black on white, the colors only apply to graymaps
+/// & pixmaps, whose opacity is ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Netpbm {
+    format: Format,
+    quiet_zone: usize,
+    scale: usize,
+    dark: Color,
+    light: Color,
+}
+
+impl Netpbm {
+    /// Creates a renderer of the given format drawing black modules of
+    /// one pixel on white, with a quiet zone of 4 modules
+    #[inline]
+    #[must_use]
+    pub const fn new(format: Format) -> Self {
+        Self {
+            format,
+            quiet_zone: 4,
+            scale: 1,
+            dark: Color::BLACK,
+            light: Color::WHITE,
+        }
+    }
+    /// Sets the width of the light border around the symbol, in modules
+    #[inline]
+    #[must_use]
+    pub const fn with_quiet_zone(mut self, modules: usize) -> Self {
+        self.quiet_zone = modules;
+        self
+    }
+    /// Sets the width & height of a module in pixels
+    #[inline]
+    #[must_use]
+    pub const fn with_scale(mut self, pixels: usize) -> Self {
+        self.scale = pixels;
+        self
+    }
+    /// Sets the colors of the dark & light modules
+    #[inline]
+    #[must_use]
+    pub const fn with_colors(mut self, dark: Color, light: Color) -> Self {
+        self.dark = dark;
+        self.light = light;
+        self
+    }
+    /// Returns the width & height of the image in pixels, or [`None`] if
+    /// either overflows
+    #[must_use]
+    pub fn dimensions(&self, symbol: &impl Symbol) -> Option<(usize, usize)> {
+        scaled_size(symbol, self.quiet_zone, self.scale)
+    }
+    /// Writes the symbol as a complete image
+    ///
+    /// # Errors
+    ///
+    /// Any error returned by the writer, or [`io::ErrorKind::InvalidInput`]
+    /// if the width or height overflows
+    pub fn write(&self, symbol: &impl Symbol, mut writer: impl Write) -> io::Result<()> {
+        if self.dimensions(symbol).is_none() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid Netpbm size",
+            ));
+        }
+        writer.write_all(&self.encode(symbol))
+    }
+    /// Returns the symbol as a complete image
+    ///
+    /// # Panics
+    ///
+    /// If the width or height overflows
+    #[must_use]
+    pub fn encode(&self, symbol: &impl Symbol) -> Vec<u8> {
+        let (width, height) = self.dimensions(symbol).expect("invalid Netpbm size");
+        let [p, n] = self.format.magic();
+        let mut out = format!("{}{}\n{width} {height}\n", p as char, n as char).into_bytes();
+        if !self.format.is_bitmap() {
+            out.extend(b"255\n");
+        }
+        let dark = |x: usize, y: usize| {
+            is_dark_with_quiet_zone(symbol, self.quiet_zone, x / self.scale, y / self.scale)
+        };
+        let color = |x, y| if dark(x, y) { self.dark } else { self.light };
+        let mut plain = PlainWriter::new(&mut out);
+        for y in 0..height {
+            match self.format {
+                Format::PlainBitmap => {
+                    (0..width).for_each(|x| plain.push(if dark(x, y) { "1" } else { "0" }, ""));
+                }
+                Format::PlainGraymap => {
+                    (0..width).for_each(|x| plain.push(&luma(color(x, y)).to_string(), " "));
+                }
+                Format::PlainPixmap => {
+                    for Color { r, g, b, .. } in (0..width).map(|x| color(x, y)) {
+                        for sample in [r, g, b] {
+                            plain.push(&sample.to_string(), " ");
+                        }
+                    }
+                }
+                Format::Bitmap => {
+                    let mut line = vec![0; width.div_ceil(8)];
+                    for x in (0..width).filter(|&x| dark(x, y)) {
+                        line[x / 8] |= 0x80 >> (x % 8);
+                    }
+                    plain.out.extend(line);
+                }
+                Format::Graymap => plain.out.extend((0..width).map(|x| luma(color(x, y)))),
+                Format::Pixmap => {
+                    for Color { r, g, b, .. } in (0..width).map(|x| color(x, y)) {
+                        plain.out.extend([r, g, b]);
+                    }
+                }
+            }
+            // every row starts on a new line
+            plain.end_line();
+        }
+        out
+    }
+}
+
+/// Writes the ASCII samples of the plain formats, wrapping the lines
+struct PlainWriter<'a> {
+    out: &'a mut Vec<u8>,
+    line: usize,
+}
+
+impl<'a> PlainWriter<'a> {
+    const fn new(out: &'a mut Vec<u8>) -> Self {
+        Self { out, line: 0 }
+    }
+    /// Appends the sample, after the separator unless it starts a line
+    fn push(&mut self, sample: &str, separator: &str) {
+        if self.line > 0 && self.line + separator.len() + sample.len() > LINE_LENGTH {
+            self.end_line();
+        }
+        if self.line > 0 {
+            self.out.extend(separator.as_bytes());
+            self.line += separator.len();
+        }
+        self.out.extend(sample.as_bytes());
+        self.line += sample.len();
+    }
+    fn end_line(&mut self) {
+        if self.line > 0 {
+            self.out.push(b'\n');
+        }
+        self.line = 0;
+    }
+}
+
+/// Returns the Rec. 601 luma of the color
+#[allow(clippy::cast_possible_truncation)]
+fn luma(Color { r, g, b, .. }: Color) -> u8 {
+    ((299 * u32::from(r) + 587 * u32::from(g) + 114 * u32::from(b) + 500) / 1000) as u8
+}
+
+/// A grayscale image read from any Netpbm format
+///
+/// Pixmaps are converted to their luma, and every sample is scaled to a
+/// maximum value of `255`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    #[inline]
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+    #[inline]
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+    /// Returns the gray levels row by row, `0` being black
+    #[inline]
+    #[must_use]
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+    /// Returns the gray level at `(x, y)`
+    #[inline]
+    #[must_use]
+    pub fn get(&self, x: usize, y: usize) -> u8 {
+        self.pixels[y * self.width + x]
+    }
+}
+
+/// Pixels darker than middle gray are dark modules
+impl Symbol for Image {
+    #[inline]
+    fn width(&self) -> usize {
+        self.width
+    }
+    #[inline]
+    fn height(&self) -> usize {
+        self.height
+    }
+    #[inline]
+    fn is_dark(&self, x: usize, y: usize) -> bool {
+        self.get(x, y) < 0x80
+    }
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Reads an image of any of the Netpbm formats
+///
+/// # Errors
+///
+/// Any error returned by the reader, or [`io::ErrorKind::InvalidData`] if
+/// the image is malformed or truncated
+pub fn read(mut reader: impl Read) -> io::Result<Image> {
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data)?;
+    let magic = data
+        .get(..2)
+        .ok_or_else(|| invalid("missing magic number"))?;
+    let format =
+        Format::from_magic([magic[0], magic[1]]).ok_or_else(|| invalid("unknown magic number"))?;
+    let mut header = Header {
+        data: &data,
+        position: 2,
+    };
+    let width = header.number()?;
+    let height = header.number()?;
+    let max = if format.is_bitmap() {
+        1
+    } else {
+        header.number()?
+    };
+    if !(1..=0xFFFF).contains(&max) {
+        return Err(invalid("maximum value out of range"));
+    }
+    let samples = width
+        .checked_mul(height)
+        .and_then(|n| n.checked_mul(format.channels()))
+        .ok_or_else(|| invalid("image too large"))?;
+    let scale = |sample: usize| -> io::Result<u8> {
+        if sample > max {
+            return Err(invalid("sample exceeds the maximum value"));
+        }
+        u8::try_from((sample * 255 + max / 2) / max).map_err(|_| invalid("sample out of range"))
+    };
+
+    let values = match format {
+        Format::PlainBitmap => (0..samples)
+            .map(|_| match header.skip_whitespace() {
+                Some(b'0') => Ok(0xFF),
+                Some(b'1') => Ok(0),
+                _ => Err(invalid("truncated bitmap")),
+            })
+            .collect::<io::Result<Vec<_>>>()?,
+        Format::PlainGraymap | Format::PlainPixmap => (0..samples)
+            .map(|_| scale(header.number()?))
+            .collect::<io::Result<Vec<_>>>()?,
+        Format::Bitmap | Format::Graymap | Format::Pixmap => {
+            // a single whitespace separates the header from the raster
+            let raster = data.get(header.position + 1..).unwrap_or_default();
+            raw_samples(raster, format, (width, height), samples, max, scale)?
+        }
+    };
+    let pixels = if format.channels() == 3 {
+        values
+            .chunks_exact(3)
+            .map(|rgb| luma(Color::rgb(rgb[0], rgb[1], rgb[2])))
+            .collect()
+    } else {
+        values
+    };
+    Ok(Image {
+        width,
+        height,
+        pixels,
+    })
+}
+
+/// Unpacks the samples of the raw formats
+fn raw_samples(
+    raster: &[u8],
+    format: Format,
+    (width, height): (usize, usize),
+    samples: usize,
+    max: usize,
+    scale: impl Fn(usize) -> io::Result<u8>,
+) -> io::Result<Vec<u8>> {
+    if format == Format::Bitmap {
+        // no larger than the number of samples
+        let stride = width.div_ceil(8);
+        if raster.len() < stride * height {
+            return Err(invalid("truncated bitmap"));
+        }
+        return Ok((0..samples)
+            .map(|i| {
+                let (x, y) = (i % width, i / width);
+                let bit = raster[y * stride + x / 8] >> (7 - x % 8) & 1;
+                if bit == 1 {
+                    0
+                } else {
+                    0xFF
+                }
+            })
+            .collect());
+    }
+    let size = if max > 0xFF { 2 } else { 1 };
+    let length = samples
+        .checked_mul(size)
+        .ok_or_else(|| invalid("image too large"))?;
+    if raster.len() < length {
+        return Err(invalid("truncated raster"));
+    }
+    raster
+        .chunks_exact(size)
+        .take(samples)
+        .map(|sample| scale(sample.iter().fold(0, |n, &b| n << 8 | usize::from(b))))
+        .collect()
+}
+
+/// Parses the ASCII parts of a file
+struct Header<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl Header<'_> {
+    /// Skips whitespace & comments, returning the next byte and moving
+    /// past it
+    fn skip_whitespace(&mut self) -> Option<u8> {
+        loop {
+            let byte = *self.data.get(self.position)?;
+            self.position += 1;
+            match byte {
+                b'#' => {
+                    while self.data.get(self.position).is_some_and(|&b| b != b'\n') {
+                        self.position += 1;
+                    }
+                }
+                b' ' | b'\t' | b'\n' | b'\r' | 0x0B | 0x0C => {}
+                _ => return Some(byte),
+            }
+        }
+    }
+    /// Reads a decimal number, leaving the position on the byte after it
+    fn number(&mut self) -> io::Result<usize> {
+        let first = self
+            .skip_whitespace()
+            .ok_or_else(|| invalid("truncated header"))?;
+        let mut number = digit(first).ok_or_else(|| invalid("expected a number"))?;
+        while let Some(n) = self.data.get(self.position).copied().and_then(digit) {
+            number = number
+                .checked_mul(10)
+                .and_then(|number| number.checked_add(n))
+                .ok_or_else(|| invalid("number too large"))?;
+            self.position += 1;
+        }
+        Ok(number)
+    }
+}
+
+const fn digit(byte: u8) -> Option<usize> {
+    match byte {
+        b'0'..=b'9' => Some((byte - b'0') as usize),
+        _ => None,
+    }
+}
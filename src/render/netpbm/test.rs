@@ -0,0 +1,106 @@
+use crate::{
+    micro::{MicroCode, MicroVersion},
+    render::{
+        netpbm::{read, Format, Netpbm},
+        test::Tiny,
+        Color, Symbol,
+    },
+    ErrorCorrection,
+};
+
+const FORMATS: [Format; 6] = [
+    Format::PlainBitmap,
+    Format::PlainGraymap,
+    Format::PlainPixmap,
+    Format::Bitmap,
+    Format::Graymap,
+    Format::Pixmap,
+];
+
+#[test]
+fn magic() {
+    for format in FORMATS {
+        assert_eq!(Format::from_magic(format.magic()), Some(format));
+    }
+    assert_eq!(Format::Pixmap.magic(), *b"P6");
+    assert_eq!(Format::from_magic(*b"P7"), None);
+}
+
+#[test]
+fn writers() {
+    let encode = |format| Netpbm::new(format).with_quiet_zone(0).encode(&Tiny);
+    assert_eq!(encode(Format::PlainBitmap), b"P1\n3 2\n111\n100\n");
+    assert_eq!(encode(Format::Bitmap), b"P4\n3 2\n\xE0\x80");
+    assert_eq!(
+        encode(Format::PlainGraymap),
+        b"P2\n3 2\n255\n0 0 0\n0 255 255\n"
+    );
+    assert_eq!(encode(Format::Graymap), b"P5\n3 2\n255\n\0\0\0\0\xFF\xFF");
+
+    let red = Netpbm::new(Format::PlainPixmap)
+        .with_quiet_zone(0)
+        .with_colors(Color::rgb(255, 0, 0), Color::rgb(1, 2, 3));
+    assert_eq!(
+        red.encode(&Tiny),
+        b"P3\n3 2\n255\n255 0 0 255 0 0 255 0 0\n255 0 0 1 2 3 1 2 3\n"
+    );
+
+    // plain lines are wrapped at 70 characters
+    let wide = Netpbm::new(Format::PlainGraymap)
+        .with_scale(10)
+        .encode(&Tiny);
+    let wide = String::from_utf8(wide).unwrap();
+    assert!(wide.lines().all(|line| line.len() <= 70));
+    assert_eq!(wide.lines().nth(3).unwrap(), "255 ".repeat(16) + "255");
+
+    let huge = Netpbm::new(Format::Bitmap).with_scale(usize::MAX / 8);
+    assert_eq!(huge.dimensions(&Tiny), None);
+    assert_eq!(
+        huge.write(&Tiny, std::io::sink()).unwrap_err().kind(),
+        std::io::ErrorKind::InvalidInput
+    );
+}
+
+#[test]
+fn round_trip() {
+    let code = MicroCode::with_version("01234567", MicroVersion::M2, ErrorCorrection::L).unwrap();
+    for format in FORMATS {
+        let image = read(&Netpbm::new(format).with_scale(2).encode(&code)[..]).unwrap();
+        assert_eq!((image.width(), image.height()), (42, 42), "{format:?}");
+        for y in 0..42 {
+            for x in 0..42 {
+                let dark = (8..34).contains(&x)
+                    && (8..34).contains(&y)
+                    && code.is_dark(x / 2 - 4, y / 2 - 4);
+                assert_eq!(image.is_dark(x, y), dark, "{format:?} ({x}, {y})");
+            }
+        }
+    }
+}
+
+#[test]
+fn reader() {
+    let image = read(&b"P2 # comment\n2 1 # size\n 1000\n0 500"[..]).unwrap();
+    assert_eq!(image.pixels(), [0, 128]);
+    let image = read(&b"P5\n2 1\n65535\n\x00\x00\xFF\xFF"[..]).unwrap();
+    assert_eq!(image.pixels(), [0, 255]);
+    let image = read(&b"P3 1 1 255 255 255 0"[..]).unwrap();
+    assert_eq!(image.pixels(), [226]);
+    let image = read(&b"P1 2 2 0110"[..]).unwrap();
+    assert_eq!(image.pixels(), [255, 0, 0, 255]);
+
+    for malformed in [
+        &b""[..],
+        b"P7 1 1",
+        b"P1 2 2 011",
+        b"P2 1 1 255 256",
+        b"P4 9 1\n\x00",
+        b"P5 1 1 0\n\x00",
+        b"P6 1 1 255\n\x00\x00",
+        b"P2 x",
+        b"P5 4294967296 2147483648 65535\n\x00\x00",
+    ] {
+        let error = read(malformed).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    }
+}
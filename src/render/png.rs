@@ -0,0 +1,243 @@
+//! Portable Network Graphics output, without any dependencies
+//!
+//! Symbols are written as 1 bit images, either grayscale when black on
+//! white or with a two color palette otherwise.
+use std::io::{self, Write};
+
+use super::{is_dark_with_quiet_zone, scaled_size, Color, Symbol};
+
+mod deflate;
+#[cfg(test)]
+mod test;
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'];
+
+/// How the image data is stored inside its zlib stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Compression {
+    /// Uncompressed blocks, the fastest to write
+    Stored,
+    /// A single block compressed with the fixed Huffman codes
+    #[default]
+    Deflate,
+}
+
+/// Renders symbols as PNG images
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Png {
+    quiet_zone: usize,
+    scale: usize,
+    dark: Color,
+    light: Color,
+    compression: Compression,
+}
+
+impl Default for Png {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Png {
+    /// Creates a renderer drawing black modules of one pixel on white,
+    /// with a quiet zone of 4 modules
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            quiet_zone: 4,
+            scale: 1,
+            dark: Color::BLACK,
+            light: Color::WHITE,
+            compression: Compression::Deflate,
+        }
+    }
+    /// Sets the width of the light border around the symbol, in modules
+    #[inline]
+    #[must_use]
+    pub const fn with_quiet_zone(mut self, modules: usize) -> Self {
+        self.quiet_zone = modules;
+        self
+    }
+    /// Sets the width & height of a module in pixels
+    #[inline]
+    #[must_use]
+    pub const fn with_scale(mut self, pixels: usize) -> Self {
+        self.scale = pixels;
+        self
+    }
+    /// Sets the colors of the dark & light modules
+    ///
+    /// Anything but black on white is written with a palette, and a
+    /// transparency chunk if either color is translucent.
+    #[inline]
+    #[must_use]
+    pub const fn with_colors(mut self, dark: Color, light: Color) -> Self {
+        self.dark = dark;
+        self.light = light;
+        self
+    }
+    /// Sets how the image data is compressed
+    #[inline]
+    #[must_use]
+    pub const fn with_compression(mut self, compression: Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+    /// Returns the width & height of the image in pixels, or [`None`] if
+    /// either overflows
+    #[must_use]
+    pub fn dimensions(&self, symbol: &impl Symbol) -> Option<(usize, usize)> {
+        scaled_size(symbol, self.quiet_zone, self.scale)
+    }
+    /// Writes the symbol as a complete PNG file
+    ///
+    /// # Errors
+    ///
+    /// Any error returned by the writer, or [`io::ErrorKind::InvalidInput`]
+    /// if the image is empty or wider or taller than `2^31 - 1` pixels
+    pub fn write(&self, symbol: &impl Symbol, mut writer: impl Write) -> io::Result<()> {
+        let invalid = || io::Error::new(io::ErrorKind::InvalidInput, "invalid PNG size");
+        let (width, height) = self.dimensions(symbol).ok_or_else(invalid)?;
+        let dimension = |n: usize| {
+            u32::try_from(n)
+                .ok()
+                .filter(|n| (1..1 << 31).contains(n))
+                .ok_or_else(invalid)
+        };
+        let palette = (self.dark, self.light) != (Color::BLACK, Color::WHITE);
+
+        let mut header = Vec::with_capacity(13);
+        header.extend(dimension(width)?.to_be_bytes());
+        header.extend(dimension(height)?.to_be_bytes());
+        // bit depth, color type, compression, filter & interlace method
+        header.extend([1, if palette { 3 } else { 0 }, 0, 0, 0]);
+
+        writer.write_all(&SIGNATURE)?;
+        write_chunk(&mut writer, *b"IHDR", &header)?;
+        if palette {
+            let [light, dark] = [self.light, self.dark];
+            let plte = [light.r, light.g, light.b, dark.r, dark.g, dark.b];
+            write_chunk(&mut writer, *b"PLTE", &plte)?;
+            if light.a < 0xFF || dark.a < 0xFF {
+                write_chunk(&mut writer, *b"tRNS", &[light.a, dark.a])?;
+            }
+        }
+        let scanlines = self.scanlines(symbol, (width, height), !palette);
+        let data = zlib(&scanlines, self.compression);
+        write_chunk(&mut writer, *b"IDAT", &data)?;
+        write_chunk(&mut writer, *b"IEND", &[])
+    }
+    /// Returns the symbol as a complete PNG file
+    ///
+    /// # Panics
+    ///
+    /// If the image is empty or wider or taller than `2^31 - 1` pixels
+    #[must_use]
+    pub fn encode(&self, symbol: &impl Symbol) -> Vec<u8> {
+        let mut png = Vec::new();
+        self.write(symbol, &mut png).expect("invalid PNG size");
+        png
+    }
+    /// Returns the unfiltered scanlines, each preceded by its filter type
+    ///
+    /// A set bit is a dark module when using the palette, and a light one
+    /// in grayscale.
+    fn scanlines(
+        &self,
+        symbol: &impl Symbol,
+        (width, height): (usize, usize),
+        grayscale: bool,
+    ) -> Vec<u8> {
+        let stride = width.div_ceil(8) + 1;
+        let mut data = vec![0; stride * height];
+        for (y, line) in data.chunks_exact_mut(stride).enumerate() {
+            for x in 0..width {
+                let dark = is_dark_with_quiet_zone(
+                    symbol,
+                    self.quiet_zone,
+                    x / self.scale,
+                    y / self.scale,
+                );
+                if dark != grayscale {
+                    line[1 + x / 8] |= 0x80 >> (x % 8);
+                }
+            }
+        }
+        data
+    }
+}
+
+/// Writes a chunk with its length & CRC
+fn write_chunk(writer: &mut impl Write, kind: [u8; 4], data: &[u8]) -> io::Result<()> {
+    let length = u32::try_from(data.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "PNG chunk too long"))?;
+    writer.write_all(&length.to_be_bytes())?;
+    writer.write_all(&kind)?;
+    writer.write_all(data)?;
+    let crc = !update_crc32(update_crc32(!0, &kind), data);
+    writer.write_all(&crc.to_be_bytes())
+}
+
+/// Wraps the data in a zlib stream
+fn zlib(data: &[u8], compression: Compression) -> Vec<u8> {
+    // deflate with a 32K window, no dictionary & the default level
+    let mut stream = vec![0x78, 0x9C];
+    match compression {
+        Compression::Stored => deflate::stored(data, &mut stream),
+        Compression::Deflate => deflate::fixed(data, &mut stream),
+    }
+    stream.extend(adler32(data).to_be_bytes());
+    stream
+}
+
+/// The lookup table of the reflected CRC-32 polynomial `0xEDB88320`
+#[allow(clippy::cast_possible_truncation)]
+const CRC_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                0xEDB8_8320 ^ crc >> 1
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+#[allow(clippy::cast_possible_truncation)]
+fn update_crc32(crc: u32, data: &[u8]) -> u32 {
+    data.iter().fold(crc, |crc, &byte| {
+        CRC_TABLE[usize::from(crc as u8 ^ byte)] ^ crc >> 8
+    })
+}
+
+/// Returns the CRC-32 checksum used by PNG chunks
+#[inline]
+#[must_use]
+pub fn crc32(data: &[u8]) -> u32 {
+    !update_crc32(!0, data)
+}
+
+/// Returns the Adler-32 checksum ending a zlib stream
+#[must_use]
+pub fn adler32(data: &[u8]) -> u32 {
+    const MOD: u32 = 65521;
+    // the largest chunk for which the sums cannot overflow
+    let (a, b) = data.chunks(5552).fold((1, 0), |(mut a, mut b), chunk| {
+        for &byte in chunk {
+            a += u32::from(byte);
+            b += a;
+        }
+        (a % MOD, b % MOD)
+    });
+    b << 16 | a
+}
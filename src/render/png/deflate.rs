@@ -0,0 +1,184 @@
+//! A minimal deflate compressor
+//!
+//! Blocks are either stored or use the fixed Huffman codes, with a greedy
+//! LZ77 search following hash chains of three byte prefixes.
+
+/// The largest distance a match may refer back
+const WINDOW: usize = 32 * 1024;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+const HASH_BITS: u32 = 15;
+/// The number of earlier positions tried for every match
+const MAX_CHAIN: usize = 64;
+
+pub(super) const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+pub(super) const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+pub(super) const DISTANCE_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+pub(super) const DISTANCE_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+
+/// Writes bits least significant first, as deflate packs them
+struct BitWriter<'a> {
+    out: &'a mut Vec<u8>,
+    bits: u32,
+    count: u32,
+}
+
+impl<'a> BitWriter<'a> {
+    const fn new(out: &'a mut Vec<u8>) -> Self {
+        Self {
+            out,
+            bits: 0,
+            count: 0,
+        }
+    }
+    /// Writes the `width` low bits of `data`
+    #[allow(clippy::cast_possible_truncation)]
+    fn write(&mut self, data: u32, width: u32) {
+        self.bits |= data << self.count;
+        self.count += width;
+        while self.count >= 8 {
+            self.out.push(self.bits as u8);
+            self.bits >>= 8;
+            self.count -= 8;
+        }
+    }
+    /// Writes a Huffman code, which is packed most significant bit first
+    fn write_code(&mut self, code: u32, width: u32) {
+        self.write(code.reverse_bits() >> (32 - width), width);
+    }
+    /// Pads the last byte with zeros
+    #[allow(clippy::cast_possible_truncation)]
+    fn flush(&mut self) {
+        if self.count > 0 {
+            self.out.push(self.bits as u8);
+        }
+        self.bits = 0;
+        self.count = 0;
+    }
+    /// Writes a literal byte or a length symbol, `256` ending the block
+    fn write_literal(&mut self, symbol: u16) {
+        let symbol = u32::from(symbol);
+        match symbol {
+            0..=143 => self.write_code(0x30 + symbol, 8),
+            144..=255 => self.write_code(0x190 + symbol - 144, 9),
+            256..=279 => self.write_code(symbol - 256, 7),
+            _ => self.write_code(0xC0 + symbol - 280, 8),
+        }
+    }
+    #[allow(clippy::cast_possible_truncation)]
+    fn write_match(&mut self, length: usize, distance: usize) {
+        let (length, distance) = (length as u16, distance as u16);
+        let code = LENGTH_BASE.partition_point(|&base| base <= length) - 1;
+        self.write_literal(257 + code as u16);
+        self.write(
+            u32::from(length - LENGTH_BASE[code]),
+            u32::from(LENGTH_EXTRA[code]),
+        );
+        let code = DISTANCE_BASE.partition_point(|&base| base <= distance) - 1;
+        self.write_code(code as u32, 5);
+        self.write(
+            u32::from(distance - DISTANCE_BASE[code]),
+            u32::from(DISTANCE_EXTRA[code]),
+        );
+    }
+}
+
+/// Earlier positions of the same three byte prefix hash
+struct Chains {
+    /// The latest position of every hash
+    head: Vec<usize>,
+    /// The previous position sharing the hash of every position in the
+    /// window
+    previous: Vec<usize>,
+}
+
+impl Chains {
+    fn new() -> Self {
+        Self {
+            head: vec![usize::MAX; 1 << HASH_BITS],
+            previous: vec![usize::MAX; WINDOW],
+        }
+    }
+    fn insert(&mut self, position: usize, hash: usize) {
+        self.previous[position % WINDOW] = self.head[hash];
+        self.head[hash] = position;
+    }
+}
+
+/// Appends the data as uncompressed blocks of at most 65535 bytes
+#[allow(clippy::cast_possible_truncation)]
+pub(super) fn stored(data: &[u8], out: &mut Vec<u8>) {
+    let mut blocks = data.chunks(0xFFFF).peekable();
+    if blocks.peek().is_none() {
+        out.extend([1, 0, 0, 0xFF, 0xFF]);
+    }
+    while let Some(block) = blocks.next() {
+        // BFINAL & BTYPE 00, padded to the byte boundary
+        out.push(u8::from(blocks.peek().is_none()));
+        let length = block.len() as u16;
+        out.extend(length.to_le_bytes());
+        out.extend((!length).to_le_bytes());
+        out.extend_from_slice(block);
+    }
+}
+
+/// Appends the data as a single block using the fixed Huffman codes
+pub(super) fn fixed(data: &[u8], out: &mut Vec<u8>) {
+    let mut writer = BitWriter::new(out);
+    // BFINAL & BTYPE 01
+    writer.write(0b011, 3);
+    let hash = |i: usize| {
+        let prefix = u32::from_le_bytes([data[i], data[i + 1], data[i + 2], 0]);
+        (prefix.wrapping_mul(0x9E37_79B1) >> (32 - HASH_BITS)) as usize
+    };
+    let mut chains = Chains::new();
+    let mut i = 0;
+    while i < data.len() {
+        if i + MIN_MATCH > data.len() {
+            writer.write_literal(u16::from(data[i]));
+            i += 1;
+            continue;
+        }
+        let (mut length, mut distance) = (0, 0);
+        let mut candidate = chains.head[hash(i)];
+        for _ in 0..MAX_CHAIN {
+            if candidate == usize::MAX || i - candidate > WINDOW {
+                break;
+            }
+            let found = data[i..]
+                .iter()
+                .zip(&data[candidate..])
+                .take(MAX_MATCH)
+                .take_while(|(a, b)| a == b)
+                .count();
+            if found > length {
+                (length, distance) = (found, i - candidate);
+            }
+            candidate = chains.previous[candidate % WINDOW];
+        }
+        if length < MIN_MATCH {
+            chains.insert(i, hash(i));
+            writer.write_literal(u16::from(data[i]));
+            i += 1;
+            continue;
+        }
+        writer.write_match(length, distance);
+        for j in i..(i + length).min(data.len() - MIN_MATCH + 1) {
+            chains.insert(j, hash(j));
+        }
+        i += length;
+    }
+    writer.write_literal(256);
+    writer.flush();
+}
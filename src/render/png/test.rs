@@ -0,0 +1,185 @@
+use crate::{
+    matrix::QrMatrix,
+    render::{
+        png::{adler32, crc32, zlib, Compression, Png},
+        Color, Symbol,
+    },
+    ErrorCorrection, QRCodeVersion, QrCode,
+};
+
+/// Reads bits least significant first
+struct BitReader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl BitReader<'_> {
+    fn bit(&mut self) -> u32 {
+        let bit = self.data[self.position / 8] >> (self.position % 8) & 1;
+        self.position += 1;
+        u32::from(bit)
+    }
+    fn bits(&mut self, width: u8) -> usize {
+        (0..width).map(|i| (self.bit() as usize) << i).sum()
+    }
+    /// Reads a symbol of the fixed literal/length code
+    fn literal(&mut self) -> usize {
+        let mut code = (0..7).fold(0, |code, _| code << 1 | self.bit());
+        if code < 24 {
+            return 256 + code as usize;
+        }
+        code = code << 1 | self.bit();
+        match code {
+            0x30..=0xBF => return code as usize - 0x30,
+            0xC0..=0xC7 => return code as usize - 0xC0 + 280,
+            _ => {}
+        }
+        (code << 1 | self.bit()) as usize - 0x190 + 144
+    }
+}
+
+/// Decompresses a zlib stream of stored & fixed Huffman blocks
+fn inflate(stream: &[u8]) -> Vec<u8> {
+    use super::deflate::{DISTANCE_BASE, DISTANCE_EXTRA, LENGTH_BASE, LENGTH_EXTRA};
+    assert_eq!(u16::from_be_bytes([stream[0], stream[1]]) % 31, 0);
+    let mut reader = BitReader {
+        data: &stream[2..],
+        position: 0,
+    };
+    let mut out = Vec::new();
+    loop {
+        let last = reader.bit() == 1;
+        match reader.bits(2) {
+            0 => {
+                let start = reader.position.div_ceil(8);
+                let block = &reader.data[start..];
+                let length = usize::from(u16::from_le_bytes([block[0], block[1]]));
+                assert_eq!(
+                    length,
+                    usize::from(!u16::from_le_bytes([block[2], block[3]]))
+                );
+                out.extend_from_slice(&block[4..4 + length]);
+                reader.position = (start + 4 + length) * 8;
+            }
+            1 => loop {
+                let symbol = reader.literal();
+                match symbol {
+                    0..=255 => out.push(u8::try_from(symbol).unwrap()),
+                    256 => break,
+                    _ => {
+                        let code = symbol - 257;
+                        let length =
+                            usize::from(LENGTH_BASE[code]) + reader.bits(LENGTH_EXTRA[code]);
+                        let code = (0..5).fold(0, |code, _| code << 1 | reader.bit()) as usize;
+                        let distance =
+                            usize::from(DISTANCE_BASE[code]) + reader.bits(DISTANCE_EXTRA[code]);
+                        for _ in 0..length {
+                            out.push(out[out.len() - distance]);
+                        }
+                    }
+                }
+            },
+            kind => panic!("unexpected block type {kind}"),
+        }
+        if last {
+            break;
+        }
+    }
+    let end = reader.position.div_ceil(8);
+    let checksum = &reader.data[end..end + 4];
+    assert_eq!(
+        u32::from_be_bytes(checksum.try_into().unwrap()),
+        adler32(&out)
+    );
+    out
+}
+
+/// Splits a PNG file into its chunks, checking their CRCs
+fn chunks(png: &[u8]) -> Vec<([u8; 4], &[u8])> {
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1A\n");
+    let mut rest = &png[8..];
+    let mut chunks = Vec::new();
+    while !rest.is_empty() {
+        let length = u32::from_be_bytes(rest[..4].try_into().unwrap()) as usize;
+        let (kind, data) = (&rest[4..8], &rest[8..8 + length]);
+        let crc = u32::from_be_bytes(rest[8 + length..12 + length].try_into().unwrap());
+        assert_eq!(crc32(&rest[4..8 + length]), crc);
+        chunks.push((kind.try_into().unwrap(), data));
+        rest = &rest[12 + length..];
+    }
+    chunks
+}
+
+#[test]
+fn checksums() {
+    assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    assert_eq!(crc32(b""), 0);
+    assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+    assert_eq!(adler32(&vec![0xFF; 100_000]), 0x149A_302C);
+}
+
+#[test]
+fn compression() {
+    let text = b"abracadabra abracadabra abracadabra".repeat(100);
+    let noise = (0..70_000_u32)
+        .map(|i| (i.wrapping_mul(2_654_435_761) >> 24) as u8)
+        .collect::<Vec<_>>();
+    for data in [&b""[..], b"ab", &text, &noise] {
+        for compression in [Compression::Stored, Compression::Deflate] {
+            assert_eq!(inflate(&zlib(data, compression)), data, "{compression:?}");
+        }
+    }
+    assert!(zlib(&text, Compression::Deflate).len() < text.len() / 10);
+}
+
+#[test]
+fn grayscale() {
+    let code = QrCode::new("HELLO WORLD", ErrorCorrection::Q).unwrap();
+    let png = Png::new().with_quiet_zone(2).with_scale(3).encode(&code);
+    let chunks = chunks(&png);
+    let kinds = chunks.iter().map(|(kind, _)| kind).collect::<Vec<_>>();
+    assert_eq!(kinds, [b"IHDR", b"IDAT", b"IEND"]);
+    // 25 modules of 3 pixels, 1 bit grayscale
+    assert_eq!(chunks[0].1, [0, 0, 0, 75, 0, 0, 0, 75, 1, 0, 0, 0, 0]);
+
+    let pixels = inflate(chunks[1].1);
+    let stride = 1 + 75_usize.div_ceil(8);
+    assert_eq!(pixels.len(), 75 * stride);
+    for (y, line) in pixels.chunks(stride).enumerate() {
+        assert_eq!(line[0], 0, "filter type");
+        for x in 0..75 {
+            let light = line[1 + x / 8] >> (7 - x % 8) & 1 == 1;
+            let (x, y) = (x / 3, y / 3);
+            let module = (2..23).contains(&x) && (2..23).contains(&y) && code.is_dark(x - 2, y - 2);
+            assert_eq!(light, !module, "({x}, {y})");
+        }
+    }
+}
+
+#[test]
+fn palette() {
+    let matrix = QrMatrix::new(QRCodeVersion::V1);
+    let png = Png::new()
+        .with_colors(Color::rgb(0x10, 0x20, 0x30), Color::TRANSPARENT)
+        .with_compression(Compression::Stored)
+        .encode(&matrix);
+    let chunks = chunks(&png);
+    assert_eq!(chunks[0].1[8..10], [1, 3]);
+    assert_eq!(chunks[1], (*b"PLTE", &[0, 0, 0, 0x10, 0x20, 0x30][..]));
+    assert_eq!(chunks[2], (*b"tRNS", &[0, 0xFF][..]));
+    // the quiet zone is left as the light palette entry
+    let pixels = inflate(chunks[3].1);
+    assert!(pixels[..4 * 5].iter().all(|&byte| byte == 0));
+    // the top edge of the finder pattern
+    assert_eq!(pixels[4 * 5 + 1..4 * 5 + 3], [0b0000_1111, 0b1110_0000]);
+
+    let empty = Png::new().with_quiet_zone(0).with_scale(0);
+    assert!(empty.write(&matrix, std::io::sink()).is_err());
+    // a size overflowing is reported rather than panicking
+    let huge = Png::new().with_scale(usize::MAX / 8);
+    assert_eq!(huge.dimensions(&matrix), None);
+    assert_eq!(
+        huge.write(&matrix, std::io::sink()).unwrap_err().kind(),
+        std::io::ErrorKind::InvalidInput
+    );
+}
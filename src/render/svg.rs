@@ -0,0 +1,135 @@
+//! Scalable Vector Graphics output
+use std::fmt::Write;
+
+use super::{is_dark_with_quiet_zone, Color, Symbol};
+
+#[cfg(test)]
+mod test;
+
+/// Renders symbols as SVG documents
+///
+/// Every dark module is drawn by a single `<path>`, merging horizontal
+/// runs of modules, on top of a background `<rect>` which is left out
+/// when transparent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Svg {
+    quiet_zone: usize,
+    module_size: usize,
+    dark: Color,
+    light: Color,
+}
+
+impl Default for Svg {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Svg {
+    /// Creates a renderer drawing black modules of one pixel on white,
+    /// with a quiet zone of 4 modules
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            quiet_zone: 4,
+            module_size: 1,
+            dark: Color::BLACK,
+            light: Color::WHITE,
+        }
+    }
+    /// Sets the width of the light border around the symbol, in modules
+    #[inline]
+    #[must_use]
+    pub const fn with_quiet_zone(mut self, modules: usize) -> Self {
+        self.quiet_zone = modules;
+        self
+    }
+    /// Sets the width & height of a module in pixels
+    #[inline]
+    #[must_use]
+    pub const fn with_module_size(mut self, pixels: usize) -> Self {
+        self.module_size = pixels;
+        self
+    }
+    /// Sets the colors of the dark & light modules
+    #[inline]
+    #[must_use]
+    pub const fn with_colors(mut self, dark: Color, light: Color) -> Self {
+        self.dark = dark;
+        self.light = light;
+        self
+    }
+    /// Renders the symbol as a standalone SVG document
+    ///
+    /// The view box is measured in modules, quiet zone included, and is
+    /// scaled to the module size by the width & height.
+    #[must_use]
+    pub fn render(&self, symbol: &impl Symbol) -> String {
+        let width = symbol.width() + 2 * self.quiet_zone;
+        let height = symbol.height() + 2 * self.quiet_zone;
+        let mut svg = String::with_capacity(256 + width * height);
+        // writing to a string never fails
+        let _ = write!(
+            svg,
+            concat!(
+                r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+                "\n",
+                r#"<svg xmlns="http://www.w3.org/2000/svg" version="1.1" width="{}" height="{}" viewBox="0 0 {} {}" shape-rendering="crispEdges">"#,
+                "\n",
+            ),
+            width * self.module_size,
+            height * self.module_size,
+            width,
+            height,
+        );
+        if !self.light.is_transparent() {
+            let _ = writeln!(
+                svg,
+                r#"<rect width="100%" height="100%" fill="{}"{}/>"#,
+                self.light.hex(),
+                opacity(self.light),
+            );
+        }
+        let _ = write!(
+            svg,
+            r#"<path fill="{}"{} d=""#,
+            self.dark.hex(),
+            opacity(self.dark)
+        );
+        svg.push_str(&path(symbol, self.quiet_zone));
+        svg.push_str("\"/>\n</svg>\n");
+        svg
+    }
+}
+
+/// Returns the `fill-opacity` attribute of a translucent color
+fn opacity(color: Color) -> String {
+    match color.a {
+        0xFF => String::new(),
+        a => format!(r#" fill-opacity="{:.3}""#, f32::from(a) / 255.0),
+    }
+}
+
+/// Returns the path data drawing every dark module, as one rectangle per
+/// horizontal run
+fn path(symbol: &impl Symbol, quiet_zone: usize) -> String {
+    let width = symbol.width() + 2 * quiet_zone;
+    let height = symbol.height() + 2 * quiet_zone;
+    let mut d = String::new();
+    for y in 0..height {
+        let mut x = 0;
+        while x < width {
+            if !is_dark_with_quiet_zone(symbol, quiet_zone, x, y) {
+                x += 1;
+                continue;
+            }
+            let start = x;
+            while x < width && is_dark_with_quiet_zone(symbol, quiet_zone, x, y) {
+                x += 1;
+            }
+            let _ = write!(d, "M{start},{y}h{}v1h-{}z", x - start, x - start);
+        }
+    }
+    d
+}
@@ -0,0 +1,66 @@
+use crate::{
+    matrix::QrMatrix,
+    render::{svg::Svg, test::Tiny, Color},
+    ErrorCorrection, QRCodeVersion, QrCode,
+};
+
+#[test]
+fn document() {
+    let svg = Svg::new()
+        .with_quiet_zone(1)
+        .with_module_size(10)
+        .render(&Tiny);
+    assert_eq!(
+        svg,
+        concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" version=\"1.1\" width=\"50\" height=\"40\" ",
+            "viewBox=\"0 0 5 4\" shape-rendering=\"crispEdges\">\n",
+            "<rect width=\"100%\" height=\"100%\" fill=\"#ffffff\"/>\n",
+            "<path fill=\"#000000\" d=\"M1,1h3v1h-3zM1,2h1v1h-1z\"/>\n",
+            "</svg>\n",
+        )
+    );
+}
+
+#[test]
+fn colors() {
+    let svg = Svg::new()
+        .with_colors(Color::rgba(0x12, 0x34, 0xAB, 0x80), Color::TRANSPARENT)
+        .render(&Tiny);
+    assert!(!svg.contains("<rect"));
+    assert!(svg.contains(r##"<path fill="#1234ab" fill-opacity="0.502" d="M4,4h3v1h-3z"##));
+    assert_eq!(Color::rgb(255, 0, 16).hex(), "#ff0010");
+}
+
+#[test]
+fn single_path() {
+    let code = QrCode::new("HELLO WORLD", ErrorCorrection::M).unwrap();
+    let svg = Svg::default().render(&code);
+    assert_eq!(svg.matches("<path").count(), 1);
+    assert!(svg.contains(r#"viewBox="0 0 29 29""#));
+    // the top left finder pattern starts after the quiet zone
+    assert!(svg.contains(r#"d="M4,4h7v1h-7z"#));
+
+    let matrix = QrMatrix::new(QRCodeVersion::V1);
+    let dark = (0..21)
+        .flat_map(|y| (0..21).map(move |x| (x, y)))
+        .filter(|&(x, y)| matrix.get(x, y))
+        .count();
+    // every run adds up to the number of dark modules
+    let svg = Svg::new().render(&matrix);
+    let d = svg
+        .split(" d=\"")
+        .nth(1)
+        .unwrap()
+        .split('"')
+        .next()
+        .unwrap();
+    let drawn: usize = d
+        .split('h')
+        .skip(1)
+        .step_by(2)
+        .map(|run| run.split('v').next().unwrap().parse::<usize>().unwrap())
+        .sum();
+    assert_eq!(drawn, dark);
+}
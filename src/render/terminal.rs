@@ -0,0 +1,109 @@
+//! Text output for terminals
+use super::{is_dark_with_quiet_zone, Symbol};
+
+#[cfg(test)]
+mod test;
+
+/// How modules are drawn as text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Style {
+    /// Unicode half blocks, fitting two rows of modules in a line
+    #[default]
+    HalfBlocks,
+    /// `##` for dark modules and two spaces for light ones
+    Ascii,
+    /// Two spaces per module on a black or white ANSI background
+    Ansi,
+}
+
+/// Renders symbols as text
+///
+/// By default the characters draw the dark modules, as suits a terminal
+/// with dark text on a light background. For light text on a dark
+/// background the output should be [inverted](Terminal::with_invert).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Terminal {
+    style: Style,
+    quiet_zone: usize,
+    invert: bool,
+}
+
+impl Default for Terminal {
+    fn default() -> Self {
+        Self::new(Style::default())
+    }
+}
+
+impl Terminal {
+    /// Creates a renderer of the given style with a quiet zone of 4
+    /// modules
+    #[inline]
+    #[must_use]
+    pub const fn new(style: Style) -> Self {
+        Self {
+            style,
+            quiet_zone: 4,
+            invert: false,
+        }
+    }
+    /// Sets the width of the light border around the symbol, in modules
+    #[inline]
+    #[must_use]
+    pub const fn with_quiet_zone(mut self, modules: usize) -> Self {
+        self.quiet_zone = modules;
+        self
+    }
+    /// Sets whether the characters, or ANSI black background, draw the
+    /// light modules instead of the dark ones
+    #[inline]
+    #[must_use]
+    pub const fn with_invert(mut self, invert: bool) -> Self {
+        self.invert = invert;
+        self
+    }
+    /// Renders the symbol with every line ending in a newline
+    ///
+    /// Half blocks treat the row below an odd height as light.
+    #[must_use]
+    pub fn render(&self, symbol: &impl Symbol) -> String {
+        let width = symbol.width() + 2 * self.quiet_zone;
+        let height = symbol.height() + 2 * self.quiet_zone;
+        // whether the module is drawn, rather than left as the background
+        let ink = |x, y| is_dark_with_quiet_zone(symbol, self.quiet_zone, x, y) != self.invert;
+        let mut text = String::new();
+        match self.style {
+            Style::HalfBlocks => {
+                for y in (0..height).step_by(2) {
+                    text.extend((0..width).map(|x| match (ink(x, y), ink(x, y + 1)) {
+                        (true, true) => '█',
+                        (true, false) => '▀',
+                        (false, true) => '▄',
+                        (false, false) => ' ',
+                    }));
+                    text.push('\n');
+                }
+            }
+            Style::Ascii => {
+                for y in 0..height {
+                    text.extend((0..width).map(|x| if ink(x, y) { "##" } else { "  " }));
+                    text.push('\n');
+                }
+            }
+            Style::Ansi => {
+                for y in 0..height {
+                    let mut current = None;
+                    for x in 0..width {
+                        let black = ink(x, y);
+                        if current != Some(black) {
+                            text.push_str(if black { "\x1b[40m" } else { "\x1b[47m" });
+                            current = Some(black);
+                        }
+                        text.push_str("  ");
+                    }
+                    text.push_str("\x1b[0m\n");
+                }
+            }
+        }
+        text
+    }
+}
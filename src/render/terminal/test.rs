@@ -0,0 +1,67 @@
+use crate::{
+    render::{
+        terminal::{Style, Terminal},
+        Symbol,
+    },
+    ErrorCorrection, QrCode,
+};
+
+/// A 3x3 symbol with a dark diagonal
+struct Diagonal;
+
+impl Symbol for Diagonal {
+    fn width(&self) -> usize {
+        3
+    }
+    fn height(&self) -> usize {
+        3
+    }
+    fn is_dark(&self, x: usize, y: usize) -> bool {
+        x == y
+    }
+}
+
+#[test]
+fn half_blocks() {
+    let terminal = Terminal::default().with_quiet_zone(0);
+    assert_eq!(terminal.render(&Diagonal), "▀▄ \n  ▀\n");
+    assert_eq!(terminal.with_invert(true).render(&Diagonal), "▄▀█\n██▄\n");
+    let bordered = Terminal::new(Style::HalfBlocks).with_quiet_zone(1);
+    assert_eq!(bordered.render(&Diagonal), " ▄   \n  ▀▄ \n     \n");
+}
+
+#[test]
+fn ascii() {
+    let terminal = Terminal::new(Style::Ascii).with_quiet_zone(0);
+    assert_eq!(terminal.render(&Diagonal), "##    \n  ##  \n    ##\n");
+    let inverted = terminal.with_invert(true).with_quiet_zone(1);
+    assert_eq!(
+        inverted.render(&Diagonal).lines().next(),
+        Some("##########")
+    );
+    assert_eq!(
+        inverted.render(&Diagonal).lines().nth(1),
+        Some("##  ######")
+    );
+}
+
+#[test]
+fn ansi() {
+    let terminal = Terminal::new(Style::Ansi).with_quiet_zone(0);
+    let (black, white, reset) = ("\x1b[40m", "\x1b[47m", "\x1b[0m\n");
+    assert_eq!(
+        terminal.render(&Diagonal),
+        format!(
+            "{black}  {white}    {reset}{white}  {black}  {white}  {reset}{white}    {black}  {reset}"
+        )
+    );
+    assert!(terminal
+        .with_invert(true)
+        .render(&Diagonal)
+        .starts_with(&format!("{white}  {black}    {reset}")));
+
+    let code = QrCode::new("HELLO", ErrorCorrection::L).unwrap();
+    let text = Terminal::new(Style::Ansi).render(&code);
+    assert_eq!(text.lines().count(), 29);
+    assert!(text.lines().all(|line| line.matches("  ").count() == 29));
+}
@@ -0,0 +1,26 @@
+use crate::render::{is_dark_with_quiet_zone, scaled_size, Symbol};
+
+/// A 3x2 symbol, dark in the top row and the bottom left corner
+pub(super) struct Tiny;
+
+impl Symbol for Tiny {
+    fn width(&self) -> usize {
+        3
+    }
+    fn height(&self) -> usize {
+        2
+    }
+    fn is_dark(&self, x: usize, y: usize) -> bool {
+        y == 0 || x == 0
+    }
+}
+
+#[test]
+fn quiet_zone() {
+    assert!(is_dark_with_quiet_zone(&Tiny, 1, 1, 1));
+    assert!(!is_dark_with_quiet_zone(&Tiny, 1, 0, 1));
+    assert!(!is_dark_with_quiet_zone(&Tiny, 1, 4, 1));
+    assert_eq!(scaled_size(&Tiny, 1, 10), Some((50, 40)));
+    assert_eq!(scaled_size(&Tiny, usize::MAX / 2, 1), None);
+    assert_eq!(scaled_size(&Tiny, 0, usize::MAX / 2), None);
+}
@@ -0,0 +1,1026 @@
+//! Rectangular Micro QR Code (rMQR), the symbols from R7x43 to R17x139
+//! fitting in a narrow strip
+use crate::{
+    byte_stream::Bytes,
+    encoding::{data_bit_length, interleave_blocks},
+    format::{bch_remainder, VERSION_GENERATOR},
+    mask::Mask,
+    reed_solomon::split_blocks,
+    segment::{self, encode_eci_designator, optimize_with, Segment},
+    BlockSplit, EncodeError, EncodingMode, ErrorCorrection, GroupSplit,
+};
+
+#[cfg(test)]
+mod test;
+
+/// Applied to the format information next to the finder pattern
+const FORMAT_MASK_FINDER: u32 = 0b01_1111_1010_1011_0010;
+/// Applied to the format information next to the finder sub pattern
+const FORMAT_MASK_SUB_FINDER: u32 = 0b10_0000_1010_0111_1011;
+
+/// The 32 rMQR versions, named after their height & width in modules
+///
+/// Only [`ErrorCorrection::M`] and [`ErrorCorrection::H`] are supported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RmqrVersion {
+    /// Total bytes: 13
+    R7x43,
+    /// Total bytes: 21
+    R7x59,
+    /// Total bytes: 32
+    R7x77,
+    /// Total bytes: 44
+    R7x99,
+    /// Total bytes: 68
+    R7x139,
+    /// Total bytes: 21
+    R9x43,
+    /// Total bytes: 33
+    R9x59,
+    /// Total bytes: 49
+    R9x77,
+    /// Total bytes: 66
+    R9x99,
+    /// Total bytes: 99
+    R9x139,
+    /// Total bytes: 15
+    R11x27,
+    /// Total bytes: 31
+    R11x43,
+    /// Total bytes: 47
+    R11x59,
+    /// Total bytes: 67
+    R11x77,
+    /// Total bytes: 89
+    R11x99,
+    /// Total bytes: 132
+    R11x139,
+    /// Total bytes: 21
+    R13x27,
+    /// Total bytes: 41
+    R13x43,
+    /// Total bytes: 60
+    R13x59,
+    /// Total bytes: 85
+    R13x77,
+    /// Total bytes: 113
+    R13x99,
+    /// Total bytes: 166
+    R13x139,
+    /// Total bytes: 51
+    R15x43,
+    /// Total bytes: 74
+    R15x59,
+    /// Total bytes: 103
+    R15x77,
+    /// Total bytes: 136
+    R15x99,
+    /// Total bytes: 199
+    R15x139,
+    /// Total bytes: 61
+    R17x43,
+    /// Total bytes: 88
+    R17x59,
+    /// Total bytes: 122
+    R17x77,
+    /// Total bytes: 160
+    R17x99,
+    /// Total bytes: 232
+    R17x139,
+}
+
+macro_rules! impl_rmqr {
+    (
+        $(
+            (
+                $version: ident, $height: literal, $width: literal, $remainder: literal,
+                [$numeric: literal, $alphanumeric: literal, $byte: literal, $kanji: literal],
+                ($data_m: literal, $ec_m: literal,
+                    [   ($g_m_1_b: literal, $g_m_1_d: literal)
+                    $(, ($g_m_2_b: literal, $g_m_2_d: literal) )?]
+                ),
+                ($data_h: literal, $ec_h: literal,
+                    [   ($g_h_1_b: literal, $g_h_1_d: literal)
+                    $(, ($g_h_2_b: literal, $g_h_2_d: literal) )?]
+                )
+            )
+        ),*
+    ) => {
+        impl RmqrVersion {
+            /// Every version, ordered by their version indicator
+            pub const ALL: &'static [Self] = &[$( RmqrVersion::$version ),+];
+            /// Returns the height of the symbol in modules
+            #[inline]
+            #[must_use]
+            pub const fn height(self) -> usize {
+                match self { $( RmqrVersion::$version => $height, )* }
+            }
+            /// Returns the width of the symbol in modules
+            #[inline]
+            #[must_use]
+            pub const fn width(self) -> usize {
+                match self { $( RmqrVersion::$version => $width, )* }
+            }
+            /// Returns the number of zero bits appended after the final codeword
+            #[inline]
+            #[must_use]
+            pub const fn remainder_bits(self) -> u16 {
+                match self { $( RmqrVersion::$version => $remainder, )* }
+            }
+            /// Returns the number of bits used for the character count
+            #[inline]
+            #[must_use]
+            pub const fn count_bits(self, mode: EncodingMode) -> u16 {
+                use EncodingMode::*;
+                match self {
+                    $(
+                        RmqrVersion::$version => match mode {
+                            Numeric => $numeric,
+                            Alphanumeric => $alphanumeric,
+                            Byte => $byte,
+                            Kanji => $kanji,
+                        },
+                    )*
+                }
+            }
+            /// Returns the number of data codewords for the given
+            /// [error correction](ErrorCorrection), if supported
+            #[inline]
+            #[must_use]
+            pub const fn data_size(self, ec: ErrorCorrection) -> Option<usize> {
+                match ec {
+                    ErrorCorrection::M => Some(match self { $( RmqrVersion::$version => $data_m, )* }),
+                    ErrorCorrection::H => Some(match self { $( RmqrVersion::$version => $data_h, )* }),
+                    ErrorCorrection::L | ErrorCorrection::Q => None,
+                }
+            }
+            /// Returns the way blocks should be split according to the given
+            /// version and error correction level, if supported
+            #[inline]
+            #[must_use]
+            pub const fn split(self, ec: ErrorCorrection) -> Option<GroupSplit> {
+                use GroupSplit::*;
+                Some(match ec {
+                    ErrorCorrection::M => match self {
+                        $(
+                            RmqrVersion::$version => impl_rmqr![@group $ec_m,
+                                    $g_m_1_b, $g_m_1_d
+                                $(, $g_m_2_b, $g_m_2_d )?
+                            ],
+                        )*
+                    },
+                    ErrorCorrection::H => match self {
+                        $(
+                            RmqrVersion::$version => impl_rmqr![@group $ec_h,
+                                    $g_h_1_b, $g_h_1_d
+                                $(, $g_h_2_b, $g_h_2_d )?
+                            ],
+                        )*
+                    },
+                    ErrorCorrection::L | ErrorCorrection::Q => return None,
+                })
+            }
+        }
+    };
+    (@group $ec_bytes: literal,
+        $blocks_one: literal, $data_bytes_one: literal,
+        $blocks_two: literal, $data_bytes_two: literal
+    ) => {
+        Two {
+            ec_bytes: $ec_bytes,
+            block_split_one: BlockSplit {
+                data_bytes: $data_bytes_one,
+                blocks: $blocks_one
+            },
+            block_split_two: BlockSplit {
+                data_bytes: $data_bytes_two,
+                blocks: $blocks_two
+            },
+        }
+    };
+    (@group $ec_bytes: literal, $blocks: literal, $data_bytes: literal) => {
+        One {
+            ec_bytes: $ec_bytes,
+            block_split: BlockSplit {
+                data_bytes: $data_bytes,
+                blocks: $blocks
+            }
+        }
+    };
+}
+
+impl_rmqr![
+    (
+        R7x43,
+        7,
+        43,
+        0,
+        [4, 3, 3, 2],
+        (6, 7, [(1, 6)]),
+        (3, 10, [(1, 3)])
+    ),
+    (
+        R7x59,
+        7,
+        59,
+        3,
+        [5, 5, 4, 3],
+        (12, 9, [(1, 12)]),
+        (7, 14, [(1, 7)])
+    ),
+    (
+        R7x77,
+        7,
+        77,
+        5,
+        [6, 5, 5, 4],
+        (20, 12, [(1, 20)]),
+        (10, 22, [(1, 10)])
+    ),
+    (
+        R7x99,
+        7,
+        99,
+        6,
+        [7, 6, 5, 5],
+        (28, 16, [(1, 28)]),
+        (14, 30, [(1, 14)])
+    ),
+    (
+        R7x139,
+        7,
+        139,
+        1,
+        [7, 6, 6, 5],
+        (44, 24, [(1, 44)]),
+        (24, 22, [(2, 12)])
+    ),
+    (
+        R9x43,
+        9,
+        43,
+        2,
+        [5, 5, 4, 3],
+        (12, 9, [(1, 12)]),
+        (7, 14, [(1, 7)])
+    ),
+    (
+        R9x59,
+        9,
+        59,
+        3,
+        [6, 5, 5, 4],
+        (21, 12, [(1, 21)]),
+        (11, 22, [(1, 11)])
+    ),
+    (
+        R9x77,
+        9,
+        77,
+        1,
+        [7, 6, 5, 5],
+        (31, 18, [(1, 31)]),
+        (17, 16, [(1, 8), (1, 9)])
+    ),
+    (
+        R9x99,
+        9,
+        99,
+        4,
+        [7, 6, 6, 5],
+        (42, 24, [(1, 42)]),
+        (22, 22, [(2, 11)])
+    ),
+    (
+        R9x139,
+        9,
+        139,
+        5,
+        [8, 7, 6, 6],
+        (63, 18, [(1, 31), (1, 32)]),
+        (33, 22, [(3, 11)])
+    ),
+    (
+        R11x27,
+        11,
+        27,
+        2,
+        [4, 4, 3, 2],
+        (7, 8, [(1, 7)]),
+        (5, 10, [(1, 5)])
+    ),
+    (
+        R11x43,
+        11,
+        43,
+        1,
+        [6, 5, 5, 4],
+        (19, 12, [(1, 19)]),
+        (11, 20, [(1, 11)])
+    ),
+    (
+        R11x59,
+        11,
+        59,
+        0,
+        [7, 6, 5, 5],
+        (31, 16, [(1, 31)]),
+        (15, 16, [(1, 7), (1, 8)])
+    ),
+    (
+        R11x77,
+        11,
+        77,
+        2,
+        [7, 6, 6, 5],
+        (43, 24, [(1, 43)]),
+        (23, 22, [(1, 11), (1, 12)])
+    ),
+    (
+        R11x99,
+        11,
+        99,
+        7,
+        [8, 7, 6, 6],
+        (57, 16, [(1, 28), (1, 29)]),
+        (29, 30, [(1, 14), (1, 15)])
+    ),
+    (
+        R11x139,
+        11,
+        139,
+        6,
+        [8, 7, 7, 6],
+        (84, 24, [(2, 42)]),
+        (42, 30, [(3, 14)])
+    ),
+    (
+        R13x27,
+        13,
+        27,
+        4,
+        [5, 5, 4, 3],
+        (12, 9, [(1, 12)]),
+        (7, 14, [(1, 7)])
+    ),
+    (
+        R13x43,
+        13,
+        43,
+        1,
+        [6, 6, 5, 5],
+        (27, 14, [(1, 27)]),
+        (13, 28, [(1, 13)])
+    ),
+    (
+        R13x59,
+        13,
+        59,
+        6,
+        [7, 6, 6, 5],
+        (38, 22, [(1, 38)]),
+        (20, 20, [(2, 10)])
+    ),
+    (
+        R13x77,
+        13,
+        77,
+        4,
+        [7, 7, 6, 6],
+        (53, 16, [(1, 26), (1, 27)]),
+        (29, 28, [(1, 14), (1, 15)])
+    ),
+    (
+        R13x99,
+        13,
+        99,
+        3,
+        [8, 7, 7, 6],
+        (73, 20, [(1, 36), (1, 37)]),
+        (35, 26, [(1, 11), (2, 12)])
+    ),
+    (
+        R13x139,
+        13,
+        139,
+        0,
+        [8, 8, 7, 7],
+        (106, 20, [(2, 35), (1, 36)]),
+        (54, 28, [(2, 13), (2, 14)])
+    ),
+    (
+        R15x43,
+        15,
+        43,
+        1,
+        [7, 6, 6, 5],
+        (33, 18, [(1, 33)]),
+        (15, 18, [(1, 7), (1, 8)])
+    ),
+    (
+        R15x59,
+        15,
+        59,
+        4,
+        [7, 7, 6, 5],
+        (48, 26, [(1, 48)]),
+        (26, 24, [(2, 13)])
+    ),
+    (
+        R15x77,
+        15,
+        77,
+        6,
+        [8, 7, 7, 6],
+        (67, 18, [(1, 33), (1, 34)]),
+        (31, 24, [(2, 10), (1, 11)])
+    ),
+    (
+        R15x99,
+        15,
+        99,
+        7,
+        [8, 7, 7, 6],
+        (88, 24, [(2, 44)]),
+        (48, 22, [(4, 12)])
+    ),
+    (
+        R15x139,
+        15,
+        139,
+        2,
+        [9, 8, 7, 7],
+        (127, 24, [(2, 42), (1, 43)]),
+        (69, 26, [(1, 13), (4, 14)])
+    ),
+    (
+        R17x43,
+        17,
+        43,
+        1,
+        [7, 6, 6, 5],
+        (39, 22, [(1, 39)]),
+        (21, 20, [(1, 10), (1, 11)])
+    ),
+    (
+        R17x59,
+        17,
+        59,
+        2,
+        [8, 7, 6, 6],
+        (56, 16, [(2, 28)]),
+        (28, 30, [(2, 14)])
+    ),
+    (
+        R17x77,
+        17,
+        77,
+        0,
+        [8, 7, 7, 6],
+        (78, 22, [(2, 39)]),
+        (38, 28, [(1, 12), (2, 13)])
+    ),
+    (
+        R17x99,
+        17,
+        99,
+        3,
+        [8, 8, 7, 6],
+        (100, 20, [(2, 33), (1, 34)]),
+        (56, 26, [(4, 14)])
+    ),
+    (
+        R17x139,
+        17,
+        139,
+        4,
+        [9, 8, 8, 7],
+        (152, 20, [(4, 38)]),
+        (76, 26, [(2, 12), (4, 13)])
+    )
+];
+
+impl RmqrVersion {
+    /// Returns the five bit version indicator written in the format
+    /// information
+    #[inline]
+    #[must_use]
+    pub const fn indicator(self) -> u8 {
+        self as u8
+    }
+    /// Returns the total number of codewords, data & error correction
+    #[inline]
+    #[must_use]
+    pub const fn total_codewords(self) -> usize {
+        match (
+            self.split(ErrorCorrection::M),
+            self.data_size(ErrorCorrection::M),
+        ) {
+            (Some(split), Some(data)) => data + split.blocks() * split.ec_bytes(),
+            _ => 0,
+        }
+    }
+    /// Returns the columns of the alignment pattern centers, which sit on
+    /// the top & bottom edges joined by a vertical timing pattern
+    #[inline]
+    #[must_use]
+    pub const fn alignment_positions(self) -> &'static [usize] {
+        match self.width() {
+            43 => &[21],
+            59 => &[19, 39],
+            77 => &[25, 51],
+            99 => &[23, 49, 75],
+            139 => &[27, 55, 83, 111],
+            _ => &[],
+        }
+    }
+}
+
+/// Returns the 18 bit format information for the given version and
+/// [error correction](ErrorCorrection), if supported
+///
+/// The first is placed next to the finder pattern, the second next to the
+/// finder sub pattern. The error correction bit, `0` for M and `1` for H, and the five bit
+/// version indicator are followed by twelve BCH error correction bits,
+/// and the result is masked differently for each location.
+#[inline]
+#[must_use]
+pub const fn format_bits(version: RmqrVersion, ec: ErrorCorrection) -> Option<(u32, u32)> {
+    let level = match ec {
+        ErrorCorrection::M => 0,
+        ErrorCorrection::H => 1,
+        ErrorCorrection::L | ErrorCorrection::Q => return None,
+    };
+    let data = level << 5 | version.indicator() as u32;
+    let bits = data << 12 | bch_remainder(data, VERSION_GENERATOR, 12);
+    Some((bits ^ FORMAT_MASK_FINDER, bits ^ FORMAT_MASK_SUB_FINDER))
+}
+
+/// Returns the value of the three bit mode indicator
+#[inline]
+#[must_use]
+pub const fn mode_indicator(mode: EncodingMode) -> u16 {
+    match mode {
+        EncodingMode::Numeric => 0b001,
+        EncodingMode::Alphanumeric => 0b010,
+        EncodingMode::Byte => 0b011,
+        EncodingMode::Kanji => 0b100,
+    }
+}
+
+/// Returns the number of bits needed to encode the segments in the given
+/// version, each with its three bit mode indicator & character count
+#[must_use]
+pub fn bit_length(segments: &[Segment], version: RmqrVersion) -> usize {
+    segments
+        .iter()
+        .map(|s| {
+            s.eci
+                .map_or(0, |designator| segment::eci::bit_length(designator) - 1)
+                + 3
+                + version.count_bits(s.mode) as usize
+                + data_bit_length(s.mode, s.character_count())
+        })
+        .sum()
+}
+
+/// Splits the string into the segments which produce the shortest
+/// bitstream for the given version, like [`segment::optimize`]
+#[must_use]
+pub fn optimize(s: &str, version: RmqrVersion) -> Vec<Segment> {
+    let head_bits = [
+        EncodingMode::Numeric,
+        EncodingMode::Alphanumeric,
+        EncodingMode::Byte,
+        EncodingMode::Kanji,
+    ]
+    .map(|mode| Some(3 + version.count_bits(mode) as usize));
+    optimize_with(s, &head_bits, false).unwrap_or_default()
+}
+
+/// Creates the data codewords for the given segments
+///
+/// Segments are written just as in QR Code, with a three bit mode
+/// indicator, and the terminator is three bits long.
+///
+/// # Errors
+///
+/// - [`EncodeError::UnsupportedRmqrErrorCorrection`] unless the error
+///   correction is M or H
+/// - [`EncodeError::InvalidEciDesignator`] if a segment's ECI is too large
+/// - [`EncodeError::InvalidCharacterForMode`] if a segment holds data its
+///   mode cannot encode
+/// - [`EncodeError::RmqrDataTooLong`] if the segments do not fit
+#[allow(clippy::cast_possible_truncation)]
+pub fn create_bits(
+    segments: &[Segment],
+    version: RmqrVersion,
+    ec: ErrorCorrection,
+) -> Result<Vec<u8>, EncodeError> {
+    let Some(data_size) = version.data_size(ec) else {
+        return Err(EncodeError::UnsupportedRmqrErrorCorrection { ec });
+    };
+    for segment in segments {
+        if let Some(designator) = segment.eci.filter(|&d| d > segment::eci::MAX) {
+            return Err(EncodeError::InvalidEciDesignator { designator });
+        }
+        if segment.mode != EncodingMode::Byte {
+            segment
+                .mode
+                .check(&String::from_utf8_lossy(&segment.data))?;
+        }
+    }
+    let needed_bits = bit_length(segments, version);
+    let capacity_bits = data_size * 8;
+    if needed_bits > capacity_bits {
+        return Err(EncodeError::RmqrDataTooLong {
+            needed_bits,
+            capacity_bits,
+            version,
+            ec,
+        });
+    }
+    // the count fields are wide enough for as many characters as fit
+
+    let mut bytes = Bytes::with_capacity(data_size);
+    for segment in segments {
+        if let Some(designator) = segment.eci {
+            bytes.push(0b111, 3);
+            encode_eci_designator(&mut bytes, designator);
+        }
+        bytes.push(mode_indicator(segment.mode), 3);
+        bytes.push(
+            segment.character_count() as u16,
+            version.count_bits(segment.mode),
+        );
+        segment.encode_data(&mut bytes)?;
+    }
+    let terminator = (capacity_bits - bytes.bit_len()).min(3);
+    if terminator > 0 {
+        bytes.push(0, terminator as u16);
+    }
+    let mut i = 0;
+    while bytes.len() < data_size {
+        bytes.push_full_byte([0xEC, 0x11][i & 1]);
+        i += 1;
+    }
+    Ok(bytes.into_parts().0)
+}
+
+/// The grid of modules making up an rMQR symbol
+///
+/// Coordinates are given as `(x, y)`, i.e. `(column, row)`, with the
+/// origin in the top left corner, on the finder pattern.
+/// A dark module is `true`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RmqrMatrix {
+    version: RmqrVersion,
+    width: usize,
+    height: usize,
+    modules: Vec<bool>,
+    reserved: Vec<bool>,
+}
+
+impl RmqrMatrix {
+    /// Creates a matrix for the given version with every function pattern
+    /// placed, and the format information areas reserved
+    #[must_use]
+    pub fn new(version: RmqrVersion) -> Self {
+        let (width, height) = (version.width(), version.height());
+        let mut matrix = Self {
+            version,
+            width,
+            height,
+            modules: vec![false; width * height],
+            reserved: vec![false; width * height],
+        };
+        matrix.place_timing();
+        matrix.place_finders();
+        matrix.place_alignment();
+        // the format information
+        for n in 0..18 {
+            let (x, y) = if n < 15 {
+                (8 + n / 5, 1 + n % 5)
+            } else {
+                (11, n - 14)
+            };
+            matrix.set_function(x, y, false);
+            let (x, y) = if n < 15 {
+                (width - 8 + n / 5, height - 6 + n % 5)
+            } else {
+                (width - 20 + n, height - 6)
+            };
+            matrix.set_function(x, y, false);
+        }
+        matrix
+    }
+    /// Places the timing patterns along the top & bottom edges, and
+    /// along the left & right edges between the finder patterns
+    fn place_timing(&mut self) {
+        let (width, height) = (self.width, self.height);
+        for x in 0..width {
+            self.set_function(x, 0, x % 2 == 0);
+            self.set_function(x, height - 1, x % 2 == 0);
+        }
+        for y in 8..height.saturating_sub(2) {
+            self.set_function(0, y, y % 2 == 0);
+        }
+        for y in 2..height - 5 {
+            self.set_function(width - 1, y, y % 2 == 0);
+        }
+    }
+    /// Places the finder pattern with its separator in the top left corner,
+    /// the finder sub pattern in the bottom right one, and the corner
+    /// finder patterns in the other two
+    fn place_finders(&mut self) {
+        let (width, height) = (self.width, self.height);
+        for y in 0..height.min(8) {
+            for x in 0..8_usize {
+                let ring = x.abs_diff(3).max(y.abs_diff(3));
+                self.set_function(x, y, ring != 2 && ring < 4);
+            }
+        }
+        for y in height - 5..height {
+            for x in width - 5..width {
+                let ring = x.abs_diff(width - 3).max(y.abs_diff(height - 3));
+                self.set_function(x, y, ring != 1);
+            }
+        }
+        self.set_function(width - 2, 0, true);
+        self.set_function(width - 1, 1, true);
+        self.set_function(width - 2, 1, false);
+        if height >= 9 {
+            for x in 0..3 {
+                self.set_function(x, height - 1, true);
+            }
+        }
+        if height >= 11 {
+            self.set_function(0, height - 2, true);
+            self.set_function(1, height - 2, false);
+        }
+    }
+    /// Places the alignment patterns on the top & bottom edges, and the
+    /// vertical timing patterns between them
+    fn place_alignment(&mut self) {
+        let height = self.height;
+        for &cx in self.version.alignment_positions() {
+            for y in 0..height {
+                self.set_function(cx, y, y % 2 == 0);
+            }
+            for cy in [1, height - 2] {
+                for y in cy - 1..=cy + 1 {
+                    for x in cx - 1..=cx + 1 {
+                        self.set_function(x, y, (x, y) != (cx, cy));
+                    }
+                }
+            }
+        }
+    }
+    /// Returns the version this matrix was created for
+    #[inline]
+    #[must_use]
+    pub const fn version(&self) -> RmqrVersion {
+        self.version
+    }
+    /// Returns the width in modules
+    #[inline]
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+    /// Returns the height in modules
+    #[inline]
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+    /// Returns whether the module at `(x, y)` is dark
+    ///
+    /// # Panics
+    ///
+    /// If the coordinates are out of bounds
+    #[inline]
+    #[must_use]
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        self.modules[self.index(x, y)]
+    }
+    /// Returns whether the module at `(x, y)` is part of a function pattern
+    /// or the format information, meaning it may not hold data
+    ///
+    /// # Panics
+    ///
+    /// If the coordinates are out of bounds
+    #[inline]
+    #[must_use]
+    pub fn is_reserved(&self, x: usize, y: usize) -> bool {
+        self.reserved[self.index(x, y)]
+    }
+    /// Returns the modules row by row
+    #[inline]
+    #[must_use]
+    pub fn modules(&self) -> &[bool] {
+        &self.modules
+    }
+    /// Returns an iterator over the rows of modules
+    pub fn rows(&self) -> impl Iterator<Item = &[bool]> {
+        self.modules.chunks(self.width)
+    }
+    /// Returns the coordinates of every data module in placement order
+    ///
+    /// Starting next to the bottom right corner, columns are walked in
+    /// pairs, alternating between upwards and downwards, right module
+    /// first. The right edge is entirely made of function patterns, so
+    /// the first pair is one column in.
+    #[must_use]
+    pub fn data_positions(&self) -> Vec<(usize, usize)> {
+        let mut positions = Vec::with_capacity(self.width * self.height);
+        let mut upward = true;
+        for right in (1..self.width - 1).rev().step_by(2) {
+            for vertical in 0..self.height {
+                let y = if upward {
+                    self.height - 1 - vertical
+                } else {
+                    vertical
+                };
+                for x in [right, right - 1] {
+                    if !self.is_reserved(x, y) {
+                        positions.push((x, y));
+                    }
+                }
+            }
+            upward = !upward;
+        }
+        positions
+    }
+    /// Writes the codewords into the data modules, most significant bit
+    /// first, in the order given by [`RmqrMatrix::data_positions`]
+    ///
+    /// Any data modules left over are set to light.
+    pub fn place_data(&mut self, codewords: &Bytes) {
+        let bits = codewords.bit_len();
+        for (i, (x, y)) in self.data_positions().into_iter().enumerate() {
+            let index = self.index(x, y);
+            self.modules[index] = i < bits && codewords[i / 8] >> (7 - i % 8) & 1 == 1;
+        }
+    }
+    /// Flips every data module at `(x, y)` for which `(y / 2 + x / 3) % 2 == 0`,
+    /// the only mask rMQR uses
+    ///
+    /// Applying the mask twice undoes it.
+    pub fn apply_mask(&mut self) {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if !self.is_reserved(x, y) && Mask::M4.applies(x, y) {
+                    let index = self.index(x, y);
+                    self.modules[index] ^= true;
+                }
+            }
+        }
+    }
+    /// Writes both copies of the format information, least significant
+    /// bit first, down the columns of their areas
+    ///
+    /// # Panics
+    ///
+    /// If the error correction is neither M nor H
+    pub fn place_format(&mut self, ec: ErrorCorrection) {
+        let Some((finder, sub_finder)) = format_bits(self.version, ec) else {
+            panic!("rMQR does not support {ec:?}");
+        };
+        let (width, height) = (self.width, self.height);
+        for n in 0..18 {
+            let (x, y) = if n < 15 {
+                (8 + n / 5, 1 + n % 5)
+            } else {
+                (11, n - 14)
+            };
+            self.set_function(x, y, finder >> n & 1 == 1);
+            let (x, y) = if n < 15 {
+                (width - 8 + n / 5, height - 6 + n % 5)
+            } else {
+                (width - 20 + n, height - 6)
+            };
+            self.set_function(x, y, sub_finder >> n & 1 == 1);
+        }
+    }
+    fn set_function(&mut self, x: usize, y: usize, dark: bool) {
+        let i = self.index(x, y);
+        self.modules[i] = dark;
+        self.reserved[i] = true;
+    }
+    #[inline]
+    const fn index(&self, x: usize, y: usize) -> usize {
+        assert!(x < self.width && y < self.height, "module out of bounds");
+        y * self.width + x
+    }
+}
+
+/// A finished rMQR symbol, with its data placed, masked and its format
+/// information written
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RmqrCode {
+    ec: ErrorCorrection,
+    matrix: RmqrMatrix,
+}
+
+impl RmqrCode {
+    /// Encodes the given string using the version with the smallest area
+    /// it fits in, preferring the shorter one on ties
+    ///
+    /// # Errors
+    ///
+    /// If the error correction is neither M nor H, or the string does not
+    /// fit in [`RmqrVersion::R17x139`]
+    pub fn new(data: &str, ec: ErrorCorrection) -> Result<Self, EncodeError> {
+        Self::with_max_height(data, 17, ec)
+    }
+    /// Like [`RmqrCode::new`], only considering versions no taller than
+    /// `max_height` modules
+    ///
+    /// # Errors
+    ///
+    /// If the error correction is neither M nor H, or the string does not
+    /// fit in the largest version of that height
+    pub fn with_max_height(
+        data: &str,
+        max_height: usize,
+        ec: ErrorCorrection,
+    ) -> Result<Self, EncodeError> {
+        let mut versions: Vec<RmqrVersion> = RmqrVersion::ALL
+            .iter()
+            .copied()
+            .filter(|v| v.height() <= max_height.max(7))
+            .collect();
+        versions.sort_by_key(|v| (v.width() * v.height(), v.height()));
+        let mut error = None;
+        for version in versions {
+            match Self::with_version(data, version, ec) {
+                Ok(code) => return Ok(code),
+                Err(e @ EncodeError::RmqrDataTooLong { .. }) => error = Some(e),
+                Err(e) => return Err(e),
+            }
+        }
+        Err(error.unwrap_or(EncodeError::UnsupportedRmqrErrorCorrection { ec }))
+    }
+    /// Encodes the given string using the given version
+    ///
+    /// # Errors
+    ///
+    /// If the error correction is neither M nor H, or the string does not
+    /// fit
+    pub fn with_version(
+        data: &str,
+        version: RmqrVersion,
+        ec: ErrorCorrection,
+    ) -> Result<Self, EncodeError> {
+        Self::from_segments_with_version(&optimize(data, version), version, ec)
+    }
+    /// Encodes the given segments, in order, using the given version
+    ///
+    /// # Errors
+    ///
+    /// If the segments are invalid or do not fit, see [`create_bits`]
+    pub fn from_segments_with_version(
+        segments: &[Segment],
+        version: RmqrVersion,
+        ec: ErrorCorrection,
+    ) -> Result<Self, EncodeError> {
+        let data = create_bits(segments, version, ec)?;
+        Ok(Self::from_data_codewords(&data, version, ec))
+    }
+    /// Creates a symbol from finished data codewords, such as those
+    /// returned by [`create_bits`]
+    ///
+    /// # Panics
+    ///
+    /// If the error correction is neither M nor H, or `data` is shorter
+    /// than [`RmqrVersion::data_size`]
+    #[must_use]
+    pub fn from_data_codewords(data: &[u8], version: RmqrVersion, ec: ErrorCorrection) -> Self {
+        let Some(split) = version.split(ec) else {
+            panic!("rMQR does not support {ec:?}");
+        };
+        let mut matrix = RmqrMatrix::new(version);
+        let blocks = split_blocks(data, &split);
+        matrix.place_data(&interleave_blocks(&blocks, version.remainder_bits()));
+        matrix.apply_mask();
+        matrix.place_format(ec);
+        Self { ec, matrix }
+    }
+    #[inline]
+    #[must_use]
+    pub const fn version(&self) -> RmqrVersion {
+        self.matrix.version()
+    }
+    #[inline]
+    #[must_use]
+    pub const fn error_correction(&self) -> ErrorCorrection {
+        self.ec
+    }
+    /// Returns the modules of the symbol, excluding the quiet zone
+    #[inline]
+    #[must_use]
+    pub const fn matrix(&self) -> &RmqrMatrix {
+        &self.matrix
+    }
+}
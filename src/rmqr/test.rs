@@ -0,0 +1,177 @@
+use crate::{
+    encoding::data_bit_length,
+    format::{bch_remainder, VERSION_GENERATOR},
+    rmqr::{create_bits, format_bits, RmqrCode, RmqrMatrix, RmqrVersion},
+    segment::Segment,
+    EncodeError, EncodingMode, ErrorCorrection,
+};
+
+#[test]
+fn tables() {
+    assert_eq!(RmqrVersion::ALL.len(), 32);
+    for &version in RmqrVersion::ALL {
+        let total = version.total_codewords();
+        for ec in [ErrorCorrection::M, ErrorCorrection::H] {
+            let split = version.split(ec).unwrap();
+            assert_eq!(
+                split.block_sizes().sum::<usize>(),
+                version.data_size(ec).unwrap(),
+                "{version:?}-{ec:?}"
+            );
+            assert_eq!(
+                version.data_size(ec).unwrap() + split.blocks() * split.ec_bytes(),
+                total,
+                "{version:?}-{ec:?}"
+            );
+        }
+        // every data module is used
+        assert_eq!(
+            RmqrMatrix::new(version).data_positions().len(),
+            total * 8 + usize::from(version.remainder_bits()),
+            "{version:?}"
+        );
+        assert!(version.split(ErrorCorrection::L).is_none());
+        assert!(version.data_size(ErrorCorrection::Q).is_none());
+    }
+}
+
+#[test]
+fn count_bits() {
+    // the count fields are just wide enough for a single segment filling
+    // the symbol at error correction level M
+    for &version in RmqrVersion::ALL {
+        let bits = version.data_size(ErrorCorrection::M).unwrap() * 8 - 3;
+        for mode in [
+            EncodingMode::Numeric,
+            EncodingMode::Alphanumeric,
+            EncodingMode::Byte,
+            EncodingMode::Kanji,
+        ] {
+            let capacity = |width: usize| {
+                (0..bits)
+                    .take_while(|&n| data_bit_length(mode, n) + width <= bits)
+                    .last()
+                    .unwrap_or(0)
+            };
+            let width = usize::from(version.count_bits(mode));
+            assert!(capacity(width) < 1 << width, "{version:?} {mode:?}");
+            assert!(
+                capacity(width - 1) >= 1 << (width - 1),
+                "{version:?} {mode:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn format_information() {
+    let (finder, sub_finder) = format_bits(RmqrVersion::R7x43, ErrorCorrection::M).unwrap();
+    assert_eq!(finder ^ sub_finder, 0b11_1111_0000_1100_1001);
+    for &version in RmqrVersion::ALL {
+        for (level, ec) in [(0, ErrorCorrection::M), (1, ErrorCorrection::H)] {
+            let (finder, _) = format_bits(version, ec).unwrap();
+            let bits = finder ^ 0b01_1111_1010_1011_0010;
+            assert_eq!(bits >> 12, level << 5 | u32::from(version.indicator()));
+            assert_eq!(
+                bch_remainder(bits >> 12, VERSION_GENERATOR, 12),
+                bits & 0xFFF
+            );
+        }
+    }
+    assert_eq!(format_bits(RmqrVersion::R7x43, ErrorCorrection::L), None);
+}
+
+#[test]
+fn function_patterns() {
+    let matrix = RmqrMatrix::new(RmqrVersion::R7x43);
+    let row = |y: usize| (0..43).map(|x| matrix.get(x, y)).collect::<Vec<_>>();
+    let (t, f) = (true, false);
+    // finder, timing, alignment pattern at 21 and the corner finder pattern
+    let mut top = vec![t, t, t, t, t, t, t, f];
+    top.extend((8..41).map(|x| x % 2 == 0 || (20..=22).contains(&x)));
+    top.extend([t, t]);
+    assert_eq!(row(0), top);
+    assert_eq!(&row(1)[..8], [t, f, f, f, f, f, t, f]);
+    assert_eq!(&row(1)[20..23], [t, f, t]);
+    assert_eq!(&row(1)[41..], [f, t]);
+    // vertical timing pattern, finder sub pattern
+    assert_eq!(&row(3)[20..23], [f, f, f]);
+    assert!(matrix.is_reserved(21, 3));
+    assert_eq!(&row(2)[38..], [t, t, t, t, t]);
+    assert_eq!(&row(3)[38..], [t, f, f, f, t]);
+    assert_eq!(&row(4)[38..], [t, f, t, f, t]);
+    assert_eq!(&row(6)[38..], [t, t, t, t, t]);
+    assert_eq!(&row(4)[..3], [t, f, t]);
+    // the first three column pairs are taken up by function patterns
+    assert_eq!(matrix.data_positions()[0], (34, 1));
+
+    let matrix = RmqrMatrix::new(RmqrVersion::R11x27);
+    // the bottom left corner finder pattern and the edge timing patterns
+    assert_eq!([0, 1, 2, 3].map(|x| matrix.get(x, 10)), [t, t, t, f]);
+    assert_eq!([0, 1].map(|x| matrix.get(x, 9)), [t, f]);
+    assert!(matrix.is_reserved(0, 8) && matrix.get(0, 8));
+    assert!(matrix.is_reserved(26, 2) && matrix.get(26, 2));
+    assert!(matrix.is_reserved(26, 3) && !matrix.get(26, 3));
+    assert!(!matrix.is_reserved(1, 8));
+}
+
+#[test]
+#[should_panic = "module out of bounds"]
+fn out_of_bounds() {
+    // would be the first module of the second row if not checked
+    let _ = RmqrMatrix::new(RmqrVersion::R7x43).is_reserved(43, 0);
+}
+
+#[test]
+fn data_codewords() {
+    let segments = [Segment::numeric("12345").unwrap()];
+    assert_eq!(
+        create_bits(&segments, RmqrVersion::R7x43, ErrorCorrection::M),
+        Ok(vec![0x2A, 0x3D, 0xAD, 0x00, 0xEC, 0x11])
+    );
+    assert_eq!(
+        create_bits(&segments, RmqrVersion::R7x43, ErrorCorrection::Q),
+        Err(EncodeError::UnsupportedRmqrErrorCorrection {
+            ec: ErrorCorrection::Q
+        })
+    );
+    // exactly full, leaving no room for the terminator
+    assert_eq!(
+        create_bits(&segments, RmqrVersion::R7x43, ErrorCorrection::H),
+        Ok(vec![0x2A, 0x3D, 0xAD])
+    );
+}
+
+#[test]
+fn symbols() {
+    use RmqrVersion::*;
+    let version =
+        |s: &str, height, ec| RmqrCode::with_max_height(s, height, ec).map(|code| code.version());
+    assert_eq!(version("HELLO", 17, ErrorCorrection::M), Ok(R11x27));
+    assert_eq!(version("HELLO", 7, ErrorCorrection::M), Ok(R7x43));
+    assert_eq!(
+        version("HELLO", 17, ErrorCorrection::L),
+        Err(EncodeError::UnsupportedRmqrErrorCorrection {
+            ec: ErrorCorrection::L
+        })
+    );
+    assert!(matches!(
+        version(&"a".repeat(200), 17, ErrorCorrection::M),
+        Err(EncodeError::RmqrDataTooLong {
+            version: R17x139,
+            ..
+        })
+    ));
+    assert!(matches!(
+        version(&"a".repeat(60), 7, ErrorCorrection::M),
+        Err(EncodeError::RmqrDataTooLong {
+            version: R7x139,
+            ..
+        })
+    ));
+
+    let code = RmqrCode::new("HELLO WORLD", ErrorCorrection::H).unwrap();
+    let matrix = code.matrix();
+    assert_eq!(matrix.rows().count(), code.version().height());
+    assert!(matrix.rows().all(|row| row.len() == code.version().width()));
+}
@@ -0,0 +1,485 @@
+//! Splitting data into segments of differing [modes](EncodingMode)
+use crate::{
+    byte_stream::Bytes,
+    encoding::{
+        count_bits_count, encode_alphanumeric, encode_end, encode_kanji, encode_numeric,
+        encode_start,
+    },
+    gs1::GS,
+    EncodeError, EncodingMode, ErrorCorrection, QRCodeVersion,
+};
+
+#[cfg(test)]
+mod test;
+
+/// A run of data encoded in a single mode
+///
+/// Segments may be built by hand, letting the caller decide on the layout
+/// of the bitstream, or found automatically with [`optimize`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Segment {
+    pub mode: EncodingMode,
+    /// The raw bytes for [`EncodingMode::Byte`], UTF-8 text otherwise
+    pub data: Vec<u8>,
+    /// The [ECI](eci) designator written before this segment, which
+    /// applies to it and every segment after it
+    pub eci: Option<u32>,
+}
+
+/// Extended Channel Interpretation assignment numbers for common charsets
+///
+/// Scanners assume ISO-8859-1 for byte mode unless an ECI says otherwise,
+/// though many guess at UTF-8.
+pub mod eci {
+    /// The largest assignment number that can be encoded
+    pub const MAX: u32 = 999_999;
+    pub const CP437: u32 = 2;
+    pub const ISO_8859_1: u32 = 3;
+    pub const ISO_8859_2: u32 = 4;
+    pub const ISO_8859_3: u32 = 5;
+    pub const ISO_8859_4: u32 = 6;
+    pub const ISO_8859_5: u32 = 7;
+    pub const ISO_8859_6: u32 = 8;
+    pub const ISO_8859_7: u32 = 9;
+    pub const ISO_8859_8: u32 = 10;
+    pub const ISO_8859_9: u32 = 11;
+    pub const ISO_8859_10: u32 = 12;
+    pub const ISO_8859_11: u32 = 13;
+    pub const ISO_8859_13: u32 = 15;
+    pub const ISO_8859_14: u32 = 16;
+    pub const ISO_8859_15: u32 = 17;
+    pub const ISO_8859_16: u32 = 18;
+    pub const SHIFT_JIS: u32 = 20;
+    pub const WINDOWS_1250: u32 = 21;
+    pub const WINDOWS_1251: u32 = 22;
+    pub const WINDOWS_1252: u32 = 23;
+    pub const WINDOWS_1256: u32 = 24;
+    pub const UTF_16BE: u32 = 25;
+    pub const UTF_8: u32 = 26;
+    pub const US_ASCII: u32 = 27;
+    pub const BIG5: u32 = 28;
+    pub const GB18030: u32 = 29;
+    pub const EUC_KR: u32 = 30;
+
+    /// Returns the number of bits the ECI takes up, mode indicator included
+    ///
+    /// Assignment numbers are written in one, two or three bytes, prefixed
+    /// with `0`, `10` or `110` respectively.
+    #[inline]
+    #[must_use]
+    pub const fn bit_length(designator: u32) -> usize {
+        4 + match designator {
+            0..=127 => 8,
+            128..=16383 => 16,
+            _ => 24,
+        }
+    }
+}
+
+impl Segment {
+    /// Creates a segment holding text in the given mode
+    ///
+    /// # Errors
+    ///
+    /// [`EncodeError::InvalidCharacterForMode`] if the mode cannot
+    /// encode every character
+    pub fn new(mode: EncodingMode, text: &str) -> Result<Self, EncodeError> {
+        mode.check(text)?;
+        Ok(Self {
+            mode,
+            data: text.as_bytes().to_vec(),
+            eci: None,
+        })
+    }
+    /// Creates a [`EncodingMode::Numeric`] segment
+    ///
+    /// # Errors
+    ///
+    /// If a character is not a digit
+    pub fn numeric(digits: &str) -> Result<Self, EncodeError> {
+        Self::new(EncodingMode::Numeric, digits)
+    }
+    /// Creates an [`EncodingMode::Alphanumeric`] segment
+    ///
+    /// # Errors
+    ///
+    /// If a character is not one of `0-9`, `A-Z` or ` $%*+-./:`
+    pub fn alphanumeric(text: &str) -> Result<Self, EncodeError> {
+        Self::new(EncodingMode::Alphanumeric, text)
+    }
+    /// Creates a [`EncodingMode::Kanji`] segment
+    ///
+    /// # Errors
+    ///
+    /// If a character has no double byte Shift JIS code
+    pub fn kanji(text: &str) -> Result<Self, EncodeError> {
+        Self::new(EncodingMode::Kanji, text)
+    }
+    /// Creates an [`EncodingMode::Byte`] segment of arbitrary bytes
+    #[must_use]
+    pub fn byte(data: &[u8]) -> Self {
+        Self {
+            mode: EncodingMode::Byte,
+            data: data.to_vec(),
+            eci: None,
+        }
+    }
+    /// Declares the charset of this segment and the ones after it
+    /// with an [ECI](eci) designator
+    ///
+    /// # Errors
+    ///
+    /// [`EncodeError::InvalidEciDesignator`] if the designator
+    /// is larger than [`eci::MAX`]
+    pub fn with_eci(mut self, designator: u32) -> Result<Self, EncodeError> {
+        if designator > eci::MAX {
+            return Err(EncodeError::InvalidEciDesignator { designator });
+        }
+        self.eci = Some(designator);
+        Ok(self)
+    }
+    /// Returns the number of characters as written in the character count
+    #[inline]
+    #[must_use]
+    pub fn character_count(&self) -> usize {
+        match self.mode {
+            EncodingMode::Byte => self.data.len(),
+            mode => mode.character_count(&String::from_utf8_lossy(&self.data)),
+        }
+    }
+    /// Writes the data of this segment, without its mode indicator
+    /// or character count
+    ///
+    /// # Errors
+    ///
+    /// [`EncodeError::InvalidCharacterForMode`] for a Kanji segment holding
+    /// a character without a double byte Shift JIS code
+    pub(crate) fn encode_data(&self, bytes: &mut Bytes) -> Result<(), EncodeError> {
+        let text = String::from_utf8_lossy(&self.data);
+        match self.mode {
+            EncodingMode::Numeric => encode_numeric(&text, bytes),
+            EncodingMode::Alphanumeric => encode_alphanumeric(&text, bytes),
+            EncodingMode::Byte => self
+                .data
+                .iter()
+                .for_each(|&byte| bytes.push(u16::from(byte), 8)),
+            EncodingMode::Kanji => encode_kanji(&text, bytes)?,
+        }
+        Ok(())
+    }
+    /// Returns the number of bits needed to encode this segment,
+    /// including its mode indicator & character count
+    #[inline]
+    #[must_use]
+    pub fn bit_length(&self, version: QRCodeVersion) -> usize {
+        self.eci.map_or(0, eci::bit_length)
+            + crate::encoding::bit_length(version, self.mode, self.character_count())
+    }
+}
+
+/// Every mode considered by [`optimize`]
+const MODES: [EncodingMode; 4] = [
+    EncodingMode::Numeric,
+    EncodingMode::Alphanumeric,
+    EncodingMode::Byte,
+    EncodingMode::Kanji,
+];
+
+/// Returns the cost of encoding the character in the given mode,
+/// in sixths of a bit
+///
+/// Under [FNC1](crate::gs1::Fnc1), `%` is doubled in alphanumeric mode.
+const fn char_cost(mode: EncodingMode, c: char, fnc1: bool) -> usize {
+    use EncodingMode::*;
+    match mode {
+        Numeric => 20,
+        Alphanumeric if fnc1 && c == '%' => 66,
+        Alphanumeric => 33,
+        Byte => c.len_utf8() * 8 * 6,
+        Kanji => 78,
+    }
+}
+
+/// Splits the string into the segments which produce the shortest
+/// bitstream for the given version
+///
+/// The cost of every mode is tracked character by character, switching
+/// modes whenever starting a new segment, with its own mode indicator and
+/// character count, is cheaper than staying in the current one. Only the
+/// width of the character count depends on the version, so the result is
+/// the same within `V1..=V9`, `V10..=V26` and `V27..=V40`.
+#[must_use]
+pub fn optimize(s: &str, version: QRCodeVersion) -> Vec<Segment> {
+    optimize_with(s, &head_bits(version), false).unwrap_or_default()
+}
+
+/// Like [`optimize`], for data following an [FNC1](crate::gs1::Fnc1)
+/// mode indicator
+///
+/// The [group separator](crate::gs1::GS) is written as `%` in
+/// alphanumeric segments, so a literal `%` is doubled there. Byte
+/// segments hold the data as is.
+#[must_use]
+pub fn optimize_fnc1(s: &str, version: QRCodeVersion) -> Vec<Segment> {
+    optimize_with(s, &head_bits(version), true).unwrap_or_default()
+}
+
+/// Returns the width of the mode indicator and character count of
+/// every mode in [`MODES`]
+fn head_bits(version: QRCodeVersion) -> [Option<usize>; 4] {
+    MODES.map(|mode| Some(4 + count_bits_count(version, mode) as usize))
+}
+
+/// Finds the optimal segments given the number of bits heading a segment
+/// of each mode in [`MODES`], `None` for the modes that are unavailable
+///
+/// Returns `None` if a character cannot be encoded in any available mode.
+pub(crate) fn optimize_with(
+    s: &str,
+    head_bits: &[Option<usize>; 4],
+    fnc1: bool,
+) -> Option<Vec<Segment>> {
+    let head = head_bits.map(|bits| bits.map_or(usize::MAX, |bits| bits * 6));
+    let chars: Vec<(usize, char)> = s.char_indices().collect();
+    // the mode each character is encoded in, given the mode after it
+    let mut previous = Vec::with_capacity(chars.len());
+    let mut costs = head;
+
+    for &(_, c) in &chars {
+        let encodable = MODES.map(|mode| {
+            mode.can_encode(c) || (fnc1 && c == GS && mode == EncodingMode::Alphanumeric)
+        });
+        let encodable: [bool; 4] = std::array::from_fn(|i| encodable[i] && head_bits[i].is_some());
+        let mut next = [usize::MAX; 4];
+        let mut modes = [None; 4];
+        for (i, &mode) in MODES.iter().enumerate() {
+            if encodable[i] {
+                next[i] = costs[i] + char_cost(mode, c, fnc1);
+                modes[i] = Some(i);
+            }
+        }
+        // switching modes after this character
+        for to in 0..MODES.len() {
+            for from in 0..MODES.len() {
+                if !encodable[from] || to == from || head_bits[to].is_none() {
+                    continue;
+                }
+                let cost = next[from].div_ceil(6) * 6 + head[to];
+                if cost < next[to] {
+                    next[to] = cost;
+                    modes[to] = Some(from);
+                }
+            }
+        }
+        previous.push(modes);
+        costs = next;
+    }
+
+    let mut mode = (0..MODES.len()).min_by_key(|&i| costs[i]).unwrap_or(0);
+    if costs[mode] == usize::MAX {
+        return None;
+    }
+    let mut char_modes = vec![0; chars.len()];
+    for (i, modes) in previous.iter().enumerate().rev() {
+        mode = modes[mode].unwrap_or(mode);
+        char_modes[i] = mode;
+    }
+
+    let mut segments: Vec<Segment> = Vec::new();
+    for (&(start, c), &mode) in chars.iter().zip(&char_modes) {
+        let bytes: &[u8] = match c {
+            GS if fnc1 && MODES[mode] == EncodingMode::Alphanumeric => b"%",
+            '%' if fnc1 && MODES[mode] == EncodingMode::Alphanumeric => b"%%",
+            _ => &s.as_bytes()[start..start + c.len_utf8()],
+        };
+        match segments.last_mut() {
+            Some(last) if last.mode == MODES[mode] => last.data.extend_from_slice(bytes),
+            _ => segments.push(Segment {
+                mode: MODES[mode],
+                data: bytes.to_vec(),
+                eci: None,
+            }),
+        }
+    }
+    Some(segments)
+}
+
+/// Declares the first [`EncodingMode::Byte`] segment holding anything but
+/// ASCII as [UTF-8](eci::UTF_8), unless an ECI was already given before it
+///
+/// Returns whether an ECI was added.
+pub fn declare_utf8(segments: &mut [Segment]) -> bool {
+    for segment in segments {
+        if segment.eci.is_some() {
+            return false;
+        }
+        if segment.mode == EncodingMode::Byte && !segment.data.is_ascii() {
+            segment.eci = Some(eci::UTF_8);
+            return true;
+        }
+    }
+    false
+}
+
+/// Returns the number of bits needed to encode every segment
+#[must_use]
+pub fn bit_length(segments: &[Segment], version: QRCodeVersion) -> usize {
+    segments.iter().map(|s| s.bit_length(version)).sum()
+}
+
+/// Creates the data codewords for the given segments, just as
+/// [`Encodable::create_bits`](crate::encoding::Encodable::create_bits)
+/// does for a single one
+///
+/// Each segment is written as its mode indicator and character count
+/// followed by its data, in the order given.
+///
+/// # Errors
+///
+/// - [`EncodeError::InvalidCharacterForMode`] if a segment holds data its
+///   mode cannot encode
+/// - [`EncodeError::CountFieldOverflow`] if a segment has more characters
+///   than its character count can represent
+/// - [`EncodeError::DataTooLong`] if the segments do not fit
+pub fn create_bits(
+    segments: &[Segment],
+    version: QRCodeVersion,
+    ec: ErrorCorrection,
+) -> Result<Vec<u8>, EncodeError> {
+    create_bits_with_header(&[], segments, version, ec)
+}
+
+/// Like [`create_bits`], first writing the header given as
+/// `(value, width)` pairs
+pub(crate) fn create_bits_with_header(
+    header: &[(u16, u16)],
+    segments: &[Segment],
+    version: QRCodeVersion,
+    ec: ErrorCorrection,
+) -> Result<Vec<u8>, EncodeError> {
+    for segment in segments {
+        if let Some(designator) = segment.eci.filter(|&d| d > eci::MAX) {
+            return Err(EncodeError::InvalidEciDesignator { designator });
+        }
+        if segment.mode != EncodingMode::Byte {
+            segment
+                .mode
+                .check(&String::from_utf8_lossy(&segment.data))?;
+        }
+        let count = segment.character_count();
+        let count_bits = count_bits_count(version, segment.mode);
+        if count >= 1 << count_bits {
+            return Err(EncodeError::CountFieldOverflow {
+                count,
+                count_bits,
+                version,
+                mode: segment.mode,
+            });
+        }
+    }
+    let header_bits: usize = header.iter().map(|&(_, width)| width as usize).sum();
+    let needed_bits = header_bits + bit_length(segments, version);
+    let required_code_words = version.data_size(ec);
+    if needed_bits > required_code_words * 8 {
+        return Err(EncodeError::DataTooLong {
+            needed_bits,
+            capacity_bits: required_code_words * 8,
+            version,
+            ec,
+        });
+    }
+
+    let mut bytes = Bytes::with_capacity(required_code_words);
+    for &(value, width) in header {
+        bytes.push(value, width);
+    }
+    for segment in segments {
+        if let Some(designator) = segment.eci {
+            encode_eci(&mut bytes, designator);
+        }
+        encode_start(&mut bytes, version, segment.mode, segment.character_count());
+        segment.encode_data(&mut bytes)?;
+    }
+    encode_end(&mut bytes, required_code_words);
+    Ok(bytes.into_parts().0)
+}
+
+/// Writes the ECI mode indicator followed by the assignment number
+fn encode_eci(bytes: &mut Bytes, designator: u32) {
+    bytes.push(0b0111, 4);
+    encode_eci_designator(bytes, designator);
+}
+
+/// Writes the assignment number of an ECI in one, two or three bytes
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn encode_eci_designator(bytes: &mut Bytes, designator: u32) {
+    match designator {
+        0..=127 => bytes.push(designator as u16, 8),
+        128..=16383 => bytes.push(0b10 << 14 | designator as u16, 16),
+        _ => {
+            bytes.push(0b110, 3);
+            bytes.push((designator >> 16) as u16, 5);
+            bytes.push(designator as u16, 16);
+        }
+    }
+}
+
+/// Returns the smallest version able to hold the segments at the given
+/// [error correction](ErrorCorrection)
+///
+/// # Errors
+///
+/// The reason the segments do not fit in [`QRCodeVersion::V40`]
+pub fn fit_segments(
+    segments: &[Segment],
+    ec: ErrorCorrection,
+) -> Result<QRCodeVersion, EncodeError> {
+    for &version in QRCodeVersion::ALL {
+        let fits = segments
+            .iter()
+            .all(|s| s.character_count() < 1 << count_bits_count(version, s.mode));
+        if fits && bit_length(segments, version) <= version.data_size(ec) * 8 {
+            return Ok(version);
+        }
+    }
+    create_bits(segments, QRCodeVersion::V40, ec).map(|_| QRCodeVersion::V40)
+}
+
+/// Returns the smallest version able to hold the segments produced by
+/// `segment` for it at the given [error correction](ErrorCorrection),
+/// alongside those segments
+///
+/// `header_bits` are reserved in front of the segments.
+///
+/// # Errors
+///
+/// The reason the segments do not fit in [`QRCodeVersion::V40`]
+pub(crate) fn fit_version(
+    ec: ErrorCorrection,
+    header_bits: usize,
+    segment: impl Fn(QRCodeVersion) -> Vec<Segment>,
+) -> Result<(QRCodeVersion, Vec<Segment>), EncodeError> {
+    let mut segments = Vec::new();
+    for &version in QRCodeVersion::ALL {
+        // the optimal segments only change along with the count widths
+        if matches!(
+            version,
+            QRCodeVersion::V1 | QRCodeVersion::V10 | QRCodeVersion::V27
+        ) {
+            segments = segment(version);
+        }
+        if header_bits + bit_length(&segments, version) <= version.data_size(ec) * 8 {
+            return Ok((version, segments));
+        }
+    }
+    let version = QRCodeVersion::V40;
+    let segments = segment(version);
+    // reports count overflows before the lack of space
+    create_bits(&segments, version, ec)?;
+    Err(EncodeError::DataTooLong {
+        needed_bits: header_bits + bit_length(&segments, version),
+        capacity_bits: version.data_size(ec) * 8,
+        version,
+        ec,
+    })
+}
@@ -0,0 +1,169 @@
+use crate::{
+    encoding::{bit_length, Encodable},
+    segment::{self, declare_utf8, optimize, Segment},
+    EncodingMode, ErrorCorrection, QRCodeVersion, QrCode,
+};
+
+fn modes(segments: &[Segment]) -> Vec<(EncodingMode, &str)> {
+    segments
+        .iter()
+        .map(|s| (s.mode, std::str::from_utf8(&s.data).unwrap()))
+        .collect()
+}
+
+#[test]
+fn single_mode() {
+    use EncodingMode::*;
+    let version = QRCodeVersion::V1;
+    assert_eq!(
+        modes(&optimize("0123456789", version)),
+        [(Numeric, "0123456789")]
+    );
+    assert_eq!(
+        modes(&optimize("HELLO WORLD", version)),
+        [(Alphanumeric, "HELLO WORLD")]
+    );
+    assert_eq!(modes(&optimize("a1b2", version)), [(Byte, "a1b2")]);
+    assert_eq!(modes(&optimize("点茗", version)), [(Kanji, "点茗")]);
+    assert!(optimize("", version).is_empty());
+
+    let (data, _) = "HELLO WORLD"
+        .create_bits(version, ErrorCorrection::M)
+        .unwrap();
+    let segments = optimize("HELLO WORLD", version);
+    assert_eq!(
+        segment::create_bits(&segments, version, ErrorCorrection::M).unwrap(),
+        data
+    );
+}
+
+#[test]
+fn mixed() {
+    use EncodingMode::*;
+    let s = "ORDER 12345678901234 for alice@example.com";
+    let version = QRCodeVersion::V3;
+    let segments = optimize(s, version);
+    assert_eq!(
+        modes(&segments),
+        [
+            (Alphanumeric, "ORDER "),
+            (Numeric, "12345678901234"),
+            (Byte, " for alice@example.com")
+        ]
+    );
+    let concatenated: Vec<u8> = segments.iter().flat_map(|s| s.data.clone()).collect();
+    assert_eq!(concatenated, s.as_bytes());
+    assert!(segment::bit_length(&segments, version) < bit_length(version, Byte, s.len()));
+
+    // a run of digits too short to be worth its own segment
+    assert_eq!(
+        modes(&optimize("abc123def", version)),
+        [(Byte, "abc123def")]
+    );
+    assert_eq!(
+        modes(&optimize("点茗12345678901234点茗", version)),
+        [
+            (Kanji, "点茗"),
+            (Numeric, "12345678901234"),
+            (Kanji, "点茗")
+        ]
+    );
+}
+
+#[test]
+fn smaller_symbols() {
+    let s = format!("{}abc", "0".repeat(60));
+    let (single, _) = s
+        .create_bits(QRCodeVersion::V5, ErrorCorrection::M)
+        .unwrap();
+    assert_eq!(
+        single.len(),
+        QRCodeVersion::V5.data_size(ErrorCorrection::M)
+    );
+    let code = QrCode::new(&s, ErrorCorrection::M).unwrap();
+    assert_eq!(code.version(), QRCodeVersion::V3);
+}
+
+#[test]
+fn explicit_segments() {
+    use crate::EncodeError;
+
+    let segments = [
+        Segment::numeric("0123456789012345").unwrap(),
+        Segment::byte(b"serial"),
+    ];
+    let version = QRCodeVersion::V1;
+    let data = segment::create_bits(&segments, version, ErrorCorrection::L).unwrap();
+    // numeric mode, a count of 16, then "012"
+    assert_eq!(data[..3], [0b0001_0000, 0b0100_0000, 0b0000_1100]);
+    assert_eq!(
+        segment::fit_segments(&segments, ErrorCorrection::L),
+        Ok(QRCodeVersion::V1)
+    );
+    assert_eq!(
+        segment::fit_segments(&segments, ErrorCorrection::H),
+        Ok(QRCodeVersion::V2)
+    );
+    let code = QrCode::from_segments(&segments, ErrorCorrection::L).unwrap();
+    assert_eq!(code.version(), QRCodeVersion::V1);
+
+    assert_eq!(
+        Segment::alphanumeric("abc"),
+        Err(EncodeError::InvalidCharacterForMode {
+            mode: EncodingMode::Alphanumeric,
+            character: 'a',
+            index: 0,
+        })
+    );
+    let invalid = Segment {
+        mode: EncodingMode::Numeric,
+        data: b"12a".to_vec(),
+        eci: None,
+    };
+    assert!(matches!(
+        segment::create_bits(&[invalid], version, ErrorCorrection::L),
+        Err(EncodeError::InvalidCharacterForMode { character: 'a', .. })
+    ));
+}
+
+#[test]
+fn eci() {
+    use crate::{segment::eci, EncodeError};
+
+    let version = QRCodeVersion::V1;
+    let ec = ErrorCorrection::L;
+    for (designator, header) in [
+        (eci::UTF_8, vec![0b0111_0001, 0b1010_0100]),
+        (1000, vec![0b0111_1000, 0b0011_1110, 0b1000_0100]),
+        (
+            eci::MAX,
+            vec![0b0111_1100, 0b1111_0100, 0b0010_0011, 0b1111_0100],
+        ),
+    ] {
+        let segment = Segment::byte(b"\xe9").with_eci(designator).unwrap();
+        let data = segment::create_bits(std::slice::from_ref(&segment), version, ec).unwrap();
+        assert_eq!(data[..header.len()], header, "{designator}");
+        assert_eq!(
+            segment.bit_length(version),
+            eci::bit_length(designator) + 4 + 8 + 8
+        );
+    }
+    assert_eq!(
+        Segment::byte(b"").with_eci(1_000_000),
+        Err(EncodeError::InvalidEciDesignator {
+            designator: 1_000_000
+        })
+    );
+
+    let mut segments = optimize("ABC école", version);
+    assert!(declare_utf8(&mut segments));
+    assert_eq!(segments.iter().filter(|s| s.eci.is_some()).count(), 1);
+    assert!(!declare_utf8(&mut segments));
+    let mut ascii = optimize("abc", version);
+    assert!(!declare_utf8(&mut ascii));
+
+    let plain = QrCode::new("école", ec).unwrap();
+    let declared = QrCode::new_utf8("école", ec).unwrap();
+    assert_eq!(plain.version(), declared.version());
+    assert_ne!(plain, declared);
+}
@@ -0,0 +1,122 @@
+//! Structured Append, spreading one message over up to 16 linked symbols
+use crate::{
+    segment::{self, create_bits_with_header},
+    EncodeError, ErrorCorrection, QrCode,
+};
+
+#[cfg(test)]
+mod test;
+
+/// The most symbols a message may be split into
+pub const MAX_SYMBOLS: usize = 16;
+
+/// The header preceding the data of every symbol in a Structured Append
+/// sequence
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StructuredAppend {
+    /// The position of the symbol in the sequence, starting at `0`
+    pub position: u8,
+    /// The number of symbols in the sequence
+    pub total: u8,
+    /// The [parity] of the whole message
+    pub parity: u8,
+}
+
+impl StructuredAppend {
+    /// The mode indicator
+    pub const MODE: u16 = 0b0011;
+    /// The number of bits taken up by the header
+    pub const BIT_LENGTH: usize = 20;
+
+    /// Returns the header as `(value, width)` pairs, ready to be pushed
+    /// onto a [`Bytes`](crate::byte_stream::Bytes)
+    ///
+    /// The mode indicator is followed by the 4 bit position, the 4 bit
+    /// total minus one, and the 8 bit parity.
+    #[inline]
+    #[must_use]
+    pub fn fields(&self) -> [(u16, u16); 4] {
+        [
+            (Self::MODE, 4),
+            (u16::from(self.position), 4),
+            (u16::from(self.total - 1), 4),
+            (u16::from(self.parity), 8),
+        ]
+    }
+}
+
+/// Returns the parity of a message, the exclusive or of every byte
+#[inline]
+#[must_use]
+pub fn parity(data: &[u8]) -> u8 {
+    data.iter().fold(0, |acc, &b| acc ^ b)
+}
+
+/// Splits the string over as few symbols as possible, each as small as
+/// possible, linking them with a [`StructuredAppend`] header
+///
+/// The string is cut into pieces of roughly equal length on character
+/// boundaries, each of which is [segmented](segment::optimize) on its own.
+/// A string fitting in a single symbol still gets a header, as a
+/// sequence of one.
+///
+/// # Errors
+///
+/// If the string does not fit in [`MAX_SYMBOLS`] symbols, the reason
+/// the largest piece does not fit in [`QRCodeVersion::V40`](crate::QRCodeVersion::V40)
+pub fn split(data: &str, ec: ErrorCorrection) -> Result<Vec<QrCode>, EncodeError> {
+    let parity = parity(data.as_bytes());
+    for total in 1..MAX_SYMBOLS {
+        if let Ok(codes) = encode(&cut(data, total), parity, ec) {
+            return Ok(codes);
+        }
+    }
+    encode(&cut(data, MAX_SYMBOLS), parity, ec)
+}
+
+/// Encodes every piece into its own symbol, as long as all of them fit
+#[allow(clippy::cast_possible_truncation)]
+fn encode(pieces: &[&str], parity: u8, ec: ErrorCorrection) -> Result<Vec<QrCode>, EncodeError> {
+    let fitted = pieces
+        .iter()
+        .map(|piece| {
+            segment::fit_version(ec, StructuredAppend::BIT_LENGTH, |version| {
+                segment::optimize(piece, version)
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    fitted
+        .into_iter()
+        .enumerate()
+        .map(|(position, (version, segments))| {
+            let header = StructuredAppend {
+                position: position as u8,
+                total: pieces.len() as u8,
+                parity,
+            };
+            let data = create_bits_with_header(&header.fields(), &segments, version, ec)?;
+            Ok(QrCode::from_data_codewords(&data, version, ec))
+        })
+        .collect()
+}
+
+/// Cuts the string into at most `count` pieces of roughly equal byte
+/// length without splitting any characters
+fn cut(s: &str, count: usize) -> Vec<&str> {
+    let target = s.len().div_ceil(count).max(1);
+    let mut pieces = Vec::with_capacity(count);
+    let mut rest = s;
+    while !rest.is_empty() {
+        let mut end = target.min(rest.len());
+        while !rest.is_char_boundary(end) {
+            end += 1;
+        }
+        let (piece, tail) = rest.split_at(end);
+        pieces.push(piece);
+        rest = tail;
+    }
+    if pieces.is_empty() {
+        pieces.push(s);
+    }
+    pieces
+}
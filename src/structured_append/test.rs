@@ -0,0 +1,77 @@
+use crate::{
+    structured_append::{cut, parity, split, StructuredAppend},
+    EncodeError, ErrorCorrection, QRCodeVersion, QrCode,
+};
+
+/// Reads the first 20 bits of a symbol's data back out of its modules,
+/// where a structured append header is
+///
+/// The first data codewords belong to the first block, so in the
+/// interleaved sequence they are a number of blocks apart.
+fn leading_bits(code: &QrCode) -> u32 {
+    let matrix = code.matrix();
+    let blocks = code.version().split(code.error_correction()).blocks();
+    let positions = matrix.data_positions();
+    let codeword = |index: usize| {
+        positions[index * 8..index * 8 + 8]
+            .iter()
+            .fold(0, |byte, &(x, y)| {
+                let dark = matrix.modules()[y * matrix.size() + x];
+                byte << 1 | u8::from(dark != code.mask().applies(x, y))
+            })
+    };
+    u32::from_be_bytes([0, codeword(0), codeword(blocks), codeword(2 * blocks)]) >> 4
+}
+
+#[test]
+fn header() {
+    let header = StructuredAppend {
+        position: 2,
+        total: 4,
+        parity: 0xA5,
+    };
+    assert_eq!(header.fields(), [(0b0011, 4), (2, 4), (3, 4), (0xA5, 8)]);
+    assert_eq!(parity(b"ABC"), b'A' ^ b'B' ^ b'C');
+    assert_eq!(parity(b""), 0);
+}
+
+#[test]
+fn pieces() {
+    assert_eq!(cut("abcdefg", 3), ["abc", "def", "g"]);
+    assert_eq!(cut("ab", 4), ["a", "b"]);
+    assert_eq!(cut("", 2), [""]);
+    // never splits a character
+    assert_eq!(cut("aéb", 3), ["aé", "b"]);
+    assert_eq!(cut("ééé", 2), ["éé", "é"]);
+}
+
+#[test]
+fn splitting() {
+    let codes = split("HELLO WORLD", ErrorCorrection::M).unwrap();
+    assert_eq!(codes.len(), 1);
+    assert_eq!(codes[0].version(), QRCodeVersion::V1);
+    // mode, position 0, total - 1 = 0, then the parity
+    assert_eq!(
+        leading_bits(&codes[0]),
+        0b0011 << 16 | u32::from(parity(b"HELLO WORLD"))
+    );
+
+    // more than a single V40-L symbol can hold, the digits cancelling out
+    // in the parity so that only the `A` is left
+    let data = "0123456789".repeat(1000) + "A";
+    let codes = split(&data, ErrorCorrection::L).unwrap();
+    assert_eq!(codes.len(), 2);
+    assert_eq!(cut(&data, codes.len()).concat(), data);
+    for (position, code) in (0_u32..).zip(&codes) {
+        assert_eq!(
+            leading_bits(code),
+            0b0011 << 16 | position << 12 | 1 << 8 | u32::from(b'A')
+        );
+    }
+
+    let bytes = "a".repeat(2953 * 16);
+    assert!(matches!(
+        split(&bytes, ErrorCorrection::L),
+        Err(EncodeError::DataTooLong { .. })
+    ));
+}